@@ -0,0 +1,76 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Age group of the receiving person.
+///
+/// The same mood shows differently, and the same suggestion reads differently,
+/// for a child than for an older adult.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AgeGroup {
+    /// Up to around 12 years old.
+    Child,
+    /// Around 13 to 19 years old.
+    Teenager,
+    /// Around 20 to 64 years old.
+    Adult,
+    /// Around 65 years old and above.
+    OlderAdult,
+}
+
+impl AgeGroup {
+    /// Returns an iterator over all variants of this `AgeGroup` enum.
+    pub fn iter() -> impl Iterator<Item = AgeGroup> {
+        [
+            AgeGroup::Child,
+            AgeGroup::Teenager,
+            AgeGroup::Adult,
+            AgeGroup::OlderAdult,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the name of this age group, e.g. `"Child"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            AgeGroup::Child => "Child",
+            AgeGroup::Teenager => "Teenager",
+            AgeGroup::Adult => "Adult",
+            AgeGroup::OlderAdult => "Older adult",
+        }
+    }
+
+    /// Returns the identifier of this age group within content keys, e.g.
+    /// `"older_adult"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            AgeGroup::Child => "child",
+            AgeGroup::Teenager => "teenager",
+            AgeGroup::Adult => "adult",
+            AgeGroup::OlderAdult => "older_adult",
+        }
+    }
+
+    /// Returns the age group with the given [`id`](Self::id), e.g.
+    /// `"older_adult"`.
+    pub fn from_id(id: &str) -> Option<AgeGroup> {
+        AgeGroup::iter().find(|age_group| age_group.id() == id)
+    }
+}
+
+impl Display for AgeGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for AgeGroup {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AgeGroup::iter()
+            .find(|age_group| age_group.name() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{Mood, MoodRange};
+
+/// A well-meaning habit of helpers that backfires at some moods.
+///
+/// Each anti-pattern lists the moods where it backfires, why, and what to do
+/// instead.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AntiPattern {
+    /// Asking the person why they feel the way they do.
+    AskingWhy,
+    /// Telling the person what to do about it.
+    GivingAdvice,
+    /// Trying to cheer the person up.
+    ForcedCheer,
+    /// Making the problem sound smaller than it feels.
+    Minimising,
+    /// Comparing the person's experience with your own.
+    ComparingExperiences,
+    /// Pressing the person to talk about it.
+    PushingToTalk,
+    /// Hugging or touching the person without asking.
+    UninvitedTouch,
+    /// Checking on the person again and again.
+    Hovering,
+}
+
+impl AntiPattern {
+    /// Returns an iterator over all variants of this `AntiPattern` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = AntiPattern> {
+        [
+            AntiPattern::AskingWhy,
+            AntiPattern::GivingAdvice,
+            AntiPattern::ForcedCheer,
+            AntiPattern::Minimising,
+            AntiPattern::ComparingExperiences,
+            AntiPattern::PushingToTalk,
+            AntiPattern::UninvitedTouch,
+            AntiPattern::Hovering,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the anti-patterns that backfire at any mood in the range.
+    pub fn for_mood_range(mood_range: MoodRange) -> impl Iterator<Item = AntiPattern> {
+        AntiPattern::iter().filter(move |anti_pattern| {
+            anti_pattern
+                .moods()
+                .iter()
+                .any(|mood| mood_range.contains(*mood))
+        })
+    }
+
+    /// Returns the identifier of this anti-pattern within content keys, e.g.
+    /// `"asking_why"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            AntiPattern::AskingWhy => "asking_why",
+            AntiPattern::GivingAdvice => "giving_advice",
+            AntiPattern::ForcedCheer => "forced_cheer",
+            AntiPattern::Minimising => "minimising",
+            AntiPattern::ComparingExperiences => "comparing_experiences",
+            AntiPattern::PushingToTalk => "pushing_to_talk",
+            AntiPattern::UninvitedTouch => "uninvited_touch",
+            AntiPattern::Hovering => "hovering",
+        }
+    }
+
+    /// Returns the anti-pattern with the given [`id`](Self::id), e.g.
+    /// `"asking_why"`.
+    pub fn from_id(id: &str) -> Option<AntiPattern> {
+        AntiPattern::iter().find(|anti_pattern| anti_pattern.id() == id)
+    }
+
+    /// Returns the name of this anti-pattern, e.g. `"Asking why"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            AntiPattern::AskingWhy => "Asking why",
+            AntiPattern::GivingAdvice => "Giving advice",
+            AntiPattern::ForcedCheer => "Forced cheerfulness",
+            AntiPattern::Minimising => "Minimising",
+            AntiPattern::ComparingExperiences => "Comparing experiences",
+            AntiPattern::PushingToTalk => "Pushing them to talk",
+            AntiPattern::UninvitedTouch => "Touching without asking",
+            AntiPattern::Hovering => "Hovering",
+        }
+    }
+
+    /// Returns an example of what this anti-pattern sounds or looks like.
+    pub fn example(self) -> &'static str {
+        match self {
+            AntiPattern::AskingWhy => "\"Why are you so upset?\"",
+            AntiPattern::GivingAdvice => "\"You should get some fresh air.\"",
+            AntiPattern::ForcedCheer => "\"Cheer up, it's a beautiful day!\"",
+            AntiPattern::Minimising => "\"It's not that bad, others have it worse.\"",
+            AntiPattern::ComparingExperiences => "\"I know exactly how you feel, when I...\"",
+            AntiPattern::PushingToTalk => "\"You can't keep it bottled up, talk to me.\"",
+            AntiPattern::UninvitedTouch => "A hug or a hand on the shoulder, without asking.",
+            AntiPattern::Hovering => "Asking \"Are you okay?\" every few minutes.",
+        }
+    }
+
+    /// Returns the moods at which this anti-pattern backfires, from the most
+    /// to the least overwhelmed.
+    pub fn moods(self) -> &'static [Mood] {
+        match self {
+            AntiPattern::AskingWhy
+            | AntiPattern::GivingAdvice
+            | AntiPattern::Minimising
+            | AntiPattern::ComparingExperiences => &[
+                Mood::_01_Anguished,
+                Mood::_02_Closed,
+                Mood::_03_Cautious,
+                Mood::_04_Unsettled,
+            ],
+            AntiPattern::ForcedCheer | AntiPattern::PushingToTalk | AntiPattern::UninvitedTouch => {
+                &[Mood::_01_Anguished, Mood::_02_Closed, Mood::_03_Cautious]
+            }
+            AntiPattern::Hovering => &[Mood::_02_Closed, Mood::_03_Cautious, Mood::_04_Unsettled],
+        }
+    }
+
+    /// Returns why this anti-pattern backfires at its moods.
+    pub fn reason(self) -> &'static str {
+        match self {
+            AntiPattern::AskingWhy => {
+                "\"Why\" asks the person to justify how they feel, which is \
+                heard as blame. They may not know why, and now feel that they \
+                have failed a test."
+            }
+            AntiPattern::GivingAdvice => {
+                "Advice can sound like \"you are doing it wrong\", and asks for \
+                energy the person doesn't have. They may stop telling you how \
+                they feel."
+            }
+            AntiPattern::ForcedCheer => {
+                "Cheerfulness tells the person that how they feel is wrong, and \
+                shows that you don't understand. They may hide their feelings \
+                to make you comfortable."
+            }
+            AntiPattern::Minimising => {
+                "Making the problem sound small says that their pain doesn't \
+                count. The person may feel foolish for being upset, and keep \
+                it to themselves next time."
+            }
+            AntiPattern::ComparingExperiences => {
+                "Your story moves the focus away from the person, and no two \
+                experiences are the same. It can feel like you are not \
+                listening."
+            }
+            AntiPattern::PushingToTalk => {
+                "Being pressed to talk is one more demand when the person is \
+                already overwhelmed. Talking may come later, when it feels \
+                safe."
+            }
+            AntiPattern::UninvitedTouch => {
+                "When the person is overwhelmed or guarded, closeness they \
+                didn't choose can feel like pressure, or even a threat, however \
+                kindly it is meant."
+            }
+            AntiPattern::Hovering => {
+                "Being checked on again and again feels like being watched, and \
+                asks for a response each time. The person may pull away to get \
+                some peace."
+            }
+        }
+    }
+
+    /// Returns what to do instead.
+    pub fn instead(self) -> &'static str {
+        match self {
+            AntiPattern::AskingWhy => {
+                "Say what you notice, and offer to listen, e.g. \"You seem \
+                tired. I'm here if you want to talk.\""
+            }
+            AntiPattern::GivingAdvice => {
+                "Listen, and ask before offering ideas, e.g. \"Do you want \
+                ideas, or just to talk?\""
+            }
+            AntiPattern::ForcedCheer => {
+                "Match their energy. Be calm and steady, and let good moments \
+                come on their own."
+            }
+            AntiPattern::Minimising => {
+                "Acknowledge how it feels, e.g. \"That sounds really hard.\""
+            }
+            AntiPattern::ComparingExperiences => {
+                "Keep the focus on them, e.g. \"What was that like for you?\", \
+                or simply listen."
+            }
+            AntiPattern::PushingToTalk => {
+                "Offer once, with a way out, e.g. \"You don't have to say \
+                anything\", then wait."
+            }
+            AntiPattern::UninvitedTouch => {
+                "Ask first, or sit nearby and let them close the distance."
+            }
+            AntiPattern::Hovering => {
+                "Check in once, tell them where you will be, and let them come \
+                to you."
+            }
+        }
+    }
+}
+
+impl Display for AntiPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for AntiPattern {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AntiPattern::iter()
+            .find(|anti_pattern| anti_pattern.name() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Mood, SuggestionContext, Trust};
+
+/// A suggestion in a [`CatalogFile`], e.g. the one for when the person does
+/// not trust you and is closed.
+///
+/// [`CatalogFile`]: crate::CatalogFile
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CatalogCell {
+    /// The suggestion for a trust and mood level, in any context.
+    Suggestion(Trust, Mood),
+    /// A suggestion for a trust and mood level that only applies in a
+    /// non-empty context.
+    ContextSuggestion(Trust, Mood, SuggestionContext),
+    /// The general guidance for when only the trust level is known.
+    TrustSuggestion(Trust),
+}
+
+impl CatalogCell {
+    /// Returns the cell for the trust level, mood, and context.
+    ///
+    /// Without a mood, this is the general guidance for the trust level, which
+    /// does not vary by context.
+    pub fn new(trust: Trust, mood: Option<Mood>, context: SuggestionContext) -> Self {
+        match mood {
+            Some(mood) if context.is_empty() => CatalogCell::Suggestion(trust, mood),
+            Some(mood) => CatalogCell::ContextSuggestion(trust, mood, context),
+            None => CatalogCell::TrustSuggestion(trust),
+        }
+    }
+
+    /// Returns the trust level of this cell.
+    pub fn trust(self) -> Trust {
+        match self {
+            CatalogCell::Suggestion(trust, _)
+            | CatalogCell::ContextSuggestion(trust, _, _)
+            | CatalogCell::TrustSuggestion(trust) => trust,
+        }
+    }
+
+    /// Returns the mood of this cell, which is `None` for the general guidance
+    /// for a trust level.
+    pub fn mood(self) -> Option<Mood> {
+        match self {
+            CatalogCell::Suggestion(_, mood) | CatalogCell::ContextSuggestion(_, mood, _) => {
+                Some(mood)
+            }
+            CatalogCell::TrustSuggestion(_) => None,
+        }
+    }
+
+    /// Returns the context of this cell, which is empty unless it is a
+    /// [`CatalogCell::ContextSuggestion`].
+    pub fn context(self) -> SuggestionContext {
+        match self {
+            CatalogCell::ContextSuggestion(_, _, context) => context,
+            CatalogCell::Suggestion(..) | CatalogCell::TrustSuggestion(_) => {
+                SuggestionContext::default()
+            }
+        }
+    }
+}
+
+impl Display for CatalogCell {
+    /// Writes the table of this cell in a catalog file, e.g. `absent.closed`
+    /// or `contexts.absent.closed.colleague+work`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segment = |name: &str| name.to_lowercase();
+        match self {
+            CatalogCell::Suggestion(trust, mood) => {
+                write!(f, "{}.{}", segment(trust.name()), segment(mood.name()))
+            }
+            CatalogCell::ContextSuggestion(trust, mood, context) => write!(
+                f,
+                "contexts.{}.{}.{}",
+                segment(trust.name()),
+                segment(mood.name()),
+                context.id()
+            ),
+            CatalogCell::TrustSuggestion(trust) => {
+                write!(f, "trust_suggestions.{}", segment(trust.name()))
+            }
+        }
+    }
+}
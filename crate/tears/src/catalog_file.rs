@@ -0,0 +1,696 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
+
+use crate::{
+    AgeGroup, CatalogCell, CatalogFileError, Mood, Predicate, Principle, Rule, Suggestion,
+    SuggestionContext, Trust,
+};
+
+/// Comment written at the top of a catalog file.
+const CATALOG_FILE_HEADER: &str = "\
+# Suggestion for each trust and mood level.
+#
+# This file is edited through the editor in the `tears` server, and may also be
+# edited by hand. Every trust and mood level needs an `action` and a
+# `description`, and may have a one line `glance` and an `extended` explanation
+# for readers who want less or more.
+#
+# `principles` lists the ideas each suggestion applies, from `no_pressure`,
+# `way_out`, `listen_dont_fix`, `let_them_lead`, `give_space`,
+# `care_without_strings`, `small_offers`, `safety_first`, and `good_memories`.
+#
+# `wait_seconds` is how long to wait quietly after acting on the suggestion,
+# before speaking again or leaving. Helpers may start a timer for it.
+#
+# Text may refer to the person's profile with `{their name}`,
+# `{their favourite comfort}`, `{someone they trust}`, `{their triggers}`, or
+# `{what backfired}`, which are filled in when known.
+#
+# `{if trust == Present}...{else}...{end}` shows text only in some situations,
+# and `{plural their triggers|trigger|triggers}` picks a word by how many the
+# profile lists. Write `{{` and `}}` for literal braces. Unknown names are
+# rejected when the catalog is loaded.
+#
+# Descriptions may use a little Markdown: `*emphasis*`, `**strong**`,
+# `[links](https://example.com)`, and lists with a `- ` or `1. ` on each line.
+# HTML is shown as text.
+#
+# A suggestion may also be narrowed down to a context, in a
+# `[contexts.<trust>.<mood>.\"<context>\"]` table. The context joins an age group,
+# relationship, and setting with `+`, e.g. `\"colleague+work\"` or `\"child\"`, and
+# the most specific one that matches the reader's situation is shown.
+#
+# `[trust_suggestions.<trust>]` is the general guidance for when only the trust
+# level is known, and `[age_symptoms.<mood>]` describes how a mood shows in an
+# age group, e.g. `child = \"Quiet and withdrawn.\"`.
+#
+# `[[rules]]` give a suggestion for situations the tables above can't single
+# out, e.g. every mood up to `Closed`. Each rule has an `id`, and applies when
+# everything in `when` holds, e.g. `\"trust == Present\"`, `\"mood <= Closed\"`,
+# `\"age group == child\"`, `\"relationship == Colleague\"`, or
+# `\"setting == Work\"`. Rules only apply when the mood is known. Rules are
+# weighed together with the tables above: of those that apply, the one that
+# checks the most of trust, mood, age group, relationship, and setting is used,
+# then the one with the highest `priority`, then the first. A table checks its
+# trust, mood, and context, and has a `priority` of -1, so a rule is used over
+# a table that checks just as much, but not over a more specific one.
+
+";
+
+/// The suggestions of a catalog, as stored in a catalog file.
+///
+/// Catalog files are TOML files with one table per trust and mood level, named
+/// the same way as in [`ContentKey`]s:
+///
+/// ```toml
+/// [absent.closed]
+/// action = "Stay away"
+/// description = "Leave a gift if you must (e.g. chocolate)."
+/// glance = "Leave a small comfort, then give them space."
+/// principles = ["give_space", "care_without_strings"]
+/// wait_seconds = 600
+///
+/// [trust_suggestions.absent]
+/// action = "Keep your distance, but stay available"
+/// description = "Without trust, your help may feel like pressure."
+/// principles = ["give_space"]
+///
+/// [contexts.absent.closed."colleague+work"]
+/// action = "Keep things predictable, and don't single them out"
+/// description = "Stick to what is needed for work, and keep it low key."
+/// principles = ["no_pressure", "give_space"]
+///
+/// [age_symptoms.closed]
+/// child = "Quiet and withdrawn. May hide, or stop playing."
+///
+/// [[rules]]
+/// id = "present-overwhelmed"
+/// when = ["trust == Present", "mood <= Closed"]
+/// action = "Stay close, and say little"
+/// description = "Your presence is enough for now."
+/// principles = ["no_pressure"]
+/// ```
+///
+/// Every trust and mood level, and every trust level in `trust_suggestions`,
+/// must have a valid suggestion, which applies at least one [`Principle`].
+/// `glance`, `extended`, and `wait_seconds` are optional. Suggestions for a
+/// context in `contexts`, symptoms in `age_symptoms`, and `rules` are
+/// optional. Rules are selected together with the other suggestions, see
+/// [`SuggestionCatalog::with_rules`].
+///
+/// [`ContentKey`]: crate::ContentKey
+/// [`SuggestionCatalog::with_rules`]: crate::SuggestionCatalog::with_rules
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogFile {
+    /// Every suggestion, by the cell it is for.
+    suggestions: HashMap<CatalogCell, Suggestion>,
+    /// Symptoms of a mood for a particular age group.
+    age_symptoms: HashMap<(Mood, AgeGroup), String>,
+    /// Rules written by content authors, in the order they are listed.
+    rules: Vec<Rule>,
+}
+
+/// Tables in a catalog file, by name.
+type Tables<T> = HashMap<String, T>;
+
+/// The tables of a catalog file.
+#[derive(Deserialize)]
+struct CatalogFileTables<'s> {
+    #[serde(default)]
+    trust_suggestions: Tables<CatalogFileEntry<'s>>,
+    #[serde(default)]
+    contexts: Tables<Tables<Tables<CatalogFileEntry<'s>>>>,
+    #[serde(default)]
+    age_symptoms: Tables<Tables<String>>,
+    #[serde(default)]
+    rules: Vec<CatalogFileRule<'s>>,
+    /// Every other table is a trust level, with a table for each mood.
+    #[serde(flatten)]
+    suggestions: Tables<Tables<CatalogFileEntry<'s>>>,
+}
+
+/// An entry in a catalog file.
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct CatalogFileEntry<'s> {
+    action: Cow<'s, str>,
+    description: Cow<'s, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    glance: Option<Cow<'s, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extended: Option<Cow<'s, str>>,
+    #[serde(default)]
+    principles: Vec<Principle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_seconds: Option<u32>,
+}
+
+/// A rule in a catalog file.
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct CatalogFileRule<'s> {
+    id: Cow<'s, str>,
+    when: Vec<Cow<'s, str>>,
+    #[serde(default, skip_serializing_if = "priority_is_default")]
+    priority: i32,
+    action: Cow<'s, str>,
+    description: Cow<'s, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    glance: Option<Cow<'s, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extended: Option<Cow<'s, str>>,
+    #[serde(default)]
+    principles: Vec<Principle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_seconds: Option<u32>,
+}
+
+impl<'s> CatalogFileRule<'s> {
+    /// Returns the entry for a rule.
+    fn new(rule: &'s Rule) -> Self {
+        let CatalogFileEntry {
+            action,
+            description,
+            glance,
+            extended,
+            principles,
+            wait_seconds,
+        } = CatalogFileEntry::new(&rule.suggestion);
+
+        Self {
+            id: Cow::Borrowed(&rule.id),
+            when: rule
+                .predicates
+                .iter()
+                .map(|predicate| Cow::Owned(predicate.to_string()))
+                .collect(),
+            priority: rule.priority,
+            action,
+            description,
+            glance,
+            extended,
+            principles,
+            wait_seconds,
+        }
+    }
+
+    /// Returns the rule for this entry.
+    fn into_rule(self) -> Result<Rule, CatalogFileError> {
+        let predicates = self
+            .when
+            .iter()
+            .map(|predicate| {
+                Predicate::from_str(predicate).map_err(|()| {
+                    CatalogFileError::RulePredicateUnknown {
+                        rule: self.id.to_string(),
+                        predicate: predicate.to_string(),
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let suggestion = CatalogFileEntry {
+            action: self.action,
+            description: self.description,
+            glance: self.glance,
+            extended: self.extended,
+            principles: self.principles,
+            wait_seconds: self.wait_seconds,
+        }
+        .into_suggestion();
+
+        Ok(Rule::new(self.id.into_owned(), predicates, suggestion).with_priority(self.priority))
+    }
+}
+
+impl<'s> CatalogFileEntry<'s> {
+    /// Returns the entry for a suggestion.
+    fn new(suggestion: &'s Suggestion) -> Self {
+        Self {
+            action: Cow::Borrowed(suggestion.action()),
+            description: Cow::Borrowed(suggestion.description()),
+            glance: suggestion.glance().map(Cow::Borrowed),
+            extended: suggestion.extended().map(Cow::Borrowed),
+            principles: suggestion.principles().to_vec(),
+            wait_seconds: suggestion.wait_seconds,
+        }
+    }
+
+    /// Returns the suggestion for this entry.
+    fn into_suggestion(self) -> Suggestion {
+        Suggestion {
+            action: Cow::Owned(self.action.into_owned()),
+            description: Cow::Owned(self.description.into_owned()),
+            glance: self.glance.map(|glance| Cow::Owned(glance.into_owned())),
+            extended: self
+                .extended
+                .map(|extended| Cow::Owned(extended.into_owned())),
+            principles: self.principles,
+            wait_seconds: self.wait_seconds,
+        }
+    }
+}
+
+impl CatalogFile {
+    /// Returns a new `CatalogFile`.
+    ///
+    /// Returns an error if any trust and mood level, or trust level, is
+    /// missing a suggestion, if any suggestion is invalid, or if any rule has
+    /// an empty or repeated ID.
+    pub fn new(
+        suggestions: HashMap<CatalogCell, Suggestion>,
+        age_symptoms: HashMap<(Mood, AgeGroup), String>,
+        rules: Vec<Rule>,
+    ) -> Result<Self, CatalogFileError> {
+        if let Some(cell) = cells_required().find(|cell| !suggestions.contains_key(cell)) {
+            return Err(CatalogFileError::SuggestionMissing { cell });
+        }
+
+        let catalog_file = Self {
+            suggestions: suggestions
+                .into_iter()
+                .map(|(cell, suggestion)| (cell_normalize(cell), suggestion))
+                .collect(),
+            age_symptoms,
+            rules,
+        };
+        for (index, rule) in catalog_file.rules.iter().enumerate() {
+            if rule.id.trim().is_empty() {
+                return Err(CatalogFileError::RuleIdEmpty);
+            }
+            if catalog_file.rules[..index]
+                .iter()
+                .any(|rule_earlier| rule_earlier.id == rule.id)
+            {
+                return Err(CatalogFileError::RuleRepeated {
+                    rule: rule.id.to_string(),
+                });
+            }
+            if let Some(error) = rule.suggestion.validate().into_iter().next() {
+                return Err(CatalogFileError::RuleInvalid {
+                    rule: rule.id.to_string(),
+                    error,
+                });
+            }
+        }
+        let suggestion_error = catalog_file.iter().find_map(|(cell, suggestion)| {
+            suggestion
+                .validate()
+                .into_iter()
+                .next()
+                .map(|error| CatalogFileError::SuggestionInvalid { cell, error })
+        });
+
+        match suggestion_error {
+            Some(error) => Err(error),
+            None => Ok(catalog_file),
+        }
+    }
+
+    /// Parses a `CatalogFile` from the contents of a catalog file.
+    pub fn parse(catalog_toml: &str) -> Result<Self, CatalogFileError> {
+        let tables = toml::from_str::<CatalogFileTables<'_>>(catalog_toml)
+            .map_err(|error| CatalogFileError::Deserialize { error })?;
+
+        let mut suggestions = HashMap::with_capacity(cells_required().count());
+        for (trust_segment, mood_tables) in tables.suggestions {
+            let trust = trust_parse("", &trust_segment)?;
+            for (mood_segment, entry) in mood_tables {
+                let mood = mood_parse(&trust_segment, mood_segment)?;
+                suggestions.insert(
+                    CatalogCell::Suggestion(trust, mood),
+                    entry.into_suggestion(),
+                );
+            }
+        }
+        for (trust_segment, entry) in tables.trust_suggestions {
+            let trust = trust_parse("trust_suggestions", &trust_segment)?;
+            suggestions.insert(CatalogCell::TrustSuggestion(trust), entry.into_suggestion());
+        }
+        for (trust_segment, mood_tables) in tables.contexts {
+            let trust = trust_parse("contexts", &trust_segment)?;
+            for (mood_segment, context_tables) in mood_tables {
+                let table = format!("contexts.{trust_segment}");
+                let mood = mood_parse(&table, mood_segment.clone())?;
+                for (context_segment, entry) in context_tables {
+                    let context =
+                        SuggestionContext::from_id(&context_segment).ok_or_else(|| {
+                            CatalogFileError::ContextUnknown {
+                                table: format!("{table}.{mood_segment}"),
+                                context: context_segment,
+                            }
+                        })?;
+                    suggestions.insert(
+                        CatalogCell::ContextSuggestion(trust, mood, context),
+                        entry.into_suggestion(),
+                    );
+                }
+            }
+        }
+
+        let mut age_symptoms = HashMap::new();
+        for (mood_segment, age_group_symptoms) in tables.age_symptoms {
+            let mood = mood_parse("age_symptoms", mood_segment.clone())?;
+            for (age_group_segment, symptoms) in age_group_symptoms {
+                let age_group = AgeGroup::from_id(&age_group_segment).ok_or_else(|| {
+                    CatalogFileError::AgeGroupUnknown {
+                        table: format!("age_symptoms.{mood_segment}"),
+                        age_group: age_group_segment,
+                    }
+                })?;
+                age_symptoms.insert((mood, age_group), symptoms);
+            }
+        }
+
+        let rules = tables
+            .rules
+            .into_iter()
+            .map(CatalogFileRule::into_rule)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(suggestions, age_symptoms, rules)
+    }
+
+    /// Returns the catalog file that ships with `tears`.
+    pub fn builtin() -> Result<Self, CatalogFileError> {
+        Self::parse(include_str!("../catalog/suggestions.toml"))
+    }
+
+    /// Returns the suggestion for the given cell.
+    pub fn get(&self, cell: CatalogCell) -> Option<&Suggestion> {
+        self.suggestions.get(&cell_normalize(cell))
+    }
+
+    /// Replaces the suggestion for the given cell, or adds it if the cell is
+    /// for a context that has no suggestion yet.
+    ///
+    /// Returns an error and leaves the catalog unchanged if the suggestion is
+    /// invalid, see [`Suggestion::validate`].
+    pub fn set(
+        &mut self,
+        cell: CatalogCell,
+        suggestion: Suggestion,
+    ) -> Result<(), CatalogFileError> {
+        let cell = cell_normalize(cell);
+        match suggestion.validate().into_iter().next() {
+            Some(error) => Err(CatalogFileError::SuggestionInvalid { cell, error }),
+            None => {
+                self.suggestions.insert(cell, suggestion);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns an iterator over every suggestion.
+    ///
+    /// For each trust level in the order of [`Trust::iter`], the general
+    /// guidance comes first, then each mood in the order of [`Mood::iter`].
+    /// Each mood's suggestion is followed by its suggestions for a context,
+    /// from the most to the least specific.
+    pub fn iter(&self) -> impl Iterator<Item = (CatalogCell, &Suggestion)> {
+        let mut contexts = self
+            .suggestions
+            .keys()
+            .filter_map(|cell| match cell {
+                CatalogCell::ContextSuggestion(_, _, context) => Some(*context),
+                CatalogCell::Suggestion(..) | CatalogCell::TrustSuggestion(_) => None,
+            })
+            .collect::<Vec<_>>();
+        contexts.sort_unstable_by_key(|context| context.specificity_order());
+        contexts.dedup();
+
+        let mut cells = Vec::with_capacity(self.suggestions.len());
+        for trust in Trust::iter() {
+            cells.push(CatalogCell::TrustSuggestion(trust));
+            for mood in Mood::iter() {
+                cells.push(CatalogCell::Suggestion(trust, mood));
+                cells.extend(
+                    contexts
+                        .iter()
+                        .map(|context| CatalogCell::ContextSuggestion(trust, mood, *context)),
+                );
+            }
+        }
+
+        cells
+            .into_iter()
+            .filter_map(|cell| self.get(cell).map(|suggestion| (cell, suggestion)))
+    }
+
+    /// Returns an iterator over the age specific symptoms of each mood, in the
+    /// order of [`Mood::iter`] and [`AgeGroup::iter`].
+    pub fn iter_age_symptoms(&self) -> impl Iterator<Item = (Mood, AgeGroup, &str)> {
+        Mood::iter()
+            .flat_map(|mood| AgeGroup::iter().map(move |age_group| (mood, age_group)))
+            .filter_map(|(mood, age_group)| {
+                self.age_symptoms
+                    .get(&(mood, age_group))
+                    .map(|symptoms| (mood, age_group, symptoms.as_str()))
+            })
+    }
+
+    /// Returns the rules written by content authors, in the order they are
+    /// listed.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Returns the contents of a catalog file for this catalog.
+    pub fn to_toml(&self) -> Result<String, CatalogFileError> {
+        let entry = |cell| self.get(cell).map(CatalogFileEntry::new);
+
+        let suggestions = TablesOrdered(
+            Trust::iter()
+                .map(|trust| {
+                    let mood_tables = Mood::iter()
+                        .filter_map(|mood| {
+                            entry(CatalogCell::Suggestion(trust, mood))
+                                .map(|entry| (segment(mood.name()), entry))
+                        })
+                        .collect();
+                    (segment(trust.name()), TablesOrdered(mood_tables))
+                })
+                .collect(),
+        );
+        let trust_suggestions = TablesOrdered(
+            Trust::iter()
+                .filter_map(|trust| {
+                    entry(CatalogCell::TrustSuggestion(trust))
+                        .map(|entry| (segment(trust.name()), entry))
+                })
+                .collect(),
+        );
+        let mut contexts = TablesOrdered(Vec::new());
+        self.iter().for_each(|(cell, suggestion)| {
+            let CatalogCell::ContextSuggestion(trust, mood, context) = cell else {
+                return;
+            };
+            let mood_tables = contexts.entry(segment(trust.name()));
+            mood_tables
+                .entry(segment(mood.name()))
+                .0
+                .push((context.id(), CatalogFileEntry::new(suggestion)));
+        });
+        let mut age_symptoms = TablesOrdered(Vec::new());
+        self.iter_age_symptoms()
+            .for_each(|(mood, age_group, symptoms)| {
+                age_symptoms
+                    .entry(segment(mood.name()))
+                    .0
+                    .push((age_group.id().to_string(), symptoms));
+            });
+
+        toml::to_string(&CatalogFileTablesOrdered {
+            suggestions,
+            trust_suggestions,
+            contexts,
+            age_symptoms,
+            rules: self.rules.iter().map(CatalogFileRule::new).collect(),
+        })
+        .map(|tables| format!("{CATALOG_FILE_HEADER}{tables}"))
+        .map_err(|error| CatalogFileError::Serialize { error })
+    }
+}
+
+/// The tables of a [`CatalogFile`], in the order they are written.
+#[derive(Serialize)]
+struct CatalogFileTablesOrdered<'catalog> {
+    #[serde(flatten)]
+    suggestions: TablesOrdered<TablesOrdered<CatalogFileEntry<'catalog>>>,
+    #[serde(skip_serializing_if = "TablesOrdered::is_empty")]
+    trust_suggestions: TablesOrdered<CatalogFileEntry<'catalog>>,
+    #[serde(skip_serializing_if = "TablesOrdered::is_empty")]
+    contexts: TablesOrdered<TablesOrdered<TablesOrdered<CatalogFileEntry<'catalog>>>>,
+    #[serde(skip_serializing_if = "TablesOrdered::is_empty")]
+    age_symptoms: TablesOrdered<TablesOrdered<&'catalog str>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<CatalogFileRule<'catalog>>,
+}
+
+/// Tables that are serialized in the order they were added.
+struct TablesOrdered<T>(Vec<(String, T)>);
+
+impl<T> TablesOrdered<T> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> TablesOrdered<TablesOrdered<T>> {
+    /// Returns the table with the given name, adding it at the end if needed.
+    fn entry(&mut self, name: String) -> &mut TablesOrdered<T> {
+        let index = match self
+            .0
+            .iter()
+            .position(|(name_existing, _)| *name_existing == name)
+        {
+            Some(index) => index,
+            None => {
+                self.0.push((name, TablesOrdered(Vec::new())));
+                self.0.len() - 1
+            }
+        };
+        &mut self.0[index].1
+    }
+}
+
+impl<T> Serialize for TablesOrdered<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        self.0
+            .iter()
+            .try_for_each(|(name, table)| map.serialize_entry(name, table))?;
+        map.end()
+    }
+}
+
+/// Returns whether a rule has the priority that [`Rule::new`] gives it.
+fn priority_is_default(priority: &i32) -> bool {
+    *priority == 0
+}
+
+/// Returns the cells that every catalog file must have a suggestion for.
+fn cells_required() -> impl Iterator<Item = CatalogCell> {
+    Trust::iter().flat_map(|trust| {
+        std::iter::once(CatalogCell::TrustSuggestion(trust))
+            .chain(Mood::iter().map(move |mood| CatalogCell::Suggestion(trust, mood)))
+    })
+}
+
+/// Returns the cell with an empty context as the cell for any context.
+fn cell_normalize(cell: CatalogCell) -> CatalogCell {
+    CatalogCell::new(cell.trust(), cell.mood(), cell.context())
+}
+
+/// Returns the trust level named by a table within `table`, which is empty for
+/// the top level.
+fn trust_parse(table: &str, trust_segment: &str) -> Result<Trust, CatalogFileError> {
+    Trust::iter()
+        .find(|trust| trust_segment == segment(trust.name()))
+        .ok_or_else(|| CatalogFileError::TrustUnknown {
+            trust: if table.is_empty() {
+                trust_segment.to_string()
+            } else {
+                format!("{table}.{trust_segment}")
+            },
+        })
+}
+
+/// Returns the mood named by a table within `table`.
+fn mood_parse(table: &str, mood_segment: String) -> Result<Mood, CatalogFileError> {
+    Mood::iter()
+        .find(|mood| mood_segment == segment(mood.name()))
+        .ok_or_else(|| CatalogFileError::MoodUnknown {
+            table: table.to_string(),
+            mood: mood_segment,
+        })
+}
+
+/// Returns the table name for a trust level or mood.
+fn segment(name: &str) -> String {
+    name.to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CatalogFile, CatalogFileError, Comparison, Mood, Predicate, Trust};
+
+    /// Returns the built in catalog file's contents with `toml` added.
+    fn catalog_toml_with(toml: &str) -> String {
+        format!("{}{toml}", include_str!("../catalog/suggestions.toml"))
+    }
+
+    #[test]
+    fn rules_are_parsed_and_written_back() {
+        let catalog_toml = catalog_toml_with(
+            r#"
+[[rules]]
+id = "present-overwhelmed"
+when = ["trust == Present", "mood <= Closed"]
+priority = 2
+action = "Stay close, and say little"
+description = "Your presence is enough for now."
+principles = ["no_pressure"]
+"#,
+        );
+
+        let catalog_file = CatalogFile::parse(&catalog_toml).expect("catalog file is valid");
+        let rule = &catalog_file.rules()[0];
+        assert_eq!("present-overwhelmed", rule.id);
+        assert_eq!(
+            vec![
+                Predicate::Trust(Trust::Present),
+                Predicate::Mood(Comparison::Le, Mood::_02_Closed),
+            ],
+            rule.predicates
+        );
+        assert_eq!(2, rule.priority);
+
+        let catalog_toml_written = catalog_file.to_toml().expect("catalog file serializes");
+        assert_eq!(catalog_toml, catalog_toml_written);
+    }
+
+    #[test]
+    fn rules_with_unknown_conditions_are_rejected() {
+        let catalog_toml = catalog_toml_with(
+            r#"
+[[rules]]
+id = "sad"
+when = ["mood <= Sad"]
+action = "Sit with them"
+description = "Be there."
+principles = ["no_pressure"]
+"#,
+        );
+
+        assert!(matches!(
+            CatalogFile::parse(&catalog_toml),
+            Err(CatalogFileError::RulePredicateUnknown { rule, predicate })
+                if rule == "sad" && predicate == "mood <= Sad"
+        ));
+    }
+
+    #[test]
+    fn rules_with_repeated_ids_are_rejected() {
+        let rule_toml = r#"
+[[rules]]
+id = "calm"
+when = ["mood == Calm"]
+action = "Offer a walk"
+description = "Fresh air can help."
+principles = ["small_offers"]
+"#;
+        let catalog_toml = catalog_toml_with(&rule_toml.repeat(2));
+
+        assert!(matches!(
+            CatalogFile::parse(&catalog_toml),
+            Err(CatalogFileError::RuleRepeated { rule }) if rule == "calm"
+        ));
+    }
+}
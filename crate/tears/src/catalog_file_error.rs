@@ -0,0 +1,94 @@
+use crate::{CatalogCell, SuggestionError};
+
+/// Errors when loading or saving a [`CatalogFile`].
+///
+/// [`CatalogFile`]: crate::CatalogFile
+#[derive(Debug, thiserror::Error)]
+pub enum CatalogFileError {
+    /// The catalog file is not valid TOML, or its entries are malformed.
+    #[error("Failed to deserialize the catalog file.")]
+    Deserialize {
+        /// The underlying error.
+        #[source]
+        error: toml::de::Error,
+    },
+    /// The catalog could not be serialized as TOML.
+    #[error("Failed to serialize the catalog file.")]
+    Serialize {
+        /// The underlying error.
+        #[source]
+        error: toml::ser::Error,
+    },
+    /// A table in the catalog file is not a known trust level.
+    #[error("`{trust}` in the catalog file is not a known trust level.")]
+    TrustUnknown {
+        /// The unknown trust level, after the table containing it if any, e.g.
+        /// `contexts.absnt`.
+        trust: String,
+    },
+    /// A table in the catalog file is not a known mood.
+    #[error("`{table}.{mood}` in the catalog file is not a known mood.")]
+    MoodUnknown {
+        /// Table containing the mood, e.g. `absent`.
+        table: String,
+        /// The unknown mood.
+        mood: String,
+    },
+    /// A table in the catalog file is not a known, non-empty context.
+    #[error("`{table}.{context}` in the catalog file is not a known context.")]
+    ContextUnknown {
+        /// Table containing the context, e.g. `contexts.absent.closed`.
+        table: String,
+        /// The unknown context.
+        context: String,
+    },
+    /// A key in the catalog file is not a known age group.
+    #[error("`{table}.{age_group}` in the catalog file is not a known age group.")]
+    AgeGroupUnknown {
+        /// Table containing the age group, e.g. `age_symptoms.closed`.
+        table: String,
+        /// The unknown age group.
+        age_group: String,
+    },
+    /// A required suggestion is missing from the catalog file.
+    #[error("The catalog file has no suggestion for `{cell}`.")]
+    SuggestionMissing {
+        /// The missing suggestion.
+        cell: CatalogCell,
+    },
+    /// A suggestion in the catalog file is invalid.
+    #[error("The suggestion for `{cell}` is invalid.")]
+    SuggestionInvalid {
+        /// The invalid suggestion.
+        cell: CatalogCell,
+        /// The underlying error.
+        #[source]
+        error: SuggestionError,
+    },
+    /// A rule in the catalog file has an empty ID.
+    #[error("Every rule in the catalog file needs an `id`.")]
+    RuleIdEmpty,
+    /// More than one rule in the catalog file has the same ID.
+    #[error("More than one rule in the catalog file has the ID `{rule}`.")]
+    RuleRepeated {
+        /// ID of the rules.
+        rule: String,
+    },
+    /// A condition of a rule in the catalog file can't be parsed.
+    #[error("`{predicate}` in the rule `{rule}` is not a known condition.")]
+    RulePredicateUnknown {
+        /// ID of the rule.
+        rule: String,
+        /// The condition as written.
+        predicate: String,
+    },
+    /// The suggestion of a rule in the catalog file is invalid.
+    #[error("The suggestion of the rule `{rule}` is invalid.")]
+    RuleInvalid {
+        /// ID of the rule.
+        rule: String,
+        /// The underlying error.
+        #[source]
+        error: SuggestionError,
+    },
+}
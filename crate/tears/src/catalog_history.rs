@@ -0,0 +1,83 @@
+use serde::Deserialize;
+
+use crate::{CatalogCell, CatalogHistoryError, CatalogRevision};
+
+/// Revisions of the suggestions in a [`CatalogFile`], oldest first.
+///
+/// History files are TOML files that revisions are appended to:
+///
+/// ```toml
+/// [[revisions]]
+/// id = 1
+/// author = "ana"
+/// timestamp = "2026-10-18T09:30:00Z"
+/// message = "Soften the wording."
+/// trust = "Absent"
+/// mood = "Closed"
+///
+/// [revisions.suggestion_previous]
+/// action = "Stay away"
+/// description = "Your presence pressurizes the person."
+///
+/// [revisions.suggestion]
+/// action = "Give them space"
+/// description = "Your presence pressurizes the person."
+/// ```
+///
+/// [`CatalogFile`]: crate::CatalogFile
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogHistory {
+    /// Every revision, oldest first.
+    #[serde(default)]
+    revisions: Vec<CatalogRevision>,
+}
+
+impl CatalogHistory {
+    /// Returns an empty `CatalogHistory`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a `CatalogHistory` from the contents of a history file.
+    pub fn parse(history_toml: &str) -> Result<Self, CatalogHistoryError> {
+        let mut catalog_history = toml::from_str::<Self>(history_toml)
+            .map_err(|error| CatalogHistoryError::Deserialize { error })?;
+        catalog_history
+            .revisions
+            .sort_by_key(|revision| revision.id);
+
+        Ok(catalog_history)
+    }
+
+    /// Returns the revision with the given ID.
+    pub fn get(&self, id: u64) -> Option<&CatalogRevision> {
+        self.revisions.iter().find(|revision| revision.id == id)
+    }
+
+    /// Returns the ID for the next revision.
+    pub fn id_next(&self) -> u64 {
+        self.revisions.last().map_or(1, |revision| revision.id + 1)
+    }
+
+    /// Adds a revision to the end of the history.
+    pub fn push(&mut self, revision: CatalogRevision) {
+        self.revisions.push(revision);
+    }
+
+    /// Returns an iterator over every revision, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &CatalogRevision> {
+        self.revisions.iter()
+    }
+
+    /// Returns an iterator over the revisions of the given suggestion, oldest
+    /// first.
+    pub fn iter_cell(
+        &self,
+        cell: CatalogCell,
+    ) -> impl DoubleEndedIterator<Item = &CatalogRevision> {
+        self.revisions
+            .iter()
+            .filter(move |revision| revision.cell() == cell)
+    }
+}
@@ -0,0 +1,20 @@
+/// Errors when loading or saving a [`CatalogHistory`].
+///
+/// [`CatalogHistory`]: crate::CatalogHistory
+#[derive(Debug, thiserror::Error)]
+pub enum CatalogHistoryError {
+    /// The history file is not valid TOML, or its revisions are malformed.
+    #[error("Failed to deserialize the catalog history file.")]
+    Deserialize {
+        /// The underlying error.
+        #[source]
+        error: toml::de::Error,
+    },
+    /// A revision could not be serialized as TOML.
+    #[error("Failed to serialize the catalog revision.")]
+    Serialize {
+        /// The underlying error.
+        #[source]
+        error: toml::ser::Error,
+    },
+}
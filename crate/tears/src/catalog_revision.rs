@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CatalogCell, CatalogHistoryError, Mood, Suggestion, SuggestionContext, Trust, WordDiff,
+};
+
+/// A change to one suggestion in a [`CatalogFile`].
+///
+/// [`CatalogFile`]: crate::CatalogFile
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogRevision {
+    /// Number of this revision, counting from `1` across the whole catalog.
+    pub id: u64,
+    /// Name of the editor who made the change.
+    pub author: String,
+    /// When the change was made, in RFC 3339 format.
+    pub timestamp: String,
+    /// Why the change was made.
+    pub message: String,
+    /// Trust level of the suggestion that was changed.
+    pub trust: Trust,
+    /// Mood of the suggestion that was changed, which is `None` for the
+    /// general guidance for the trust level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mood: Option<Mood>,
+    /// Context of the suggestion that was changed, which is empty unless it
+    /// only applies in a context.
+    #[serde(default, skip_serializing_if = "SuggestionContext::is_empty")]
+    pub context: SuggestionContext,
+    /// Revision that this change restored, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restored_from: Option<u64>,
+    /// The suggestion before the change.
+    pub suggestion_previous: Suggestion,
+    /// The suggestion after the change.
+    pub suggestion: Suggestion,
+}
+
+impl CatalogRevision {
+    /// Returns the suggestion that was changed.
+    pub fn cell(&self) -> CatalogCell {
+        CatalogCell::new(self.trust, self.mood, self.context)
+    }
+
+    /// Returns the word level differences in the action.
+    pub fn action_diff(&self) -> WordDiff {
+        WordDiff::new(self.suggestion_previous.action(), self.suggestion.action())
+    }
+
+    /// Returns the word level differences in the description.
+    pub fn description_diff(&self) -> WordDiff {
+        WordDiff::new(
+            self.suggestion_previous.description(),
+            self.suggestion.description(),
+        )
+    }
+
+    /// Returns the word level differences in the one line glance, or `None`
+    /// if neither version has one.
+    pub fn glance_diff(&self) -> Option<WordDiff> {
+        (self.suggestion_previous.glance().is_some() || self.suggestion.glance().is_some()).then(
+            || {
+                WordDiff::new(
+                    self.suggestion_previous.glance().unwrap_or_default(),
+                    self.suggestion.glance().unwrap_or_default(),
+                )
+            },
+        )
+    }
+
+    /// Returns the word level differences in the extended explanation, or
+    /// `None` if neither version has one.
+    pub fn extended_diff(&self) -> Option<WordDiff> {
+        (self.suggestion_previous.extended().is_some() || self.suggestion.extended().is_some())
+            .then(|| {
+                WordDiff::new(
+                    self.suggestion_previous.extended().unwrap_or_default(),
+                    self.suggestion.extended().unwrap_or_default(),
+                )
+            })
+    }
+
+    /// Returns this revision as an entry to append to a history file.
+    pub fn to_toml(&self) -> Result<String, CatalogHistoryError> {
+        #[derive(Serialize)]
+        struct Revisions<'revision> {
+            revisions: [&'revision CatalogRevision; 1],
+        }
+
+        toml::to_string(&Revisions { revisions: [self] })
+            .map_err(|error| CatalogHistoryError::Serialize { error })
+    }
+}
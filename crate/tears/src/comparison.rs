@@ -0,0 +1,81 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// How a value in a [`Situation`] is compared against a value in a
+/// [`Predicate`].
+///
+/// [`Predicate`]: crate::Predicate
+/// [`Situation`]: crate::Situation
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Comparison {
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `==`
+    Eq,
+    /// `>=`
+    Ge,
+    /// `>`
+    Gt,
+}
+
+impl Comparison {
+    /// Returns an iterator over all variants of this `Comparison` enum.
+    pub fn iter() -> impl Iterator<Item = Comparison> {
+        [
+            Comparison::Lt,
+            Comparison::Le,
+            Comparison::Eq,
+            Comparison::Ge,
+            Comparison::Gt,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the operator for this comparison, e.g. `"<="`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Eq => "==",
+            Comparison::Ge => ">=",
+            Comparison::Gt => ">",
+        }
+    }
+
+    /// Returns whether `actual` compared to `expected` satisfies this
+    /// comparison.
+    pub fn compare<T>(self, actual: &T, expected: &T) -> bool
+    where
+        T: Ord,
+    {
+        let ordering = actual.cmp(expected);
+        match self {
+            Comparison::Lt => ordering == Ordering::Less,
+            Comparison::Le => ordering != Ordering::Greater,
+            Comparison::Eq => ordering == Ordering::Equal,
+            Comparison::Ge => ordering != Ordering::Less,
+            Comparison::Gt => ordering == Ordering::Greater,
+        }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.symbol().fmt(f)
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Comparison::iter()
+            .find(|comparison| comparison.symbol() == s)
+            .ok_or(())
+    }
+}
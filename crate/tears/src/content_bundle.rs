@@ -0,0 +1,144 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{ContentBundleError, ContentFingerprint, ContentKey, Locale, Template};
+
+/// Translations of the content into one locale.
+///
+/// Bundles are TOML files with one table per [`ContentKey`]:
+///
+/// ```toml
+/// ["mood.closed.symptoms"]
+/// text = "Silencio, mirada perdida. Poco movimiento."
+/// fingerprint = "6b692f26aa14d853"
+/// ```
+///
+/// Texts that are missing from the bundle fall back to English.
+///
+/// `fingerprint` is the [`ContentFingerprint`] of the English text that was
+/// translated, and is used to detect translations that are out of date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContentBundle {
+    /// Locale that the texts are in.
+    locale: Locale,
+    /// Translated text for each key.
+    texts: HashMap<ContentKey, String>,
+    /// Fingerprint of the English text each translation was made from.
+    fingerprints: HashMap<ContentKey, ContentFingerprint>,
+}
+
+/// An entry in a content bundle file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContentBundleEntry {
+    text: String,
+    #[serde(default)]
+    fingerprint: Option<String>,
+}
+
+impl ContentBundle {
+    /// Returns an empty `ContentBundle` for the given locale.
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            texts: HashMap::new(),
+            fingerprints: HashMap::new(),
+        }
+    }
+
+    /// Parses a `ContentBundle` from the contents of a bundle file.
+    pub fn parse(locale: Locale, bundle_toml: &str) -> Result<Self, ContentBundleError> {
+        let (content_bundle, keys_unknown) = Self::parse_with_unknown_keys(locale, bundle_toml)?;
+
+        match keys_unknown.into_iter().next() {
+            Some(key) => Err(ContentBundleError::KeyUnknown {
+                locale_code: locale.code(),
+                key,
+            }),
+            None => Ok(content_bundle),
+        }
+    }
+
+    /// Parses a `ContentBundle` from the contents of a bundle file, returning
+    /// the keys that are not known [`ContentKey`]s instead of failing.
+    ///
+    /// The unknown keys are sorted.
+    pub fn parse_with_unknown_keys(
+        locale: Locale,
+        bundle_toml: &str,
+    ) -> Result<(Self, Vec<String>), ContentBundleError> {
+        let entries = toml::from_str::<HashMap<String, ContentBundleEntry>>(bundle_toml).map_err(
+            |error| ContentBundleError::Deserialize {
+                locale_code: locale.code(),
+                error,
+            },
+        )?;
+
+        let mut content_bundle = Self::new(locale);
+        let mut keys_unknown = Vec::new();
+        for (key_str, entry) in entries {
+            let Ok(key) = ContentKey::from_str(&key_str) else {
+                keys_unknown.push(key_str);
+                continue;
+            };
+
+            if key.is_template() {
+                Template::parse(&entry.text).map_err(|error| {
+                    ContentBundleError::TemplateInvalid {
+                        locale_code: locale.code(),
+                        key: key_str.clone(),
+                        error,
+                    }
+                })?;
+            }
+            if let Some(fingerprint) = entry.fingerprint {
+                let fingerprint = ContentFingerprint::from_str(&fingerprint).map_err(|()| {
+                    ContentBundleError::FingerprintInvalid {
+                        locale_code: locale.code(),
+                        key: key_str,
+                        fingerprint,
+                    }
+                })?;
+                content_bundle.fingerprints.insert(key, fingerprint);
+            }
+            content_bundle.texts.insert(key, entry.text);
+        }
+        keys_unknown.sort();
+
+        Ok((content_bundle, keys_unknown))
+    }
+
+    /// Returns the bundle that ships with `tears` for the given locale.
+    ///
+    /// English is the base locale, so its bundle is empty.
+    pub fn builtin(locale: Locale) -> Result<Self, ContentBundleError> {
+        match locale {
+            Locale::En => Ok(Self::new(locale)),
+            Locale::Es => Self::parse(locale, include_str!("../locales/es.toml")),
+            Locale::Ar => Self::parse(locale, include_str!("../locales/ar.toml")),
+            Locale::He => Self::parse(locale, include_str!("../locales/he.toml")),
+        }
+    }
+
+    /// Returns the locale that the texts are in.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Returns the translated text for the given key, if any.
+    pub fn get(&self, key: ContentKey) -> Option<&str> {
+        self.texts.get(&key).map(String::as_str)
+    }
+
+    /// Returns the fingerprint of the English text that the translation for
+    /// the given key was made from, if recorded.
+    pub fn fingerprint(&self, key: ContentKey) -> Option<ContentFingerprint> {
+        self.fingerprints.get(&key).copied()
+    }
+
+    /// Returns an iterator over the translated texts.
+    pub fn iter(&self) -> impl Iterator<Item = (ContentKey, &str)> {
+        self.texts.iter().map(|(key, text)| (*key, text.as_str()))
+    }
+}
@@ -0,0 +1,50 @@
+/// Errors when loading a [`ContentBundle`].
+///
+/// [`ContentBundle`]: crate::ContentBundle
+#[derive(Debug, thiserror::Error)]
+pub enum ContentBundleError {
+    /// The content bundle is not valid TOML, or its entries are malformed.
+    #[error("Failed to deserialize the `{locale_code}` content bundle.")]
+    Deserialize {
+        /// Code of the locale whose bundle failed to deserialize.
+        locale_code: &'static str,
+        /// The underlying error.
+        #[source]
+        error: toml::de::Error,
+    },
+    /// An entry in the content bundle is not a known [`ContentKey`].
+    ///
+    /// [`ContentKey`]: crate::ContentKey
+    #[error("`{key}` in the `{locale_code}` content bundle is not a known content key.")]
+    KeyUnknown {
+        /// Code of the locale whose bundle contains the key.
+        locale_code: &'static str,
+        /// The unknown key.
+        key: String,
+    },
+    /// The fingerprint of an entry is not 16 hex digits.
+    #[error(
+        "`{key}` in the `{locale_code}` content bundle has an invalid fingerprint: `{fingerprint}`."
+    )]
+    FingerprintInvalid {
+        /// Code of the locale whose bundle contains the entry.
+        locale_code: &'static str,
+        /// Key of the entry.
+        key: String,
+        /// The invalid fingerprint.
+        fingerprint: String,
+    },
+    /// The text of a suggestion entry is not a valid [`Template`].
+    ///
+    /// [`Template`]: crate::Template
+    #[error("`{key}` in the `{locale_code}` content bundle is not a valid template.")]
+    TemplateInvalid {
+        /// Code of the locale whose bundle contains the entry.
+        locale_code: &'static str,
+        /// Key of the entry.
+        key: String,
+        /// The underlying error.
+        #[source]
+        error: crate::TemplateError,
+    },
+}
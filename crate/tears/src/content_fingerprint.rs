@@ -0,0 +1,47 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Fingerprint of the English text that a translation was made from.
+///
+/// When the English text changes, its fingerprint changes, so translations
+/// whose recorded fingerprint differs are out of date.
+///
+/// This is the 64 bit FNV-1a hash of the text, written as 16 lowercase hex
+/// digits. It is stable across platforms and Rust versions, unlike
+/// [`std::hash::DefaultHasher`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ContentFingerprint(u64);
+
+impl ContentFingerprint {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    /// Returns the fingerprint of the given text.
+    pub fn new(text: &str) -> Self {
+        let hash = text.bytes().fold(Self::FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(Self::FNV_PRIME)
+        });
+
+        Self(hash)
+    }
+}
+
+impl Display for ContentFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for ContentFingerprint {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 16 {
+            return Err(());
+        }
+
+        u64::from_str_radix(s, 16).map(Self).map_err(|_| ())
+    }
+}
@@ -0,0 +1,615 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    AgeGroup, AntiPattern, GuidanceBasis, HelperAction, Mood, Principle, Relationship, SayingKind,
+    Setting, SuggestionCatalog, SuggestionContext, Trust, UiText, Verdict,
+};
+
+/// Identifies a piece of text in a [`ContentBundle`].
+///
+/// Keys are written as dot separated segments, e.g. `mood.closed.symptoms`.
+///
+/// [`ContentBundle`]: crate::ContentBundle
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ContentKey {
+    /// `mood.<mood>.name`
+    MoodName(Mood),
+    /// `mood.<mood>.symptoms`
+    MoodSymptoms(Mood),
+    /// `mood.<mood>.summary`
+    MoodSummary(Mood),
+    /// `mood.<mood>.description`
+    MoodDescription(Mood),
+    /// `mood.<mood>.glance`
+    MoodGlance(Mood),
+    /// `mood.<mood>.extended`
+    MoodExtended(Mood),
+    /// `mood.<mood>.symptoms.<age_group>`
+    MoodSymptomsForAge(Mood, AgeGroup),
+    /// `trust.<trust>.name`
+    TrustName(Trust),
+    /// `trust.<trust>.description`
+    TrustDescription(Trust),
+    /// `trust.<trust>.glance`
+    TrustGlance(Trust),
+    /// `trust.<trust>.extended`
+    TrustExtended(Trust),
+    /// `age_group.<age_group>.name`
+    AgeGroupName(AgeGroup),
+    /// `relationship.<relationship>.name`
+    RelationshipName(Relationship),
+    /// `setting.<setting>.name`
+    SettingName(Setting),
+    /// `suggestion.<trust>.<mood>.action`
+    SuggestionAction(Trust, Mood),
+    /// `suggestion.<trust>.<mood>.description`
+    SuggestionDescription(Trust, Mood),
+    /// `suggestion.<trust>.<mood>.glance`
+    SuggestionGlance(Trust, Mood),
+    /// `suggestion.<trust>.<mood>.extended`
+    SuggestionExtended(Trust, Mood),
+    /// `suggestion.<trust>.<mood>.<context>.action`
+    ///
+    /// `<context>` is the known dimensions joined by `+`, e.g.
+    /// `colleague+work`, in the order age group, relationship, setting. The
+    /// context must not be empty.
+    ContextSuggestionAction(Trust, Mood, SuggestionContext),
+    /// `suggestion.<trust>.<mood>.<context>.description`
+    ContextSuggestionDescription(Trust, Mood, SuggestionContext),
+    /// `trust_suggestion.<trust>.action`
+    TrustSuggestionAction(Trust),
+    /// `trust_suggestion.<trust>.description`
+    TrustSuggestionDescription(Trust),
+    /// `principle.<principle>.name`
+    PrincipleName(Principle),
+    /// `principle.<principle>.explanation`
+    PrincipleExplanation(Principle),
+    /// `principle.<principle>.example.<n>`
+    ///
+    /// `<n>` counts from 1, while the index in this variant counts from 0.
+    PrincipleExample(Principle, usize),
+    /// `anti_pattern.<anti_pattern>.name`
+    AntiPatternName(AntiPattern),
+    /// `anti_pattern.<anti_pattern>.example`
+    AntiPatternExample(AntiPattern),
+    /// `anti_pattern.<anti_pattern>.reason`
+    AntiPatternReason(AntiPattern),
+    /// `anti_pattern.<anti_pattern>.instead`
+    AntiPatternInstead(AntiPattern),
+    /// `helper_action.<helper_action>.name`
+    HelperActionName(HelperAction),
+    /// `helper_action.<helper_action>.<verdict>`, for the verdicts that the
+    /// action may have
+    HelperActionReason(HelperAction, Verdict),
+    /// `saying.<trust>.<mood>.<saying_kind>.<n>`, where `<n>` counts from 1
+    Saying(Trust, Mood, SayingKind, usize),
+    /// `guidance_note.<basis>`
+    GuidanceNote(GuidanceBasis),
+    /// `ui.<id>`
+    Ui(UiText),
+}
+
+impl ContentKey {
+    /// Returns an iterator over every key that may have text.
+    ///
+    /// Whether a key has English text depends on the [`SuggestionCatalog`],
+    /// see [`ContentKey::base_text`].
+    pub fn iter() -> impl Iterator<Item = ContentKey> {
+        let mood_keys = Mood::iter().flat_map(|mood| {
+            [
+                ContentKey::MoodName(mood),
+                ContentKey::MoodSymptoms(mood),
+                ContentKey::MoodSummary(mood),
+                ContentKey::MoodDescription(mood),
+                ContentKey::MoodGlance(mood),
+                ContentKey::MoodExtended(mood),
+            ]
+        });
+        let trust_keys = Trust::iter().flat_map(|trust| {
+            [
+                ContentKey::TrustName(trust),
+                ContentKey::TrustDescription(trust),
+                ContentKey::TrustGlance(trust),
+                ContentKey::TrustExtended(trust),
+            ]
+        });
+        let mood_symptoms_for_age_keys = Mood::iter().flat_map(|mood| {
+            AgeGroup::iter().map(move |age_group| ContentKey::MoodSymptomsForAge(mood, age_group))
+        });
+        let age_group_keys = AgeGroup::iter().map(ContentKey::AgeGroupName);
+        let relationship_keys = Relationship::iter().map(ContentKey::RelationshipName);
+        let setting_keys = Setting::iter().map(ContentKey::SettingName);
+        let suggestion_keys = Trust::iter().flat_map(|trust| {
+            Mood::iter().flat_map(move |mood| {
+                [
+                    ContentKey::SuggestionAction(trust, mood),
+                    ContentKey::SuggestionDescription(trust, mood),
+                    ContentKey::SuggestionGlance(trust, mood),
+                    ContentKey::SuggestionExtended(trust, mood),
+                ]
+            })
+        });
+        let context_suggestion_keys = Trust::iter().flat_map(|trust| {
+            Mood::iter().flat_map(move |mood| {
+                contexts_non_empty().flat_map(move |context| {
+                    [
+                        ContentKey::ContextSuggestionAction(trust, mood, context),
+                        ContentKey::ContextSuggestionDescription(trust, mood, context),
+                    ]
+                })
+            })
+        });
+        let trust_suggestion_keys = Trust::iter().flat_map(|trust| {
+            [
+                ContentKey::TrustSuggestionAction(trust),
+                ContentKey::TrustSuggestionDescription(trust),
+            ]
+        });
+        let principle_keys = Principle::iter().flat_map(|principle| {
+            [
+                ContentKey::PrincipleName(principle),
+                ContentKey::PrincipleExplanation(principle),
+            ]
+            .into_iter()
+            .chain(
+                (0..principle.examples().len())
+                    .map(move |index| ContentKey::PrincipleExample(principle, index)),
+            )
+        });
+        let anti_pattern_keys = AntiPattern::iter().flat_map(|anti_pattern| {
+            [
+                ContentKey::AntiPatternName(anti_pattern),
+                ContentKey::AntiPatternExample(anti_pattern),
+                ContentKey::AntiPatternReason(anti_pattern),
+                ContentKey::AntiPatternInstead(anti_pattern),
+            ]
+        });
+        let helper_action_keys = HelperAction::iter().flat_map(|helper_action| {
+            [ContentKey::HelperActionName(helper_action)]
+                .into_iter()
+                .chain(
+                    Verdict::iter()
+                        .filter(move |verdict| helper_action.reason(*verdict).is_some())
+                        .map(move |verdict| ContentKey::HelperActionReason(helper_action, verdict)),
+                )
+        });
+        let saying_keys = Trust::iter().flat_map(|trust| {
+            Mood::iter().flat_map(move |mood| {
+                SayingKind::iter().flat_map(move |saying_kind| {
+                    (0..saying_kind.examples(trust, mood).len())
+                        .map(move |index| ContentKey::Saying(trust, mood, saying_kind, index))
+                })
+            })
+        });
+        let guidance_note_keys = GuidanceBasis::iter().map(ContentKey::GuidanceNote);
+        let ui_keys = UiText::iter().map(ContentKey::Ui);
+
+        mood_keys
+            .chain(mood_symptoms_for_age_keys)
+            .chain(trust_keys)
+            .chain(age_group_keys)
+            .chain(relationship_keys)
+            .chain(setting_keys)
+            .chain(suggestion_keys)
+            .chain(context_suggestion_keys)
+            .chain(trust_suggestion_keys)
+            .chain(principle_keys)
+            .chain(anti_pattern_keys)
+            .chain(helper_action_keys)
+            .chain(saying_keys)
+            .chain(guidance_note_keys)
+            .chain(ui_keys)
+    }
+
+    /// Returns whether the text for this key is a suggestion, which may use
+    /// [`Template`] syntax.
+    ///
+    /// [`Template`]: crate::Template
+    pub fn is_template(self) -> bool {
+        matches!(
+            self,
+            ContentKey::SuggestionAction(..)
+                | ContentKey::SuggestionDescription(..)
+                | ContentKey::SuggestionGlance(..)
+                | ContentKey::SuggestionExtended(..)
+                | ContentKey::ContextSuggestionAction(..)
+                | ContentKey::ContextSuggestionDescription(..)
+                | ContentKey::TrustSuggestionAction(_)
+                | ContentKey::TrustSuggestionDescription(_)
+        )
+    }
+
+    /// Returns the English text for this key, which translations are made
+    /// from.
+    ///
+    /// Returns `None` if there is no text for this key, e.g. a suggestion
+    /// that is not in the catalog.
+    pub fn base_text(self, suggestion_catalog: &SuggestionCatalog) -> Option<&str> {
+        match self {
+            ContentKey::MoodName(mood) => Some(mood.name()),
+            ContentKey::MoodSymptoms(mood) => Some(mood.symptoms()),
+            ContentKey::MoodSummary(mood) => Some(mood.summary()),
+            ContentKey::MoodDescription(mood) => Some(mood.description()),
+            ContentKey::MoodGlance(mood) => Some(mood.glance()),
+            ContentKey::MoodExtended(mood) => Some(mood.extended()),
+            ContentKey::MoodSymptomsForAge(mood, age_group) => {
+                suggestion_catalog.symptoms_for_age(mood, age_group)
+            }
+            ContentKey::TrustName(trust) => Some(trust.name()),
+            ContentKey::TrustDescription(trust) => Some(trust.description()),
+            ContentKey::TrustGlance(trust) => Some(trust.glance()),
+            ContentKey::TrustExtended(trust) => Some(trust.extended()),
+            ContentKey::AgeGroupName(age_group) => Some(age_group.name()),
+            ContentKey::RelationshipName(relationship) => Some(relationship.name()),
+            ContentKey::SettingName(setting) => Some(setting.name()),
+            ContentKey::SuggestionAction(trust, mood) => suggestion_catalog
+                .get(trust, mood)
+                .map(|suggestion| suggestion.action()),
+            ContentKey::SuggestionDescription(trust, mood) => suggestion_catalog
+                .get(trust, mood)
+                .map(|suggestion| suggestion.description()),
+            ContentKey::SuggestionGlance(trust, mood) => suggestion_catalog
+                .get(trust, mood)
+                .and_then(|suggestion| suggestion.glance()),
+            ContentKey::SuggestionExtended(trust, mood) => suggestion_catalog
+                .get(trust, mood)
+                .and_then(|suggestion| suggestion.extended()),
+            ContentKey::ContextSuggestionAction(trust, mood, context) => suggestion_catalog
+                .get_for_context(trust, mood, context)
+                .map(|suggestion| suggestion.action()),
+            ContentKey::ContextSuggestionDescription(trust, mood, context) => suggestion_catalog
+                .get_for_context(trust, mood, context)
+                .map(|suggestion| suggestion.description()),
+            ContentKey::TrustSuggestionAction(trust) => suggestion_catalog
+                .get_for_trust(trust)
+                .map(|suggestion| suggestion.action()),
+            ContentKey::TrustSuggestionDescription(trust) => suggestion_catalog
+                .get_for_trust(trust)
+                .map(|suggestion| suggestion.description()),
+            ContentKey::PrincipleName(principle) => Some(principle.name()),
+            ContentKey::PrincipleExplanation(principle) => Some(principle.explanation()),
+            ContentKey::PrincipleExample(principle, index) => {
+                principle.examples().get(index).copied()
+            }
+            ContentKey::AntiPatternName(anti_pattern) => Some(anti_pattern.name()),
+            ContentKey::AntiPatternExample(anti_pattern) => Some(anti_pattern.example()),
+            ContentKey::AntiPatternReason(anti_pattern) => Some(anti_pattern.reason()),
+            ContentKey::AntiPatternInstead(anti_pattern) => Some(anti_pattern.instead()),
+            ContentKey::HelperActionName(helper_action) => Some(helper_action.name()),
+            ContentKey::HelperActionReason(helper_action, verdict) => helper_action.reason(verdict),
+            ContentKey::Saying(trust, mood, saying_kind, index) => {
+                saying_kind.examples(trust, mood).get(index).copied()
+            }
+            ContentKey::GuidanceNote(guidance_basis) => guidance_basis.note(),
+            ContentKey::Ui(ui_text) => Some(ui_text.text()),
+        }
+    }
+}
+
+fn mood_segment(mood: Mood) -> String {
+    mood.name().to_lowercase()
+}
+
+fn trust_segment(trust: Trust) -> String {
+    trust.name().to_lowercase()
+}
+
+fn relationship_segment(relationship: Relationship) -> String {
+    relationship.name().to_lowercase()
+}
+
+fn setting_segment(setting: Setting) -> String {
+    setting.name().to_lowercase()
+}
+
+/// Returns every context that has at least one known dimension.
+fn contexts_non_empty() -> impl Iterator<Item = SuggestionContext> {
+    std::iter::once(None)
+        .chain(AgeGroup::iter().map(Some))
+        .flat_map(|age_group| {
+            std::iter::once(None)
+                .chain(Relationship::iter().map(Some))
+                .map(move |relationship| (age_group, relationship))
+        })
+        .flat_map(|(age_group, relationship)| {
+            std::iter::once(None)
+                .chain(Setting::iter().map(Some))
+                .map(move |setting| SuggestionContext {
+                    age_group,
+                    relationship,
+                    setting,
+                })
+        })
+        .filter(|context| !context.is_empty())
+}
+
+fn guidance_basis_segment(guidance_basis: GuidanceBasis) -> &'static str {
+    match guidance_basis {
+        GuidanceBasis::TrustAndMood => "trust_and_mood",
+        GuidanceBasis::MoodOnly => "mood_only",
+        GuidanceBasis::TrustOnly => "trust_only",
+    }
+}
+
+fn mood_parse(segment: &str) -> Result<Mood, ()> {
+    Mood::iter()
+        .find(|mood| mood.name().eq_ignore_ascii_case(segment))
+        .ok_or(())
+}
+
+fn trust_parse(segment: &str) -> Result<Trust, ()> {
+    Trust::iter()
+        .find(|trust| trust.name().eq_ignore_ascii_case(segment))
+        .ok_or(())
+}
+
+fn relationship_parse(segment: &str) -> Result<Relationship, ()> {
+    Relationship::iter()
+        .find(|relationship| relationship.name().eq_ignore_ascii_case(segment))
+        .ok_or(())
+}
+
+fn setting_parse(segment: &str) -> Result<Setting, ()> {
+    Setting::iter()
+        .find(|setting| setting.name().eq_ignore_ascii_case(segment))
+        .ok_or(())
+}
+
+fn guidance_basis_parse(segment: &str) -> Result<GuidanceBasis, ()> {
+    GuidanceBasis::iter()
+        .find(|guidance_basis| guidance_basis_segment(*guidance_basis) == segment)
+        .ok_or(())
+}
+
+impl Display for ContentKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentKey::MoodName(mood) => write!(f, "mood.{}.name", mood_segment(*mood)),
+            ContentKey::MoodSymptoms(mood) => write!(f, "mood.{}.symptoms", mood_segment(*mood)),
+            ContentKey::MoodSummary(mood) => write!(f, "mood.{}.summary", mood_segment(*mood)),
+            ContentKey::MoodDescription(mood) => {
+                write!(f, "mood.{}.description", mood_segment(*mood))
+            }
+            ContentKey::MoodGlance(mood) => write!(f, "mood.{}.glance", mood_segment(*mood)),
+            ContentKey::MoodExtended(mood) => write!(f, "mood.{}.extended", mood_segment(*mood)),
+            ContentKey::MoodSymptomsForAge(mood, age_group) => write!(
+                f,
+                "mood.{}.symptoms.{}",
+                mood_segment(*mood),
+                age_group.id()
+            ),
+            ContentKey::TrustName(trust) => write!(f, "trust.{}.name", trust_segment(*trust)),
+            ContentKey::TrustDescription(trust) => {
+                write!(f, "trust.{}.description", trust_segment(*trust))
+            }
+            ContentKey::TrustGlance(trust) => write!(f, "trust.{}.glance", trust_segment(*trust)),
+            ContentKey::TrustExtended(trust) => {
+                write!(f, "trust.{}.extended", trust_segment(*trust))
+            }
+            ContentKey::AgeGroupName(age_group) => {
+                write!(f, "age_group.{}.name", age_group.id())
+            }
+            ContentKey::RelationshipName(relationship) => {
+                write!(
+                    f,
+                    "relationship.{}.name",
+                    relationship_segment(*relationship)
+                )
+            }
+            ContentKey::SettingName(setting) => {
+                write!(f, "setting.{}.name", setting_segment(*setting))
+            }
+            ContentKey::SuggestionAction(trust, mood) => write!(
+                f,
+                "suggestion.{}.{}.action",
+                trust_segment(*trust),
+                mood_segment(*mood)
+            ),
+            ContentKey::SuggestionDescription(trust, mood) => write!(
+                f,
+                "suggestion.{}.{}.description",
+                trust_segment(*trust),
+                mood_segment(*mood)
+            ),
+            ContentKey::SuggestionGlance(trust, mood) => write!(
+                f,
+                "suggestion.{}.{}.glance",
+                trust_segment(*trust),
+                mood_segment(*mood)
+            ),
+            ContentKey::SuggestionExtended(trust, mood) => write!(
+                f,
+                "suggestion.{}.{}.extended",
+                trust_segment(*trust),
+                mood_segment(*mood)
+            ),
+            ContentKey::ContextSuggestionAction(trust, mood, context) => write!(
+                f,
+                "suggestion.{}.{}.{}.action",
+                trust_segment(*trust),
+                mood_segment(*mood),
+                context.id()
+            ),
+            ContentKey::ContextSuggestionDescription(trust, mood, context) => write!(
+                f,
+                "suggestion.{}.{}.{}.description",
+                trust_segment(*trust),
+                mood_segment(*mood),
+                context.id()
+            ),
+            ContentKey::TrustSuggestionAction(trust) => {
+                write!(f, "trust_suggestion.{}.action", trust_segment(*trust))
+            }
+            ContentKey::TrustSuggestionDescription(trust) => {
+                write!(f, "trust_suggestion.{}.description", trust_segment(*trust))
+            }
+            ContentKey::PrincipleName(principle) => {
+                write!(f, "principle.{}.name", principle.id())
+            }
+            ContentKey::PrincipleExplanation(principle) => {
+                write!(f, "principle.{}.explanation", principle.id())
+            }
+            ContentKey::PrincipleExample(principle, index) => {
+                write!(f, "principle.{}.example.{}", principle.id(), index + 1)
+            }
+            ContentKey::AntiPatternName(anti_pattern) => {
+                write!(f, "anti_pattern.{}.name", anti_pattern.id())
+            }
+            ContentKey::AntiPatternExample(anti_pattern) => {
+                write!(f, "anti_pattern.{}.example", anti_pattern.id())
+            }
+            ContentKey::AntiPatternReason(anti_pattern) => {
+                write!(f, "anti_pattern.{}.reason", anti_pattern.id())
+            }
+            ContentKey::AntiPatternInstead(anti_pattern) => {
+                write!(f, "anti_pattern.{}.instead", anti_pattern.id())
+            }
+            ContentKey::HelperActionName(helper_action) => {
+                write!(f, "helper_action.{}.name", helper_action.id())
+            }
+            ContentKey::HelperActionReason(helper_action, verdict) => {
+                write!(f, "helper_action.{}.{}", helper_action.id(), verdict.id())
+            }
+            ContentKey::Saying(trust, mood, saying_kind, index) => write!(
+                f,
+                "saying.{}.{}.{}.{}",
+                trust_segment(*trust),
+                mood_segment(*mood),
+                saying_kind.id(),
+                index + 1
+            ),
+            ContentKey::GuidanceNote(guidance_basis) => {
+                write!(
+                    f,
+                    "guidance_note.{}",
+                    guidance_basis_segment(*guidance_basis)
+                )
+            }
+            ContentKey::Ui(ui_text) => write!(f, "ui.{}", ui_text.id()),
+        }
+    }
+}
+
+impl FromStr for ContentKey {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments = s.split('.').collect::<Vec<_>>();
+        match segments.as_slice() {
+            ["mood", mood, "name"] => mood_parse(mood).map(ContentKey::MoodName),
+            ["mood", mood, "symptoms"] => mood_parse(mood).map(ContentKey::MoodSymptoms),
+            ["mood", mood, "summary"] => mood_parse(mood).map(ContentKey::MoodSummary),
+            ["mood", mood, "description"] => mood_parse(mood).map(ContentKey::MoodDescription),
+            ["mood", mood, "glance"] => mood_parse(mood).map(ContentKey::MoodGlance),
+            ["mood", mood, "extended"] => mood_parse(mood).map(ContentKey::MoodExtended),
+            ["mood", mood, "symptoms", age_group] => Ok(ContentKey::MoodSymptomsForAge(
+                mood_parse(mood)?,
+                AgeGroup::from_id(age_group).ok_or(())?,
+            )),
+            ["trust", trust, "name"] => trust_parse(trust).map(ContentKey::TrustName),
+            ["trust", trust, "description"] => trust_parse(trust).map(ContentKey::TrustDescription),
+            ["trust", trust, "glance"] => trust_parse(trust).map(ContentKey::TrustGlance),
+            ["trust", trust, "extended"] => trust_parse(trust).map(ContentKey::TrustExtended),
+            ["age_group", age_group, "name"] => AgeGroup::from_id(age_group)
+                .map(ContentKey::AgeGroupName)
+                .ok_or(()),
+            ["relationship", relationship, "name"] => {
+                relationship_parse(relationship).map(ContentKey::RelationshipName)
+            }
+            ["setting", setting, "name"] => setting_parse(setting).map(ContentKey::SettingName),
+            ["suggestion", trust, mood, "action"] => Ok(ContentKey::SuggestionAction(
+                trust_parse(trust)?,
+                mood_parse(mood)?,
+            )),
+            ["suggestion", trust, mood, "description"] => Ok(ContentKey::SuggestionDescription(
+                trust_parse(trust)?,
+                mood_parse(mood)?,
+            )),
+            ["suggestion", trust, mood, "glance"] => Ok(ContentKey::SuggestionGlance(
+                trust_parse(trust)?,
+                mood_parse(mood)?,
+            )),
+            ["suggestion", trust, mood, "extended"] => Ok(ContentKey::SuggestionExtended(
+                trust_parse(trust)?,
+                mood_parse(mood)?,
+            )),
+            ["suggestion", trust, mood, context, "action"] => {
+                Ok(ContentKey::ContextSuggestionAction(
+                    trust_parse(trust)?,
+                    mood_parse(mood)?,
+                    SuggestionContext::from_id(context).ok_or(())?,
+                ))
+            }
+            ["suggestion", trust, mood, context, "description"] => {
+                Ok(ContentKey::ContextSuggestionDescription(
+                    trust_parse(trust)?,
+                    mood_parse(mood)?,
+                    SuggestionContext::from_id(context).ok_or(())?,
+                ))
+            }
+            ["trust_suggestion", trust, "action"] => {
+                trust_parse(trust).map(ContentKey::TrustSuggestionAction)
+            }
+            ["trust_suggestion", trust, "description"] => {
+                trust_parse(trust).map(ContentKey::TrustSuggestionDescription)
+            }
+            ["principle", principle, "name"] => Principle::from_id(principle)
+                .map(ContentKey::PrincipleName)
+                .ok_or(()),
+            ["principle", principle, "explanation"] => Principle::from_id(principle)
+                .map(ContentKey::PrincipleExplanation)
+                .ok_or(()),
+            ["principle", principle, "example", n] => {
+                let principle = Principle::from_id(principle).ok_or(())?;
+                match n.parse::<usize>() {
+                    Ok(n) if (1..=principle.examples().len()).contains(&n) => {
+                        Ok(ContentKey::PrincipleExample(principle, n - 1))
+                    }
+                    _ => Err(()),
+                }
+            }
+            ["anti_pattern", anti_pattern, "name"] => AntiPattern::from_id(anti_pattern)
+                .map(ContentKey::AntiPatternName)
+                .ok_or(()),
+            ["anti_pattern", anti_pattern, "example"] => AntiPattern::from_id(anti_pattern)
+                .map(ContentKey::AntiPatternExample)
+                .ok_or(()),
+            ["anti_pattern", anti_pattern, "reason"] => AntiPattern::from_id(anti_pattern)
+                .map(ContentKey::AntiPatternReason)
+                .ok_or(()),
+            ["anti_pattern", anti_pattern, "instead"] => AntiPattern::from_id(anti_pattern)
+                .map(ContentKey::AntiPatternInstead)
+                .ok_or(()),
+            ["helper_action", helper_action, "name"] => HelperAction::from_id(helper_action)
+                .map(ContentKey::HelperActionName)
+                .ok_or(()),
+            ["helper_action", helper_action, verdict] => {
+                let helper_action = HelperAction::from_id(helper_action).ok_or(())?;
+                let verdict = Verdict::from_str(verdict)?;
+                match helper_action.reason(verdict) {
+                    Some(_) => Ok(ContentKey::HelperActionReason(helper_action, verdict)),
+                    None => Err(()),
+                }
+            }
+            ["saying", trust, mood, saying_kind, n] => {
+                let trust = trust_parse(trust)?;
+                let mood = mood_parse(mood)?;
+                let saying_kind = SayingKind::from_str(saying_kind)?;
+                match n.parse::<usize>() {
+                    Ok(n) if (1..=saying_kind.examples(trust, mood).len()).contains(&n) => {
+                        Ok(ContentKey::Saying(trust, mood, saying_kind, n - 1))
+                    }
+                    _ => Err(()),
+                }
+            }
+            ["guidance_note", guidance_basis] => {
+                guidance_basis_parse(guidance_basis).map(ContentKey::GuidanceNote)
+            }
+            ["ui", id] => UiText::from_str(id).map(ContentKey::Ui),
+            _ => Err(()),
+        }
+    }
+}
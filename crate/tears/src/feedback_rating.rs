@@ -0,0 +1,65 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::UiText;
+
+/// How a suggestion turned out, as reported by someone who followed it.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum FeedbackRating {
+    /// The suggestion helped.
+    Helpful,
+    /// The suggestion made no difference.
+    NotHelpful,
+    /// The suggestion made things worse.
+    MadeItWorse,
+}
+
+impl FeedbackRating {
+    /// Returns an iterator over all variants of this `FeedbackRating` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = FeedbackRating> {
+        [
+            FeedbackRating::Helpful,
+            FeedbackRating::NotHelpful,
+            FeedbackRating::MadeItWorse,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the name of this rating, e.g. `"NotHelpful"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FeedbackRating::Helpful => "Helpful",
+            FeedbackRating::NotHelpful => "NotHelpful",
+            FeedbackRating::MadeItWorse => "MadeItWorse",
+        }
+    }
+
+    /// Returns the label for this rating in the user interface.
+    pub fn ui_text(self) -> UiText {
+        match self {
+            FeedbackRating::Helpful => UiText::FeedbackHelpful,
+            FeedbackRating::NotHelpful => UiText::FeedbackNotHelpful,
+            FeedbackRating::MadeItWorse => UiText::FeedbackMadeItWorse,
+        }
+    }
+}
+
+impl Display for FeedbackRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for FeedbackRating {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FeedbackRating::iter()
+            .find(|feedback_rating| feedback_rating.name() == s)
+            .ok_or(())
+    }
+}
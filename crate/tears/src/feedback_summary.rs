@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{FeedbackRating, Mood, Trust};
+
+/// Feedback on the suggestion for one trust and mood level, added up.
+///
+/// Feedback is anonymous, so this is all that is known about it.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FeedbackSummary {
+    /// Trust level of the suggestion.
+    pub trust: Trust,
+    /// Mood of the suggestion.
+    pub mood: Mood,
+    /// Number of times the suggestion was rated helpful.
+    pub helpful: u64,
+    /// Number of times the suggestion was rated not helpful.
+    pub not_helpful: u64,
+    /// Number of times the suggestion was rated as making it worse.
+    pub made_it_worse: u64,
+    /// Comments left with the ratings, in the order they were received.
+    pub comments: Vec<String>,
+}
+
+impl FeedbackSummary {
+    /// Returns a summary with no feedback.
+    pub fn new(trust: Trust, mood: Mood) -> Self {
+        Self {
+            trust,
+            mood,
+            helpful: 0,
+            not_helpful: 0,
+            made_it_worse: 0,
+            comments: Vec::new(),
+        }
+    }
+
+    /// Returns the number of times the suggestion was given the rating.
+    pub fn count(&self, feedback_rating: FeedbackRating) -> u64 {
+        match feedback_rating {
+            FeedbackRating::Helpful => self.helpful,
+            FeedbackRating::NotHelpful => self.not_helpful,
+            FeedbackRating::MadeItWorse => self.made_it_worse,
+        }
+    }
+
+    /// Adds `count` ratings to the summary.
+    pub fn add(&mut self, feedback_rating: FeedbackRating, count: u64) {
+        let total = match feedback_rating {
+            FeedbackRating::Helpful => &mut self.helpful,
+            FeedbackRating::NotHelpful => &mut self.not_helpful,
+            FeedbackRating::MadeItWorse => &mut self.made_it_worse,
+        };
+        *total += count;
+    }
+
+    /// Returns the number of ratings.
+    pub fn total(&self) -> u64 {
+        self.helpful + self.not_helpful + self.made_it_worse
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{GuidanceBasis, Suggestion, SuggestionContext};
+
+/// A suggestion looked up from a [`SuggestionCatalog`], and what it is based
+/// on.
+///
+/// [`SuggestionCatalog`]: crate::SuggestionCatalog
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Guidance {
+    /// The suggestion to show.
+    pub suggestion: Suggestion,
+    /// Which of the trust and mood levels the suggestion is based on.
+    pub basis: GuidanceBasis,
+    /// Suggestion for the upper bound of the mood range, if it differs from
+    /// `suggestion`.
+    ///
+    /// `suggestion` is for the lower bound, as that is the safer mood to act
+    /// on. This is what changes if the person is actually at the upper bound.
+    pub upper_bound: Option<Suggestion>,
+    /// Context that the suggestion applies to.
+    ///
+    /// This is the empty context when the suggestion is not specific to the
+    /// relationship or setting.
+    pub context: SuggestionContext,
+}
+
+impl Guidance {
+    pub fn suggestion(&self) -> &Suggestion {
+        &self.suggestion
+    }
+
+    pub fn basis(&self) -> GuidanceBasis {
+        self.basis
+    }
+
+    pub fn upper_bound(&self) -> Option<&Suggestion> {
+        self.upper_bound.as_ref()
+    }
+
+    pub fn context(&self) -> SuggestionContext {
+        self.context
+    }
+
+    /// Returns a note explaining why the suggestion may not fit the situation
+    /// exactly, if it is based on partial information.
+    pub fn note(&self) -> Option<&'static str> {
+        self.basis.note()
+    }
+}
@@ -0,0 +1,40 @@
+/// Which of the trust and mood levels a [`Guidance`] is based on.
+///
+/// [`Guidance`]: crate::Guidance
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GuidanceBasis {
+    /// Both the trust and mood levels are known.
+    TrustAndMood,
+    /// Only the mood is known, so the most cautious trust level is assumed.
+    MoodOnly,
+    /// Only the trust level is known, so the guidance is general.
+    TrustOnly,
+}
+
+impl GuidanceBasis {
+    /// Returns an iterator over all variants of this `GuidanceBasis` enum.
+    pub fn iter() -> impl Iterator<Item = GuidanceBasis> {
+        [
+            GuidanceBasis::TrustAndMood,
+            GuidanceBasis::MoodOnly,
+            GuidanceBasis::TrustOnly,
+        ]
+        .into_iter()
+    }
+
+    /// Returns a note explaining why the guidance may not fit the situation
+    /// exactly, if it is based on partial information.
+    pub fn note(self) -> Option<&'static str> {
+        match self {
+            GuidanceBasis::TrustAndMood => None,
+            GuidanceBasis::MoodOnly => Some(
+                "Trust is unknown, so this is the most cautious suggestion for \
+                this mood -- as if the person does not trust you.",
+            ),
+            GuidanceBasis::TrustOnly => Some(
+                "Mood is unknown, so this is general guidance for this trust \
+                level. Select a mood for a more specific suggestion.",
+            ),
+        }
+    }
+}
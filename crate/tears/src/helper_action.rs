@@ -0,0 +1,331 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{Mood, MoodRange, Trust, Verdict};
+
+/// A specific thing that a helper may have in mind to do, e.g. a hug.
+///
+/// Each action has a [`Verdict`] for every trust and mood level, with a reason
+/// for each verdict it may have.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HelperAction {
+    /// Give the person a hug.
+    Hug,
+    /// Suggest going for a walk.
+    SuggestWalk,
+    /// Bring the person something to eat or drink.
+    BringFood,
+    /// Ask the person what happened.
+    AskWhatHappened,
+    /// Sit quietly with the person.
+    SitWithThem,
+    /// Send the person a short message.
+    SendMessage,
+    /// Make plans to do something together.
+    MakePlans,
+    /// Make a joke to lighten the mood.
+    Joke,
+    /// Offer advice on what to do.
+    OfferAdvice,
+    /// Leave the person alone.
+    LeaveThemAlone,
+}
+
+impl HelperAction {
+    /// Returns an iterator over all variants of this `HelperAction` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = HelperAction> {
+        [
+            HelperAction::Hug,
+            HelperAction::SuggestWalk,
+            HelperAction::BringFood,
+            HelperAction::AskWhatHappened,
+            HelperAction::SitWithThem,
+            HelperAction::SendMessage,
+            HelperAction::MakePlans,
+            HelperAction::Joke,
+            HelperAction::OfferAdvice,
+            HelperAction::LeaveThemAlone,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the identifier of this action within content keys, e.g.
+    /// `"suggest_walk"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            HelperAction::Hug => "hug",
+            HelperAction::SuggestWalk => "suggest_walk",
+            HelperAction::BringFood => "bring_food",
+            HelperAction::AskWhatHappened => "ask_what_happened",
+            HelperAction::SitWithThem => "sit_with_them",
+            HelperAction::SendMessage => "send_message",
+            HelperAction::MakePlans => "make_plans",
+            HelperAction::Joke => "joke",
+            HelperAction::OfferAdvice => "offer_advice",
+            HelperAction::LeaveThemAlone => "leave_them_alone",
+        }
+    }
+
+    /// Returns the action with the given [`id`](Self::id), e.g.
+    /// `"suggest_walk"`.
+    pub fn from_id(id: &str) -> Option<HelperAction> {
+        HelperAction::iter().find(|helper_action| helper_action.id() == id)
+    }
+
+    /// Returns the name of this action, which completes "Is it okay to …",
+    /// e.g. `"suggest a walk"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            HelperAction::Hug => "give them a hug",
+            HelperAction::SuggestWalk => "suggest a walk",
+            HelperAction::BringFood => "bring them food or a drink",
+            HelperAction::AskWhatHappened => "ask what happened",
+            HelperAction::SitWithThem => "sit with them",
+            HelperAction::SendMessage => "send them a message",
+            HelperAction::MakePlans => "make plans together",
+            HelperAction::Joke => "make a joke",
+            HelperAction::OfferAdvice => "offer advice",
+            HelperAction::LeaveThemAlone => "leave them alone",
+        }
+    }
+
+    /// Returns the verdict on this action for the given trust and mood level.
+    pub fn verdict(self, trust: Trust, mood: Mood) -> Verdict {
+        use Verdict::{Avoid, Recommended, Risky};
+
+        // Verdicts for each mood, from Anguished to Hopeful.
+        let verdicts = match (self, trust) {
+            (HelperAction::Hug, Trust::Absent) => [Avoid, Avoid, Avoid, Risky, Risky, Risky],
+            (HelperAction::Hug, Trust::Present) => {
+                [Risky, Risky, Risky, Risky, Recommended, Recommended]
+            }
+            (HelperAction::SuggestWalk, _) => {
+                [Avoid, Avoid, Risky, Risky, Recommended, Recommended]
+            }
+            (HelperAction::BringFood, Trust::Absent) => [
+                Risky,
+                Recommended,
+                Recommended,
+                Recommended,
+                Recommended,
+                Recommended,
+            ],
+            (HelperAction::BringFood, Trust::Present) => [Recommended; 6],
+            (HelperAction::AskWhatHappened, Trust::Absent) => {
+                [Avoid, Avoid, Avoid, Risky, Risky, Recommended]
+            }
+            (HelperAction::AskWhatHappened, Trust::Present) => {
+                [Avoid, Avoid, Risky, Risky, Recommended, Recommended]
+            }
+            (HelperAction::SitWithThem, Trust::Absent) => {
+                [Avoid, Avoid, Risky, Risky, Recommended, Recommended]
+            }
+            (HelperAction::SitWithThem, Trust::Present) => [
+                Recommended,
+                Risky,
+                Recommended,
+                Recommended,
+                Recommended,
+                Recommended,
+            ],
+            (HelperAction::SendMessage, Trust::Absent) => [
+                Risky,
+                Recommended,
+                Recommended,
+                Recommended,
+                Recommended,
+                Recommended,
+            ],
+            (HelperAction::SendMessage, Trust::Present) => [Recommended; 6],
+            (HelperAction::MakePlans, _) => [Avoid, Avoid, Avoid, Risky, Recommended, Recommended],
+            (HelperAction::Joke, Trust::Absent) => [Avoid, Avoid, Avoid, Risky, Risky, Recommended],
+            (HelperAction::Joke, Trust::Present) => {
+                [Avoid, Avoid, Avoid, Risky, Recommended, Recommended]
+            }
+            (HelperAction::OfferAdvice, Trust::Absent) => {
+                [Avoid, Avoid, Avoid, Avoid, Risky, Risky]
+            }
+            (HelperAction::OfferAdvice, Trust::Present) => {
+                [Avoid, Avoid, Avoid, Risky, Risky, Recommended]
+            }
+            (HelperAction::LeaveThemAlone, Trust::Absent) => {
+                [Risky, Recommended, Recommended, Risky, Risky, Risky]
+            }
+            (HelperAction::LeaveThemAlone, Trust::Present) => {
+                [Risky, Risky, Risky, Risky, Risky, Risky]
+            }
+        };
+
+        verdicts[usize::from(mood.rank() - 1)]
+    }
+
+    /// Returns the least safe verdict on this action across the mood range.
+    ///
+    /// When the trust level is not known, the verdict for either trust level
+    /// is considered, i.e. the more cautious one.
+    pub fn verdict_cautious(self, trust: Option<Trust>, mood_range: MoodRange) -> Verdict {
+        let trusts = match trust {
+            Some(trust) => vec![trust],
+            None => Trust::iter().collect(),
+        };
+        trusts
+            .into_iter()
+            .flat_map(|trust| mood_range.iter().map(move |mood| self.verdict(trust, mood)))
+            .max()
+            .unwrap_or(Verdict::Avoid)
+    }
+
+    /// Returns the other actions with a safer verdict than this one, from the
+    /// safest.
+    ///
+    /// Verdicts are found with [`HelperAction::verdict_cautious`].
+    pub fn alternatives(self, trust: Option<Trust>, mood_range: MoodRange) -> Vec<HelperAction> {
+        let verdict = self.verdict_cautious(trust, mood_range);
+        let mut alternatives = HelperAction::iter()
+            .map(|helper_action| {
+                (
+                    helper_action.verdict_cautious(trust, mood_range),
+                    helper_action,
+                )
+            })
+            .filter(|(verdict_alternative, _)| *verdict_alternative < verdict)
+            .collect::<Vec<_>>();
+        alternatives.sort_by_key(|(verdict_alternative, _)| *verdict_alternative);
+        alternatives
+            .into_iter()
+            .map(|(_, helper_action)| helper_action)
+            .collect()
+    }
+
+    /// Returns why this action has the given verdict, or `None` if the action
+    /// never has that verdict.
+    pub fn reason(self, verdict: Verdict) -> Option<&'static str> {
+        let reason = match (self, verdict) {
+            (HelperAction::Hug, Verdict::Recommended) => {
+                "A hug from someone they trust can be a real comfort. Let them \
+                decide how long it lasts."
+            }
+            (HelperAction::Hug, Verdict::Risky) => {
+                "Some people want to be held, and some can't bear it right now. \
+                Ask first, and accept a no."
+            }
+            (HelperAction::Hug, Verdict::Avoid) => {
+                "Closeness from someone they don't trust can feel like pressure, \
+                or even a threat, however kindly it is meant."
+            }
+            (HelperAction::SuggestWalk, Verdict::Recommended) => {
+                "Walking side by side is easy company, and talking is optional."
+            }
+            (HelperAction::SuggestWalk, Verdict::Risky) => {
+                "They may not have the energy. Offer once, keep it short, and \
+                make it easy to say no."
+            }
+            (HelperAction::SuggestWalk, Verdict::Avoid) => {
+                "Going out asks for energy and a decision that they don't have \
+                right now."
+            }
+            (HelperAction::BringFood, Verdict::Recommended) => {
+                "Food or a drink left within reach, without comment, is care \
+                that asks nothing back."
+            }
+            (HelperAction::BringFood, Verdict::Risky) => {
+                "Approaching them may overwhelm them. Leave it nearby, or ask \
+                someone they trust to bring it."
+            }
+            (HelperAction::AskWhatHappened, Verdict::Recommended) => {
+                "They may be ready to talk. Listen without fixing, and let them \
+                stop whenever they want."
+            }
+            (HelperAction::AskWhatHappened, Verdict::Risky) => {
+                "They may not want to talk about it. Ask once, say that they \
+                don't have to answer, then wait."
+            }
+            (HelperAction::AskWhatHappened, Verdict::Avoid) => {
+                "A question asks them to relive it and explain it, which is too \
+                much right now."
+            }
+            (HelperAction::SitWithThem, Verdict::Recommended) => {
+                "A calm, quiet presence shows them that they are not alone, \
+                without asking anything of them."
+            }
+            (HelperAction::SitWithThem, Verdict::Risky) => {
+                "Your presence may feel like pressure. Stay within reach, but at \
+                a little distance, and leave if they seem tense."
+            }
+            (HelperAction::SitWithThem, Verdict::Avoid) => {
+                "Being near someone they don't trust pressures them, even \
+                without words."
+            }
+            (HelperAction::SendMessage, Verdict::Recommended) => {
+                "A short message, such as \"Thinking of you, no need to \
+                reply\", shows care from a distance."
+            }
+            (HelperAction::SendMessage, Verdict::Risky) => {
+                "Even a message asks for attention. Keep it short, and say that \
+                no reply is needed."
+            }
+            (HelperAction::MakePlans, Verdict::Recommended) => {
+                "Something to look forward to helps hope grow. Let them choose \
+                what to do."
+            }
+            (HelperAction::MakePlans, Verdict::Risky) => {
+                "Keep plans small, soon, and easy to cancel."
+            }
+            (HelperAction::MakePlans, Verdict::Avoid) => {
+                "Plans ask them to commit to a future that they can't picture \
+                right now."
+            }
+            (HelperAction::Joke, Verdict::Recommended) => {
+                "Laughing together is a good sign. Follow their lead."
+            }
+            (HelperAction::Joke, Verdict::Risky) => {
+                "Gentle humour may help, but watch how it lands, and stop if it \
+                doesn't."
+            }
+            (HelperAction::Joke, Verdict::Avoid) => {
+                "Humour can feel like you are not taking them seriously."
+            }
+            (HelperAction::OfferAdvice, Verdict::Recommended) => {
+                "They may welcome ideas now, but let them choose what to use."
+            }
+            (HelperAction::OfferAdvice, Verdict::Risky) => {
+                "Ask first, e.g. \"Do you want ideas, or just to talk?\""
+            }
+            (HelperAction::OfferAdvice, Verdict::Avoid) => {
+                "Advice can sound like \"you are doing it wrong\", when they have \
+                no energy to act on it."
+            }
+            (HelperAction::LeaveThemAlone, Verdict::Recommended) => {
+                "Distance lets them settle. Tell them once where you will be."
+            }
+            (HelperAction::LeaveThemAlone, Verdict::Risky) => {
+                "Space can help, but leaving may feel like being given up on. \
+                Say where you will be, and if they may be unsafe, stay within \
+                sight and get help."
+            }
+            (HelperAction::BringFood, Verdict::Avoid)
+            | (HelperAction::SendMessage, Verdict::Avoid)
+            | (HelperAction::LeaveThemAlone, Verdict::Avoid) => return None,
+        };
+
+        Some(reason)
+    }
+}
+
+impl Display for HelperAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for HelperAction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HelperAction::iter()
+            .find(|helper_action| helper_action.name() == s)
+            .ok_or(())
+    }
+}
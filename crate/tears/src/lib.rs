@@ -1,7 +1,82 @@
 //! Data types representing a stack trace.
 
-pub use crate::{mood::Mood, suggestion::Suggestion, trust::Trust};
+pub use crate::{
+    age_group::AgeGroup, anti_pattern::AntiPattern, catalog_cell::CatalogCell,
+    catalog_file::CatalogFile, catalog_file_error::CatalogFileError,
+    catalog_history::CatalogHistory, catalog_history_error::CatalogHistoryError,
+    catalog_revision::CatalogRevision, comparison::Comparison, content_bundle::ContentBundle,
+    content_bundle_error::ContentBundleError, content_fingerprint::ContentFingerprint,
+    content_key::ContentKey, feedback_rating::FeedbackRating, feedback_summary::FeedbackSummary,
+    guidance::Guidance, guidance_basis::GuidanceBasis, helper_action::HelperAction, locale::Locale,
+    localizer::Localizer, mood::Mood, mood_range::MoodRange, person_profile::PersonProfile,
+    person_profile_error::PersonProfileError, phrase_check::PhraseCheck, phrase_flag::PhraseFlag,
+    predicate::Predicate, predicate_outcome::PredicateOutcome, principle::Principle,
+    profile_list::ProfileList, profile_placeholder::ProfilePlaceholder,
+    reading_level::ReadingLevel, relationship::Relationship, rich_text::RichText,
+    rich_text_block::RichTextBlock, rich_text_span::RichTextSpan, rule::Rule,
+    rule_evaluation::RuleEvaluation, rule_explanation::RuleExplanation, rule_set::RuleSet,
+    saying_kind::SayingKind, setting::Setting, situation::Situation, suggestion::Suggestion,
+    suggestion_catalog::SuggestionCatalog, suggestion_context::SuggestionContext,
+    suggestion_error::SuggestionError, template::Template, template_error::TemplateError,
+    template_node::TemplateNode, text_direction::TextDirection, trust::Trust, ui_text::UiText,
+    verdict::Verdict, word_change::WordChange, word_diff::WordDiff,
+    word_diff_segment::WordDiffSegment,
+};
 
+mod age_group;
+mod anti_pattern;
+mod catalog_cell;
+mod catalog_file;
+mod catalog_file_error;
+mod catalog_history;
+mod catalog_history_error;
+mod catalog_revision;
+mod comparison;
+mod content_bundle;
+mod content_bundle_error;
+mod content_fingerprint;
+mod content_key;
+mod feedback_rating;
+mod feedback_summary;
+mod guidance;
+mod guidance_basis;
+mod helper_action;
+mod locale;
+mod localizer;
 mod mood;
+mod mood_range;
+mod person_profile;
+mod person_profile_error;
+mod phrase_check;
+mod phrase_flag;
+mod predicate;
+mod predicate_outcome;
+mod principle;
+mod profile_list;
+mod profile_placeholder;
+mod reading_level;
+mod relationship;
+mod rich_text;
+mod rich_text_block;
+mod rich_text_span;
+mod rule;
+mod rule_evaluation;
+mod rule_explanation;
+mod rule_set;
+mod saying_kind;
+mod setting;
+mod situation;
 mod suggestion;
+mod suggestion_catalog;
+mod suggestion_context;
+mod suggestion_error;
+mod template;
+mod template_error;
+mod template_node;
+mod text_direction;
 mod trust;
+mod ui_text;
+mod verdict;
+mod word_change;
+mod word_diff;
+mod word_diff_segment;
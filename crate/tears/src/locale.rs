@@ -0,0 +1,79 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::TextDirection;
+
+/// Language that the content is shown in.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Locale {
+    /// English, which all other locales fall back to.
+    #[default]
+    En,
+    /// Spanish.
+    Es,
+    /// Arabic.
+    Ar,
+    /// Hebrew.
+    He,
+}
+
+impl Locale {
+    /// Returns an iterator over all variants of this `Locale` enum.
+    pub fn iter() -> impl Iterator<Item = Locale> {
+        [Locale::En, Locale::Es, Locale::Ar, Locale::He].into_iter()
+    }
+
+    /// Returns the BCP 47 language tag for this locale, e.g. `"en"`.
+    ///
+    /// This is used for the `lang` attribute in HTML.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::Ar => "ar",
+            Locale::He => "he",
+        }
+    }
+
+    /// Returns the name of this locale in its own language, e.g. `"Español"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::Ar => "العربية",
+            Locale::He => "עברית",
+        }
+    }
+
+    /// Returns the direction that text in this locale is written in.
+    ///
+    /// This is used for the `dir` attribute in HTML.
+    pub fn text_direction(self) -> TextDirection {
+        match self {
+            Locale::En | Locale::Es => TextDirection::Ltr,
+            Locale::Ar | Locale::He => TextDirection::Rtl,
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "en" => Ok(Locale::En),
+            "es" => Ok(Locale::Es),
+            "ar" => Ok(Locale::Ar),
+            "he" => Ok(Locale::He),
+            _ => Err(()),
+        }
+    }
+}
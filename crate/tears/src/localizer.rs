@@ -0,0 +1,370 @@
+use std::borrow::Cow;
+
+use crate::{
+    AgeGroup, AntiPattern, ContentBundle, ContentBundleError, ContentFingerprint, ContentKey,
+    GuidanceBasis, HelperAction, Locale, Mood, PersonProfile, Principle, ReadingLevel,
+    Relationship, SayingKind, Setting, Situation, Suggestion, SuggestionCatalog, Template, Trust,
+    UiText, Verdict,
+};
+
+/// Returns text in the active locale, falling back to English when a
+/// translation is missing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Localizer {
+    /// Translations for the active locale.
+    bundle: ContentBundle,
+}
+
+impl Localizer {
+    /// Returns a `Localizer` using the built in bundle for the given locale.
+    pub fn new(locale: Locale) -> Result<Self, ContentBundleError> {
+        ContentBundle::builtin(locale).map(Self::with_bundle)
+    }
+
+    /// Returns a `Localizer` that uses the given bundle.
+    pub fn with_bundle(bundle: ContentBundle) -> Self {
+        Self { bundle }
+    }
+
+    /// Returns the active locale.
+    pub fn locale(&self) -> Locale {
+        self.bundle.locale()
+    }
+
+    /// Returns the translated text for the given key, or `base` if there is no
+    /// translation.
+    pub fn text<'s>(&'s self, key: ContentKey, base: &'s str) -> &'s str {
+        self.bundle.get(key).unwrap_or(base)
+    }
+
+    pub fn mood_name(&self, mood: Mood) -> &str {
+        self.text(ContentKey::MoodName(mood), mood.name())
+    }
+
+    pub fn mood_symptoms(&self, mood: Mood) -> &str {
+        self.text(ContentKey::MoodSymptoms(mood), mood.symptoms())
+    }
+
+    pub fn mood_summary(&self, mood: Mood) -> &str {
+        self.text(ContentKey::MoodSummary(mood), mood.summary())
+    }
+
+    pub fn mood_description(&self, mood: Mood) -> &str {
+        self.text(ContentKey::MoodDescription(mood), mood.description())
+    }
+
+    pub fn mood_glance(&self, mood: Mood) -> &str {
+        self.text(ContentKey::MoodGlance(mood), mood.glance())
+    }
+
+    pub fn mood_extended(&self, mood: Mood) -> &str {
+        self.text(ContentKey::MoodExtended(mood), mood.extended())
+    }
+
+    /// Returns the paragraphs that describe the mood at the given reading
+    /// level, in order.
+    ///
+    /// This is the glance text, or the summary and description, and then the
+    /// extended text at [`ReadingLevel::Extended`].
+    pub fn mood_description_at(&self, mood: Mood, reading_level: ReadingLevel) -> Vec<&str> {
+        match reading_level {
+            ReadingLevel::Glance => vec![self.mood_glance(mood)],
+            ReadingLevel::Standard => vec![self.mood_summary(mood), self.mood_description(mood)],
+            ReadingLevel::Extended => vec![
+                self.mood_summary(mood),
+                self.mood_description(mood),
+                self.mood_extended(mood),
+            ],
+        }
+    }
+
+    pub fn trust_name(&self, trust: Trust) -> &str {
+        self.text(ContentKey::TrustName(trust), trust.name())
+    }
+
+    pub fn trust_description(&self, trust: Trust) -> &str {
+        self.text(ContentKey::TrustDescription(trust), trust.description())
+    }
+
+    pub fn trust_glance(&self, trust: Trust) -> &str {
+        self.text(ContentKey::TrustGlance(trust), trust.glance())
+    }
+
+    pub fn trust_extended(&self, trust: Trust) -> &str {
+        self.text(ContentKey::TrustExtended(trust), trust.extended())
+    }
+
+    /// Returns the paragraphs that describe the trust level at the given
+    /// reading level, in order.
+    pub fn trust_description_at(&self, trust: Trust, reading_level: ReadingLevel) -> Vec<&str> {
+        match reading_level {
+            ReadingLevel::Glance => vec![self.trust_glance(trust)],
+            ReadingLevel::Standard => vec![self.trust_description(trust)],
+            ReadingLevel::Extended => {
+                vec![self.trust_description(trust), self.trust_extended(trust)]
+            }
+        }
+    }
+
+    pub fn age_group_name(&self, age_group: AgeGroup) -> &str {
+        self.text(ContentKey::AgeGroupName(age_group), age_group.name())
+    }
+
+    pub fn relationship_name(&self, relationship: Relationship) -> &str {
+        self.text(
+            ContentKey::RelationshipName(relationship),
+            relationship.name(),
+        )
+    }
+
+    pub fn setting_name(&self, setting: Setting) -> &str {
+        self.text(ContentKey::SettingName(setting), setting.name())
+    }
+
+    pub fn principle_name(&self, principle: Principle) -> &str {
+        self.text(ContentKey::PrincipleName(principle), principle.name())
+    }
+
+    pub fn principle_explanation(&self, principle: Principle) -> &str {
+        self.text(
+            ContentKey::PrincipleExplanation(principle),
+            principle.explanation(),
+        )
+    }
+
+    pub fn principle_examples(&self, principle: Principle) -> Vec<&str> {
+        principle
+            .examples()
+            .iter()
+            .enumerate()
+            .map(|(index, example)| {
+                self.text(ContentKey::PrincipleExample(principle, index), example)
+            })
+            .collect()
+    }
+
+    pub fn anti_pattern_name(&self, anti_pattern: AntiPattern) -> &str {
+        self.text(
+            ContentKey::AntiPatternName(anti_pattern),
+            anti_pattern.name(),
+        )
+    }
+
+    pub fn anti_pattern_example(&self, anti_pattern: AntiPattern) -> &str {
+        self.text(
+            ContentKey::AntiPatternExample(anti_pattern),
+            anti_pattern.example(),
+        )
+    }
+
+    pub fn anti_pattern_reason(&self, anti_pattern: AntiPattern) -> &str {
+        self.text(
+            ContentKey::AntiPatternReason(anti_pattern),
+            anti_pattern.reason(),
+        )
+    }
+
+    pub fn anti_pattern_instead(&self, anti_pattern: AntiPattern) -> &str {
+        self.text(
+            ContentKey::AntiPatternInstead(anti_pattern),
+            anti_pattern.instead(),
+        )
+    }
+
+    pub fn helper_action_name(&self, helper_action: HelperAction) -> &str {
+        self.text(
+            ContentKey::HelperActionName(helper_action),
+            helper_action.name(),
+        )
+    }
+
+    pub fn helper_action_reason(
+        &self,
+        helper_action: HelperAction,
+        verdict: Verdict,
+    ) -> Option<&str> {
+        helper_action.reason(verdict).map(|reason| {
+            self.text(
+                ContentKey::HelperActionReason(helper_action, verdict),
+                reason,
+            )
+        })
+    }
+
+    pub fn sayings(&self, trust: Trust, mood: Mood, saying_kind: SayingKind) -> Vec<&str> {
+        saying_kind
+            .examples(trust, mood)
+            .iter()
+            .enumerate()
+            .map(|(index, saying)| {
+                self.text(ContentKey::Saying(trust, mood, saying_kind, index), saying)
+            })
+            .collect()
+    }
+
+    pub fn guidance_note(&self, guidance_basis: GuidanceBasis) -> Option<&str> {
+        guidance_basis
+            .note()
+            .map(|note| self.text(ContentKey::GuidanceNote(guidance_basis), note))
+    }
+
+    pub fn ui(&self, ui_text: UiText) -> &str {
+        self.text(ContentKey::Ui(ui_text), ui_text.text())
+    }
+
+    /// Returns the [`Template`] text rendered for the situation, with the
+    /// profile filled in, or with the fallback text in this locale.
+    ///
+    /// Text that is not a valid template is returned as is. Catalogs and
+    /// bundles are validated when they are loaded, so this only happens for
+    /// text from elsewhere.
+    pub fn personalize(
+        &self,
+        text: &str,
+        situation: &Situation,
+        person_profile: &PersonProfile,
+    ) -> String {
+        match Template::parse(text) {
+            Ok(template) => template.render(situation, person_profile, |profile_placeholder| {
+                self.ui(profile_placeholder.fallback())
+            }),
+            Err(_) => text.to_string(),
+        }
+    }
+
+    /// Returns a copy of the given catalog with its suggestions translated.
+    ///
+    /// The catalog may be edited while the server runs, so a translation is
+    /// only used if it was made from the catalog's current English text, see
+    /// [`ContentBundle::fingerprint`]. Otherwise the English text is used.
+    pub fn suggestion_catalog(&self, base: &SuggestionCatalog) -> SuggestionCatalog {
+        let suggestions = base
+            .iter()
+            .map(|(trust, mood, suggestion)| {
+                let suggestion = Suggestion {
+                    glance: suggestion.glance.clone().map(|glance| {
+                        self.translate(ContentKey::SuggestionGlance(trust, mood), glance)
+                    }),
+                    extended: suggestion.extended.clone().map(|extended| {
+                        self.translate(ContentKey::SuggestionExtended(trust, mood), extended)
+                    }),
+                    ..self.suggestion(
+                        suggestion,
+                        ContentKey::SuggestionAction(trust, mood),
+                        ContentKey::SuggestionDescription(trust, mood),
+                    )
+                };
+                ((trust, mood), suggestion)
+            })
+            .collect();
+        let context_suggestions = base
+            .iter_context()
+            .map(|(trust, mood, context, suggestion)| {
+                let suggestion = self.suggestion(
+                    suggestion,
+                    ContentKey::ContextSuggestionAction(trust, mood, context),
+                    ContentKey::ContextSuggestionDescription(trust, mood, context),
+                );
+                ((trust, mood, context), suggestion)
+            })
+            .collect();
+        let trust_suggestions = base
+            .iter_trust()
+            .map(|(trust, suggestion)| {
+                let suggestion = self.suggestion(
+                    suggestion,
+                    ContentKey::TrustSuggestionAction(trust),
+                    ContentKey::TrustSuggestionDescription(trust),
+                );
+                (trust, suggestion)
+            })
+            .collect();
+
+        let age_symptoms = base
+            .iter_age_symptoms()
+            .map(|(mood, age_group, symptoms)| {
+                let symptoms = self
+                    .translation_current(ContentKey::MoodSymptomsForAge(mood, age_group), symptoms)
+                    .unwrap_or(symptoms)
+                    .to_string();
+                ((mood, age_group), Cow::Owned(symptoms))
+            })
+            .collect();
+
+        SuggestionCatalog::new(
+            suggestions,
+            context_suggestions,
+            trust_suggestions,
+            age_symptoms,
+        )
+        .with_rules(base.iter_rules().cloned().collect())
+    }
+
+    fn suggestion(
+        &self,
+        base: &Suggestion,
+        action_key: ContentKey,
+        description_key: ContentKey,
+    ) -> Suggestion {
+        Suggestion {
+            action: self.translate(action_key, base.action.clone()),
+            description: self.translate(description_key, base.description.clone()),
+            glance: base.glance.clone(),
+            extended: base.extended.clone(),
+            principles: base.principles.clone(),
+            wait_seconds: base.wait_seconds,
+        }
+    }
+
+    /// Returns the translated text for the given key, or `base_text` if there
+    /// is no translation of it.
+    fn translate(&self, key: ContentKey, base_text: Cow<'static, str>) -> Cow<'static, str> {
+        self.translation_current(key, &base_text)
+            .map(|text| Cow::Owned(text.to_string()))
+            .unwrap_or(base_text)
+    }
+
+    /// Returns the translated text for the given key, if it was made from
+    /// `base_text`.
+    ///
+    /// Translations without a fingerprint may be of any text, so they are not
+    /// used either.
+    fn translation_current(&self, key: ContentKey, base_text: &str) -> Option<&str> {
+        self.bundle.get(key).filter(|_text| {
+            self.bundle.fingerprint(key) == Some(ContentFingerprint::new(base_text))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CatalogCell, CatalogFile, Locale, Localizer, Mood, SuggestionCatalog, Trust};
+
+    #[test]
+    fn suggestion_catalog_uses_translations_of_the_current_text() {
+        let localizer = Localizer::new(Locale::Es).expect("built in bundle is valid");
+        let action = |catalog_file: &CatalogFile| {
+            localizer
+                .suggestion_catalog(&SuggestionCatalog::from_catalog_file(catalog_file))
+                .get(Trust::Absent, Mood::_01_Anguished)
+                .map(|suggestion| suggestion.action().to_string())
+        };
+
+        let mut catalog_file = CatalogFile::builtin().expect("built in catalog file is valid");
+        assert_eq!(
+            Some(String::from("Mantén la distancia")),
+            action(&catalog_file)
+        );
+
+        let cell = CatalogCell::Suggestion(Trust::Absent, Mood::_01_Anguished);
+        let mut suggestion = catalog_file.get(cell).expect("cell is required").clone();
+        suggestion.action = "Keep your distance".into();
+        catalog_file
+            .set(cell, suggestion)
+            .expect("suggestion is valid");
+        assert_eq!(
+            Some(String::from("Keep your distance")),
+            action(&catalog_file)
+        );
+    }
+}
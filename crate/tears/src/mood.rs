@@ -3,21 +3,31 @@ use std::{
     str::FromStr,
 };
 
+use serde::{Deserialize, Serialize};
+
 /// The mood the receiving person is in.
+///
+/// Moods are ordered from the most to the least overwhelmed.
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub enum Mood {
     /// Unresponsiveness to any interaction. Outbursts, self-harm.
+    #[serde(rename = "Anguished")]
     _01_Anguished,
     /// Silence, eyes stare blankly. Little movement.
+    #[serde(rename = "Closed")]
     _02_Closed,
     /// One word answers, eyes assessing every detail.
+    #[serde(rename = "Cautious")]
     _03_Cautious,
     /// Asks for justification / to see evidence.
+    #[serde(rename = "Unsettled")]
     _04_Unsettled,
     /// No sad symptoms, smile is conscious.
+    #[serde(rename = "Calm")]
     _05_Calm,
     /// Smiles subconciously.
+    #[serde(rename = "Hopeful")]
     _06_Hopeful,
 }
 
@@ -51,6 +61,18 @@ impl Mood {
         }
     }
 
+    /// Returns the name of this mood, e.g. `"Anguished"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Mood::_01_Anguished => "Anguished",
+            Mood::_02_Closed => "Closed",
+            Mood::_03_Cautious => "Cautious",
+            Mood::_04_Unsettled => "Unsettled",
+            Mood::_05_Calm => "Calm",
+            Mood::_06_Hopeful => "Hopeful",
+        }
+    }
+
     pub fn symptoms(self) -> &'static str {
         match self {
             Mood::_01_Anguished => "Unresponsiveness to any interaction. Outbursts, self-harm.",
@@ -102,6 +124,67 @@ impl Mood {
             }
         }
     }
+
+    /// Returns a one line description, shown at [`ReadingLevel::Glance`].
+    ///
+    /// [`ReadingLevel::Glance`]: crate::ReadingLevel::Glance
+    pub fn glance(self) -> &'static str {
+        match self {
+            Mood::_01_Anguished => "Everything hurts right now, so keep things very gentle.",
+            Mood::_02_Closed => "They have shut down, and expect nothing good.",
+            Mood::_03_Cautious => "They are guarded, and watching who understands them.",
+            Mood::_04_Unsettled => "They are wary, but willing to see if trust can be mended.",
+            Mood::_05_Calm => "They feel okay, neither good nor bad.",
+            Mood::_06_Hopeful => "They feel good, and open to what is ahead.",
+        }
+    }
+
+    /// Returns a longer explanation, shown after the description at
+    /// [`ReadingLevel::Extended`].
+    ///
+    /// [`ReadingLevel::Extended`]: crate::ReadingLevel::Extended
+    pub fn extended(self) -> &'static str {
+        match self {
+            Mood::_01_Anguished => {
+                "The person may not be able to take in words at all. Questions, \
+                advice, and even kind reassurance add to the load, because each \
+                one asks them to respond. If they may hurt themselves, get help \
+                from someone trained. Otherwise, less is more: a quiet, steady \
+                presence or space is usually what helps."
+            }
+            Mood::_02_Closed => {
+                "The person has learned that hoping leads to being hurt, so they \
+                protect themselves by expecting nothing. Pushing them to see the \
+                bright side tends to confirm that others don't understand. Small, \
+                reliable acts that ask nothing back are more likely to be noticed \
+                over time than any argument."
+            }
+            Mood::_03_Cautious => {
+                "The person is watching for signs that someone understands how \
+                they feel. Instructions, judgements, or being hurried are read as \
+                not understanding, and they will pull away. Listening, and letting \
+                them set the pace, builds the trust that lets them open up."
+            }
+            Mood::_04_Unsettled => {
+                "The person wants to believe things can get better, but needs to \
+                see it for themselves. They may ask hard questions, or test \
+                whether you mean what you say. Being honest, consistent, and \
+                patient with their doubts shows them that trust can be mended."
+            }
+            Mood::_05_Calm => {
+                "The person is steady, but may still be tired from what came \
+                before. This is a good time for ordinary, low pressure time \
+                together. Leave heavy topics unless they bring them up, and let \
+                good moments happen without making a point of them."
+            }
+            Mood::_06_Hopeful => {
+                "The person has energy for new things, and can see good ahead of \
+                them. They are more open to ideas, plans, and encouragement. Share \
+                in their enthusiasm and let them lead, because hope that is theirs \
+                lasts longer than hope that is handed to them."
+            }
+        }
+    }
 }
 
 impl TryFrom<u8> for Mood {
@@ -122,14 +205,7 @@ impl TryFrom<u8> for Mood {
 
 impl Display for Mood {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Mood::_01_Anguished => "Anguished".fmt(f),
-            Mood::_02_Closed => "Closed".fmt(f),
-            Mood::_03_Cautious => "Cautious".fmt(f),
-            Mood::_04_Unsettled => "Unsettled".fmt(f),
-            Mood::_05_Calm => "Calm".fmt(f),
-            Mood::_06_Hopeful => "Hopeful".fmt(f),
-        }
+        self.name().fmt(f)
     }
 }
 
@@ -0,0 +1,68 @@
+use std::fmt::{self, Display};
+
+use crate::Mood;
+
+/// A range of moods that the receiving person may be in, for when it isn't
+/// clear which one they are in.
+///
+/// e.g. "somewhere between Closed and Cautious".
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct MoodRange {
+    /// The most overwhelmed mood that the person may be in.
+    lower: Mood,
+    /// The least overwhelmed mood that the person may be in.
+    upper: Mood,
+}
+
+impl MoodRange {
+    /// Returns a new `MoodRange` spanning the two given moods, in either order.
+    pub fn new(a: Mood, b: Mood) -> Self {
+        Self {
+            lower: a.min(b),
+            upper: a.max(b),
+        }
+    }
+
+    /// Returns the most overwhelmed mood in this range, which is the safer
+    /// mood to act on.
+    pub fn lower(self) -> Mood {
+        self.lower
+    }
+
+    /// Returns the least overwhelmed mood in this range.
+    pub fn upper(self) -> Mood {
+        self.upper
+    }
+
+    /// Returns whether this range only contains one mood.
+    pub fn is_single(self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Returns whether the given mood is within this range.
+    pub fn contains(self, mood: Mood) -> bool {
+        self.lower <= mood && mood <= self.upper
+    }
+
+    /// Returns an iterator over the moods in this range, from the lower to the
+    /// upper bound.
+    pub fn iter(self) -> impl Iterator<Item = Mood> {
+        Mood::iter().filter(move |mood| self.contains(*mood))
+    }
+}
+
+impl From<Mood> for MoodRange {
+    fn from(mood: Mood) -> Self {
+        Self::new(mood, mood)
+    }
+}
+
+impl Display for MoodRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_single() {
+            self.lower.fmt(f)
+        } else {
+            write!(f, "{} – {}", self.lower, self.upper)
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{PersonProfileError, ProfileList, ProfilePlaceholder};
+
+/// What is known about the person being supported, to make suggestions
+/// concrete for them.
+///
+/// Each list is in order of preference, so the first comfort is their
+/// favourite.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PersonProfile {
+    /// What they call themselves.
+    pub name: String,
+    /// Things that comfort them, e.g. `"dark chocolate"`.
+    pub comforts: Vec<String>,
+    /// Things that upset them, e.g. `"being asked about work"`.
+    pub triggers: Vec<String>,
+    /// People they already trust.
+    pub trusted_people: Vec<String>,
+    /// Things that made it worse when they were tried before.
+    pub backfired: Vec<String>,
+}
+
+impl PersonProfile {
+    /// Returns an empty profile.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a profile written by [`PersonProfile::to_toml`].
+    pub fn parse(profile_toml: &str) -> Result<Self, PersonProfileError> {
+        toml::from_str(profile_toml).map_err(|error| PersonProfileError::Deserialize { error })
+    }
+
+    /// Returns the profile as TOML.
+    pub fn to_toml(&self) -> Result<String, PersonProfileError> {
+        toml::to_string(self).map_err(|error| PersonProfileError::Serialize { error })
+    }
+
+    /// Returns whether nothing is known about the person.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the values in one of the lists.
+    pub fn list(&self, profile_list: ProfileList) -> &[String] {
+        match profile_list {
+            ProfileList::Comforts => &self.comforts,
+            ProfileList::TrustedPeople => &self.trusted_people,
+            ProfileList::Triggers => &self.triggers,
+            ProfileList::Backfired => &self.backfired,
+        }
+    }
+
+    /// Returns a mutable reference to one of the lists.
+    pub fn list_mut(&mut self, profile_list: ProfileList) -> &mut Vec<String> {
+        match profile_list {
+            ProfileList::Comforts => &mut self.comforts,
+            ProfileList::TrustedPeople => &mut self.trusted_people,
+            ProfileList::Triggers => &mut self.triggers,
+            ProfileList::Backfired => &mut self.backfired,
+        }
+    }
+
+    /// Returns the text to fill in for a placeholder, if the profile has a
+    /// value for it.
+    ///
+    /// Lists are joined by commas.
+    pub fn value(&self, profile_placeholder: ProfilePlaceholder) -> Option<String> {
+        let value = match profile_placeholder {
+            ProfilePlaceholder::Name => self.name.trim().to_string(),
+            ProfilePlaceholder::FavouriteComfort => first(&self.comforts),
+            ProfilePlaceholder::TrustedPerson => first(&self.trusted_people),
+            ProfilePlaceholder::Triggers => joined(&self.triggers),
+            ProfilePlaceholder::Backfired => joined(&self.backfired),
+        };
+
+        (!value.is_empty()).then_some(value)
+    }
+
+    /// Returns the number of values the profile has for a placeholder.
+    pub fn count(&self, profile_placeholder: ProfilePlaceholder) -> usize {
+        let values = match profile_placeholder {
+            ProfilePlaceholder::Name => return usize::from(!self.name.trim().is_empty()),
+            ProfilePlaceholder::FavouriteComfort => &self.comforts,
+            ProfilePlaceholder::TrustedPerson => &self.trusted_people,
+            ProfilePlaceholder::Triggers => &self.triggers,
+            ProfilePlaceholder::Backfired => &self.backfired,
+        };
+        values
+            .iter()
+            .filter(|value| !value.trim().is_empty())
+            .count()
+    }
+}
+
+fn first(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| value.trim())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn joined(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,20 @@
+/// Errors when reading or writing a [`PersonProfile`].
+///
+/// [`PersonProfile`]: crate::PersonProfile
+#[derive(Debug, thiserror::Error)]
+pub enum PersonProfileError {
+    /// The profile is not valid TOML, or has unknown fields.
+    #[error("Failed to parse the person profile.")]
+    Deserialize {
+        /// The underlying error.
+        #[source]
+        error: toml::de::Error,
+    },
+    /// The profile could not be written as TOML.
+    #[error("Failed to serialize the person profile.")]
+    Serialize {
+        /// The underlying error.
+        #[source]
+        error: toml::ser::Error,
+    },
+}
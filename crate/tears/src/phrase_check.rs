@@ -0,0 +1,357 @@
+use std::ops::Range;
+
+use crate::{AntiPattern, MoodRange, PhraseFlag};
+
+/// Phrases that are risky to say, as lowercase words separated by spaces, with
+/// the anti-pattern they are an example of and a gentler way to say it.
+const PHRASE_RULES: &[(AntiPattern, &str, &str)] = &[
+    (
+        AntiPattern::AskingWhy,
+        "why",
+        "\"It looks like a hard day. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::AskingWhy,
+        "how come",
+        "\"It looks like a hard day. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::AskingWhy,
+        "what's wrong with you",
+        "\"You seem upset. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "you should",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "you shouldn't",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "you need to",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "you have to",
+        "\"If you want, I can help with that.\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "you ought to",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "you must",
+        "\"If you want, I can help with that.\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "why don't you",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "have you tried",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::GivingAdvice,
+        "if i were you",
+        "\"Do you want ideas, or just to talk?\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "it's not that bad",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "it isn't that bad",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "not a big deal",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "no big deal",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "could be worse",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "could have been worse",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "have it worse",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "at least",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "it's nothing",
+        "\"That sounds really hard.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "get over it",
+        "\"It makes sense that this hurts.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "you're overreacting",
+        "\"It makes sense that this hurts.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "calm down",
+        "\"Take your time. I'm here.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "just relax",
+        "\"Take your time. I'm here.\"",
+    ),
+    (
+        AntiPattern::Minimising,
+        "don't worry",
+        "\"It makes sense that you're worried.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "just tell me",
+        "\"You don't have to say anything. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "tell me what",
+        "\"You don't have to say anything. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "talk to me",
+        "\"You don't have to say anything. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "say something",
+        "\"You don't have to say anything.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "answer me",
+        "\"You don't have to answer.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "i need to know",
+        "\"You don't have to answer. I'm here if you want to talk.\"",
+    ),
+    (
+        AntiPattern::PushingToTalk,
+        "look at me",
+        "\"I'm right here.\"",
+    ),
+];
+
+/// Risky phrases in what a helper intends to say, for a mood range.
+///
+/// Phrases are recognised by rules rather than understood, so a check is a
+/// prompt to think again, not a judgement. Only English phrases are
+/// recognised.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct PhraseCheck {
+    /// Risky phrases, in the order they appear in the text.
+    ///
+    /// Phrases never overlap.
+    flags: Vec<PhraseFlag>,
+}
+
+impl PhraseCheck {
+    /// Returns the phrases in `text` that backfire at any mood in the range.
+    pub fn new(text: &str, mood_range: MoodRange) -> Self {
+        let words = words(text);
+
+        let mut flags = PHRASE_RULES
+            .iter()
+            .filter(|(anti_pattern, _, _)| {
+                anti_pattern
+                    .moods()
+                    .iter()
+                    .any(|mood| mood_range.contains(*mood))
+            })
+            .flat_map(|(anti_pattern, phrase, rewording)| {
+                let phrase = phrase.split(' ').collect::<Vec<_>>();
+                words
+                    .windows(phrase.len())
+                    .filter(move |window| {
+                        window
+                            .iter()
+                            .zip(phrase.iter())
+                            .all(|((_, word), phrase_word)| word == phrase_word)
+                    })
+                    .map(|window| PhraseFlag {
+                        anti_pattern: *anti_pattern,
+                        range: window[0].0.start..window[window.len() - 1].0.end,
+                        rewording,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Longer phrases win over the phrases within them, e.g. "why don't
+        // you" is advice rather than asking why.
+        flags.sort_by_key(|flag| (flag.range.start, usize::MAX - flag.range.end));
+        let mut flags_kept = Vec::<PhraseFlag>::new();
+        flags.into_iter().for_each(|flag| match flags_kept.last() {
+            Some(flag_kept) if flag.range.start < flag_kept.range.end => {}
+            _ => flags_kept.push(flag),
+        });
+
+        Self { flags: flags_kept }
+    }
+
+    /// Returns the risky phrases, in the order they appear in the text.
+    pub fn flags(&self) -> &[PhraseFlag] {
+        &self.flags
+    }
+
+    /// Returns whether no risky phrases were found.
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+}
+
+/// Returns the words in `text` with their byte ranges, in lowercase and with
+/// typographic apostrophes replaced by `'`.
+fn words(text: &str) -> Vec<(Range<usize>, String)> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'' || c == '’';
+
+    let mut words = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, is_word_char(c)) {
+            (None, true) => start = Some(index),
+            (Some(word_start), false) => {
+                let word = text[word_start..index].to_lowercase().replace('’', "'");
+                words.push((word_start..index, word));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AntiPattern, Mood, MoodRange, PhraseCheck};
+
+    /// Returns the anti-pattern and text of each flagged phrase.
+    fn flagged(text: &str, mood_range: MoodRange) -> Vec<(AntiPattern, &str)> {
+        PhraseCheck::new(text, mood_range)
+            .flags()
+            .iter()
+            .map(|phrase_flag| (phrase_flag.anti_pattern, &text[phrase_flag.range.clone()]))
+            .collect()
+    }
+
+    #[test]
+    fn risky_phrases_are_flagged_in_order() {
+        let text = "Why don't you just tell me what’s wrong? It's not that bad, calm down.";
+
+        assert_eq!(
+            vec![
+                (AntiPattern::GivingAdvice, "Why don't you"),
+                (AntiPattern::PushingToTalk, "just tell me"),
+                (AntiPattern::Minimising, "It's not that bad"),
+                (AntiPattern::Minimising, "calm down"),
+            ],
+            flagged(text, MoodRange::from(Mood::_02_Closed))
+        );
+    }
+
+    #[test]
+    fn longer_phrase_wins_over_phrase_within() {
+        assert_eq!(
+            vec![(AntiPattern::GivingAdvice, "why don't you")],
+            flagged("why don't you rest", MoodRange::from(Mood::_02_Closed))
+        );
+    }
+
+    #[test]
+    fn overlapping_phrases_keep_the_first() {
+        // "just tell me" and "tell me what" overlap on "tell me".
+        assert_eq!(
+            vec![(AntiPattern::PushingToTalk, "Just tell me")],
+            flagged(
+                "Just tell me what happened.",
+                MoodRange::from(Mood::_02_Closed)
+            )
+        );
+    }
+
+    #[test]
+    fn phrases_match_whole_words_ignoring_case_and_apostrophe_style() {
+        assert_eq!(
+            vec![
+                (AntiPattern::Minimising, "It’s NOT that bad"),
+                (AntiPattern::Minimising, "Calm Down"),
+            ],
+            flagged(
+                "Whyte said It’s NOT that bad. Calm Down.",
+                MoodRange::from(Mood::_03_Cautious)
+            )
+        );
+    }
+
+    #[test]
+    fn phrases_are_only_flagged_for_moods_they_backfire_at() {
+        let text = "Talk to me. You should rest.";
+
+        assert_eq!(
+            vec![
+                (AntiPattern::PushingToTalk, "Talk to me"),
+                (AntiPattern::GivingAdvice, "You should"),
+            ],
+            flagged(text, MoodRange::from(Mood::_02_Closed))
+        );
+        assert_eq!(
+            vec![(AntiPattern::GivingAdvice, "You should")],
+            flagged(text, MoodRange::from(Mood::_04_Unsettled))
+        );
+        assert_eq!(
+            vec![
+                (AntiPattern::PushingToTalk, "Talk to me"),
+                (AntiPattern::GivingAdvice, "You should"),
+            ],
+            flagged(text, MoodRange::new(Mood::_06_Hopeful, Mood::_03_Cautious))
+        );
+        assert!(PhraseCheck::new(text, MoodRange::from(Mood::_06_Hopeful)).is_empty());
+    }
+
+    #[test]
+    fn gentle_text_is_not_flagged() {
+        assert!(
+            PhraseCheck::new("I'm here. Tea?", MoodRange::from(Mood::_01_Anguished)).is_empty()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use std::ops::Range;
+
+use crate::AntiPattern;
+
+/// A risky phrase found by a [`PhraseCheck`].
+///
+/// [`PhraseCheck`]: crate::PhraseCheck
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PhraseFlag {
+    /// The anti-pattern that the phrase is an example of.
+    pub anti_pattern: AntiPattern,
+    /// Byte range of the phrase within the checked text.
+    pub range: Range<usize>,
+    /// A gentler way to say it.
+    pub rewording: &'static str,
+}
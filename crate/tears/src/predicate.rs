@@ -0,0 +1,118 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    AgeGroup, Comparison, Mood, PredicateOutcome, Relationship, Setting, Situation, Trust,
+};
+
+/// A condition on one dimension of a [`Situation`].
+///
+/// Moods are ordered from the most to the least overwhelmed, so
+/// `mood <= Cautious` matches `Anguished`, `Closed`, and `Cautious`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Predicate {
+    /// `trust == <trust>`
+    Trust(Trust),
+    /// `mood <comparison> <mood>`
+    Mood(Comparison, Mood),
+    /// `age group == <age_group>`
+    AgeGroup(AgeGroup),
+    /// `relationship == <relationship>`
+    Relationship(Relationship),
+    /// `setting == <setting>`
+    Setting(Setting),
+}
+
+impl Predicate {
+    /// Returns whether the situation satisfies this predicate.
+    pub fn evaluate(&self, situation: &Situation) -> PredicateOutcome {
+        let satisfied = match *self {
+            Predicate::Trust(trust) => situation.trust.map(|actual| actual == trust),
+            Predicate::Mood(comparison, mood) => situation
+                .mood
+                .map(|actual| comparison.compare(&actual, &mood)),
+            Predicate::AgeGroup(age_group) => situation
+                .context
+                .age_group
+                .map(|actual| actual == age_group),
+            Predicate::Relationship(relationship) => situation
+                .context
+                .relationship
+                .map(|actual| actual == relationship),
+            Predicate::Setting(setting) => {
+                situation.context.setting.map(|actual| actual == setting)
+            }
+        };
+
+        match satisfied {
+            Some(true) => PredicateOutcome::Satisfied,
+            Some(false) => PredicateOutcome::Unsatisfied,
+            None => PredicateOutcome::Unknown,
+        }
+    }
+
+    /// Returns the name of the dimension this predicate checks, e.g.
+    /// `"mood"`.
+    pub fn dimension(&self) -> &'static str {
+        match self {
+            Predicate::Trust(_) => "trust",
+            Predicate::Mood(..) => "mood",
+            Predicate::AgeGroup(_) => "age group",
+            Predicate::Relationship(_) => "relationship",
+            Predicate::Setting(_) => "setting",
+        }
+    }
+}
+
+impl Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dimension = self.dimension();
+        match self {
+            Predicate::Trust(trust) => write!(f, "{dimension} == {trust}"),
+            Predicate::Mood(comparison, mood) => write!(f, "{dimension} {comparison} {mood}"),
+            Predicate::AgeGroup(age_group) => write!(f, "{dimension} == {age_group}"),
+            Predicate::Relationship(relationship) => write!(f, "{dimension} == {relationship}"),
+            Predicate::Setting(setting) => write!(f, "{dimension} == {setting}"),
+        }
+    }
+}
+
+impl FromStr for Predicate {
+    type Err = ();
+
+    /// Parses a predicate written as `<dimension> <comparison> <value>`, e.g.
+    /// `mood <= Cautious` or `trust=Present`.
+    ///
+    /// `=` is the same as `==`. Only moods may be compared with anything other
+    /// than equality.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dimension, symbol, value) = ["<=", ">=", "==", "<", ">", "="]
+            .into_iter()
+            .find_map(|symbol| {
+                s.split_once(symbol)
+                    .map(|(dimension, value)| (dimension.trim(), symbol, value.trim()))
+            })
+            .ok_or(())?;
+        let comparison = match symbol {
+            "=" => Comparison::Eq,
+            symbol => Comparison::from_str(symbol)?,
+        };
+
+        match (dimension, comparison) {
+            ("mood", comparison) => {
+                Mood::from_str(value).map(|mood| Predicate::Mood(comparison, mood))
+            }
+            (_, Comparison::Lt | Comparison::Le | Comparison::Ge | Comparison::Gt) => Err(()),
+            ("trust", _) => Trust::from_str(value).map(Predicate::Trust),
+            ("age group" | "age_group", _) => AgeGroup::iter()
+                .find(|age_group| age_group.id() == value || age_group.name() == value)
+                .map(Predicate::AgeGroup)
+                .ok_or(()),
+            ("relationship", _) => Relationship::from_str(value).map(Predicate::Relationship),
+            ("setting", _) => Setting::from_str(value).map(Predicate::Setting),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt::{self, Display};
+
+/// Result of evaluating a [`Predicate`] against a [`Situation`].
+///
+/// [`Predicate`]: crate::Predicate
+/// [`Situation`]: crate::Situation
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PredicateOutcome {
+    /// The situation satisfies the predicate.
+    Satisfied,
+    /// The situation does not satisfy the predicate.
+    Unsatisfied,
+    /// The dimension that the predicate checks is not known, so the predicate
+    /// is not satisfied.
+    Unknown,
+}
+
+impl PredicateOutcome {
+    /// Returns whether the predicate is satisfied.
+    pub fn is_satisfied(self) -> bool {
+        self == PredicateOutcome::Satisfied
+    }
+}
+
+impl Display for PredicateOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PredicateOutcome::Satisfied => "yes".fmt(f),
+            PredicateOutcome::Unsatisfied => "no".fmt(f),
+            PredicateOutcome::Unknown => "unknown".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// An idea that recurs across suggestions, e.g. "Provide a way out".
+///
+/// Each [`Suggestion`] is tagged with the principles it applies, so that
+/// readers can see why the same advice comes up in different situations.
+///
+/// [`Suggestion`]: crate::Suggestion
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Principle {
+    /// Anything that asks for a response can feel like pressure.
+    NoPressure,
+    /// The person can always say no, or not answer, without a cost.
+    WayOut,
+    /// Hear the person out before, or instead of, offering solutions.
+    ListenDontFix,
+    /// The person sets the pace and the direction.
+    LetThemLead,
+    /// Distance lets the person settle.
+    GiveSpace,
+    /// Care is shown without expecting anything back.
+    CareWithoutStrings,
+    /// Offers are short, concrete, and easy to answer.
+    SmallOffers,
+    /// When the person may be unsafe, get help from someone trained.
+    SafetyFirst,
+    /// Good experiences balance out the hard ones.
+    GoodMemories,
+}
+
+impl Principle {
+    /// Returns an iterator over all variants of this `Principle` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = Principle> {
+        [
+            Principle::NoPressure,
+            Principle::WayOut,
+            Principle::ListenDontFix,
+            Principle::LetThemLead,
+            Principle::GiveSpace,
+            Principle::CareWithoutStrings,
+            Principle::SmallOffers,
+            Principle::SafetyFirst,
+            Principle::GoodMemories,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the identifier of this principle within catalog files and
+    /// content keys, e.g. `"way_out"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Principle::NoPressure => "no_pressure",
+            Principle::WayOut => "way_out",
+            Principle::ListenDontFix => "listen_dont_fix",
+            Principle::LetThemLead => "let_them_lead",
+            Principle::GiveSpace => "give_space",
+            Principle::CareWithoutStrings => "care_without_strings",
+            Principle::SmallOffers => "small_offers",
+            Principle::SafetyFirst => "safety_first",
+            Principle::GoodMemories => "good_memories",
+        }
+    }
+
+    /// Returns the principle with the given [`id`](Self::id), e.g.
+    /// `"way_out"`.
+    pub fn from_id(id: &str) -> Option<Principle> {
+        Principle::iter().find(|principle| principle.id() == id)
+    }
+
+    /// Returns the name of this principle, e.g. `"Provide a way out"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Principle::NoPressure => "Don't pressure",
+            Principle::WayOut => "Provide a way out",
+            Principle::ListenDontFix => "Listen, don't problem solve",
+            Principle::LetThemLead => "Let them lead",
+            Principle::GiveSpace => "Give them space",
+            Principle::CareWithoutStrings => "Care without strings",
+            Principle::SmallOffers => "Keep offers small",
+            Principle::SafetyFirst => "Safety first",
+            Principle::GoodMemories => "Make good memories",
+        }
+    }
+
+    pub fn explanation(self) -> &'static str {
+        match self {
+            Principle::NoPressure => {
+                "Questions, advice, and even a hug ask the person to respond. \
+                When they are overwhelmed, each one adds to the load, however \
+                kindly it is meant."
+            }
+            Principle::WayOut => {
+                "Make it clear that the person doesn't have to answer, accept, \
+                or explain. When saying no is easy, saying yes feels safe."
+            }
+            Principle::ListenDontFix => {
+                "Solutions can sound like \"you are doing it wrong\". Being \
+                heard is often the help the person needs, and advice is best \
+                given when they ask for it."
+            }
+            Principle::LetThemLead => {
+                "Follow the person's pace, and what they want to do or talk \
+                about. Choices that are theirs last longer than choices made \
+                for them."
+            }
+            Principle::GiveSpace => {
+                "Your presence may feel like pressure, even when your motive is \
+                pure. Distance lets the person settle, and come to you when \
+                they are ready."
+            }
+            Principle::CareWithoutStrings => {
+                "Small, steady signs of care that ask for nothing back, not even \
+                thanks, show the person that they matter."
+            }
+            Principle::SmallOffers => {
+                "Open questions ask the person to work out what they need, which \
+                is hard right now. A short, concrete offer is easy to answer."
+            }
+            Principle::SafetyFirst => {
+                "If the person may hurt themselves, their safety comes before \
+                everything else. Get help from someone trained, such as a local \
+                crisis line."
+            }
+            Principle::GoodMemories => {
+                "Good experiences help to balance out the hard ones the person \
+                remembers, and help them believe that life can be good."
+            }
+        }
+    }
+
+    /// Returns examples of this principle in practice.
+    pub fn examples(self) -> &'static [&'static str] {
+        match self {
+            Principle::NoPressure => &[
+                "Leave a cup of tea without waiting for thanks.",
+                "Sit nearby instead of asking how they feel.",
+            ],
+            Principle::WayOut => &[
+                "\"You don't have to answer.\"",
+                "\"Tea? It's fine if not.\"",
+            ],
+            Principle::ListenDontFix => &[
+                "\"Do you want ideas, or just to talk?\"",
+                "Nod and wait, instead of saying what you would do.",
+            ],
+            Principle::LetThemLead => &[
+                "Join their game rather than starting a new one.",
+                "Stop when they lose interest, without asking why.",
+            ],
+            Principle::GiveSpace => &[
+                "Stay in the next room, within reach.",
+                "Leave, and tell them once where to find you.",
+            ],
+            Principle::CareWithoutStrings => &[
+                "Leave their favourite snack within reach.",
+                "Keep showing up, even when they don't respond.",
+            ],
+            Principle::SmallOffers => &[
+                "\"Tea?\" rather than \"Is there anything I can do?\"",
+                "\"I can take the notes for the next meeting.\"",
+            ],
+            Principle::SafetyFirst => &[
+                "Call the local emergency number, and stay within sight.",
+                "Let the staff caring for them know.",
+            ],
+            Principle::GoodMemories => &[
+                "Plan something small to look forward to together.",
+                "Celebrate a small win with them.",
+            ],
+        }
+    }
+}
+
+impl Display for Principle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for Principle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Principle::iter()
+            .find(|principle| principle.name() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::UiText;
+
+/// One of the lists in a [`PersonProfile`].
+///
+/// [`PersonProfile`]: crate::PersonProfile
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ProfileList {
+    /// Things that comfort them.
+    Comforts,
+    /// People they already trust.
+    TrustedPeople,
+    /// Things that upset them.
+    Triggers,
+    /// Things that made it worse when they were tried before.
+    Backfired,
+}
+
+impl ProfileList {
+    /// Returns an iterator over all variants of this `ProfileList` enum.
+    pub fn iter() -> impl Iterator<Item = ProfileList> {
+        [
+            ProfileList::Comforts,
+            ProfileList::TrustedPeople,
+            ProfileList::Triggers,
+            ProfileList::Backfired,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the label for this list in the user interface.
+    pub fn label(self) -> UiText {
+        match self {
+            ProfileList::Comforts => UiText::ProfileComfortsLabel,
+            ProfileList::TrustedPeople => UiText::ProfileTrustedPeopleLabel,
+            ProfileList::Triggers => UiText::ProfileTriggersLabel,
+            ProfileList::Backfired => UiText::ProfileBackfiredLabel,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::UiText;
+
+/// Part of a suggestion that is filled in from the [`PersonProfile`].
+///
+/// Suggestion text refers to a placeholder by its token in braces, e.g.
+/// `"Leave {their favourite comfort} at the door."`. When the profile has no
+/// value for it, the placeholder's fallback text is used instead.
+///
+/// [`PersonProfile`]: crate::PersonProfile
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ProfilePlaceholder {
+    /// Name of the receiving person.
+    Name,
+    /// The first of their preferred comforts.
+    FavouriteComfort,
+    /// The first of the people they trust.
+    TrustedPerson,
+    /// All of their known triggers.
+    Triggers,
+    /// All of the things that backfired before.
+    Backfired,
+}
+
+impl ProfilePlaceholder {
+    /// Returns an iterator over all variants of this `ProfilePlaceholder` enum.
+    pub fn iter() -> impl Iterator<Item = ProfilePlaceholder> {
+        [
+            ProfilePlaceholder::Name,
+            ProfilePlaceholder::FavouriteComfort,
+            ProfilePlaceholder::TrustedPerson,
+            ProfilePlaceholder::Triggers,
+            ProfilePlaceholder::Backfired,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the text between the braces that refers to this placeholder,
+    /// e.g. `"their favourite comfort"`.
+    pub fn token(self) -> &'static str {
+        match self {
+            ProfilePlaceholder::Name => "their name",
+            ProfilePlaceholder::FavouriteComfort => "their favourite comfort",
+            ProfilePlaceholder::TrustedPerson => "someone they trust",
+            ProfilePlaceholder::Triggers => "their triggers",
+            ProfilePlaceholder::Backfired => "what backfired",
+        }
+    }
+
+    /// Returns the text used when the profile has no value for this
+    /// placeholder.
+    pub fn fallback(self) -> UiText {
+        match self {
+            ProfilePlaceholder::Name => UiText::ProfileNameFallback,
+            ProfilePlaceholder::FavouriteComfort => UiText::ProfileComfortFallback,
+            ProfilePlaceholder::TrustedPerson => UiText::ProfileTrustedPersonFallback,
+            ProfilePlaceholder::Triggers => UiText::ProfileTriggersFallback,
+            ProfilePlaceholder::Backfired => UiText::ProfileBackfiredFallback,
+        }
+    }
+}
+
+impl Display for ProfilePlaceholder {
+    /// Writes the token in braces, e.g. `{their favourite comfort}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", self.token())
+    }
+}
+
+impl FromStr for ProfilePlaceholder {
+    type Err = ();
+
+    /// Parses the token, without braces.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ProfilePlaceholder::iter()
+            .find(|profile_placeholder| profile_placeholder.token() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::UiText;
+
+/// How much text to show for each mood, trust level, and suggestion.
+#[derive(
+    Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize,
+)]
+pub enum ReadingLevel {
+    /// One line, for when there is no time or energy to read.
+    Glance,
+    /// The usual text, which is intentionally short.
+    #[default]
+    Standard,
+    /// The usual text and a longer explanation of why.
+    Extended,
+}
+
+impl ReadingLevel {
+    /// Returns an iterator over all variants of this `ReadingLevel` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = ReadingLevel> {
+        [
+            ReadingLevel::Glance,
+            ReadingLevel::Standard,
+            ReadingLevel::Extended,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the identifier of this reading level, e.g. `"glance"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            ReadingLevel::Glance => "glance",
+            ReadingLevel::Standard => "standard",
+            ReadingLevel::Extended => "extended",
+        }
+    }
+
+    /// Returns the label for this reading level in the user interface.
+    pub fn ui_text(self) -> UiText {
+        match self {
+            ReadingLevel::Glance => UiText::ReadingLevelGlance,
+            ReadingLevel::Standard => UiText::ReadingLevelStandard,
+            ReadingLevel::Extended => UiText::ReadingLevelExtended,
+        }
+    }
+}
+
+impl Display for ReadingLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id().fmt(f)
+    }
+}
+
+impl FromStr for ReadingLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReadingLevel::iter()
+            .find(|reading_level| reading_level.id() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// How you are related to the receiving person.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Relationship {
+    /// You are family, e.g. their parent, child, or sibling.
+    Family,
+    /// You are their partner.
+    Partner,
+    /// You are their friend.
+    Friend,
+    /// You work or study with them.
+    Colleague,
+    /// You don't know each other.
+    Stranger,
+}
+
+impl Relationship {
+    /// Returns an iterator over all variants of this `Relationship` enum.
+    pub fn iter() -> impl Iterator<Item = Relationship> {
+        [
+            Relationship::Family,
+            Relationship::Partner,
+            Relationship::Friend,
+            Relationship::Colleague,
+            Relationship::Stranger,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the name of this relationship, e.g. `"Family"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Relationship::Family => "Family",
+            Relationship::Partner => "Partner",
+            Relationship::Friend => "Friend",
+            Relationship::Colleague => "Colleague",
+            Relationship::Stranger => "Stranger",
+        }
+    }
+}
+
+impl Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for Relationship {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Relationship::iter()
+            .find(|relationship| relationship.name() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,433 @@
+use std::fmt::{self, Display};
+
+use crate::{RichTextBlock, RichTextSpan};
+
+/// Description text with a small, safe subset of Markdown.
+///
+/// The syntax is:
+///
+/// * A blank line separates paragraphs and lists.
+/// * `- item` or `* item` on each line: a bulleted list.
+/// * `1. item` on each line: a numbered list.
+/// * `*text*` or `_text_`: emphasis.
+/// * `**text**` or `__text__`: strong emphasis.
+/// * `[text](https://example.com)`: a link. Only `http`, `https`, and `mailto`
+///   addresses are linked, anything else is kept as text.
+/// * `\*`: a literal `*`, and likewise for other punctuation.
+///
+/// There is no way to write HTML, so rich text is safe to render from content
+/// files. Markup that is not understood, such as `"do you want ____"`, is kept
+/// as text, so parsing never fails.
+///
+/// `Display` writes the text without markup, for terminals and other outputs
+/// that are not HTML.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RichText {
+    /// Paragraphs and lists, in order.
+    blocks: Vec<RichTextBlock>,
+}
+
+/// Address schemes that links may use.
+const URL_SCHEMES: [&str; 3] = ["https://", "http://", "mailto:"];
+
+impl RichText {
+    /// Parses rich text, keeping markup that is not understood as text.
+    pub fn parse(text: &str) -> Self {
+        let mut blocks = Vec::new();
+        let mut lines = Vec::new();
+        for line in text.lines().chain(std::iter::once("")) {
+            if !line.trim().is_empty() {
+                lines.push(line.trim());
+            } else if !lines.is_empty() {
+                blocks.push(Self::parse_block(&std::mem::take(&mut lines)));
+            }
+        }
+
+        Self { blocks }
+    }
+
+    /// Returns the paragraphs and lists, in order.
+    pub fn blocks(&self) -> &[RichTextBlock] {
+        &self.blocks
+    }
+
+    /// Parses the lines of one paragraph or list.
+    ///
+    /// A block is a list when its first line is a list item. Later lines that
+    /// are not list items continue the item before them.
+    fn parse_block(lines: &[&str]) -> RichTextBlock {
+        let is_numbered = Self::item_numbered(lines[0]).is_some();
+        let is_bulleted = Self::item_bulleted(lines[0]).is_some();
+        if !is_numbered && !is_bulleted {
+            return RichTextBlock::Paragraph(Self::parse_spans(&lines.join(" ")));
+        }
+
+        let mut items = Vec::<String>::new();
+        for line in lines {
+            let item = if is_numbered {
+                Self::item_numbered(line)
+            } else {
+                Self::item_bulleted(line)
+            };
+            match (item, items.last_mut()) {
+                (Some(item), _) => items.push(item.to_string()),
+                (None, Some(item_last)) => {
+                    item_last.push(' ');
+                    item_last.push_str(line);
+                }
+                (None, None) => items.push(line.to_string()),
+            }
+        }
+        let items = items
+            .iter()
+            .map(|item| Self::parse_spans(item))
+            .collect::<Vec<_>>();
+
+        if is_numbered {
+            RichTextBlock::ListNumbered(items)
+        } else {
+            RichTextBlock::ListBulleted(items)
+        }
+    }
+
+    /// Returns the text of a `- item` or `* item` line.
+    fn item_bulleted(line: &str) -> Option<&str> {
+        line.strip_prefix("- ")
+            .or_else(|| line.strip_prefix("* "))
+            .map(str::trim_start)
+    }
+
+    /// Returns the text of a `1. item` line.
+    fn item_numbered(line: &str) -> Option<&str> {
+        let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return None;
+        }
+        line[digits..].strip_prefix(". ").map(str::trim_start)
+    }
+
+    /// Parses emphasis, links, and escapes in a line of text.
+    fn parse_spans(text: &str) -> Vec<RichTextSpan> {
+        let mut spans = Vec::new();
+        let mut text_pending = String::new();
+        let mut c_previous = None::<char>;
+
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let mut span_parsed = None;
+            match c {
+                '\\' => {
+                    if let Some(c_escaped) = rest[1..].chars().next() {
+                        if c_escaped.is_ascii_punctuation() {
+                            text_pending.push(c_escaped);
+                            c_previous = Some(c_escaped);
+                            rest = &rest[1 + c_escaped.len_utf8()..];
+                            continue;
+                        }
+                    }
+                }
+                '*' | '_' => {
+                    let run = rest.len() - rest.trim_start_matches(c).len();
+                    let is_intraword = c == '_' && c_previous.is_some_and(char::is_alphanumeric);
+                    span_parsed = (!is_intraword)
+                        .then(|| Self::parse_emphasis(rest, c, run))
+                        .flatten();
+                    if span_parsed.is_none() {
+                        text_pending.push_str(&rest[..run]);
+                        c_previous = Some(c);
+                        rest = &rest[run..];
+                        continue;
+                    }
+                }
+                '[' => span_parsed = Self::parse_link(rest),
+                _ => {}
+            }
+
+            match span_parsed {
+                Some((span, rest_after)) => {
+                    if !text_pending.is_empty() {
+                        spans.push(RichTextSpan::Text(std::mem::take(&mut text_pending)));
+                    }
+                    spans.push(span);
+                    c_previous = rest[..rest.len() - rest_after.len()].chars().next_back();
+                    rest = rest_after;
+                }
+                None => {
+                    text_pending.push(c);
+                    c_previous = Some(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        if !text_pending.is_empty() {
+            spans.push(RichTextSpan::Text(text_pending));
+        }
+
+        spans
+    }
+
+    /// Parses `*text*` or `**text**` at the start of `text`, returning the
+    /// span and the text after it.
+    ///
+    /// The text inside must not be empty or start or end with whitespace, so
+    /// that e.g. `2 * 3 * 4` stays as it is.
+    fn parse_emphasis(text: &str, delimiter: char, run: usize) -> Option<(RichTextSpan, &str)> {
+        if run > 2 {
+            return None;
+        }
+        let inner = &text[run..];
+        if inner.starts_with(char::is_whitespace) {
+            return None;
+        }
+
+        let mut chars = inner.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            if c == '\\' {
+                chars.next();
+                continue;
+            }
+            if c != delimiter {
+                continue;
+            }
+
+            let run_close = inner[offset..].len() - inner[offset..].trim_start_matches(c).len();
+            while chars
+                .next_if(|(index, _)| *index < offset + run_close)
+                .is_some()
+            {}
+
+            let content = &inner[..offset];
+            let rest = &inner[offset + run_close..];
+            let is_intraword = delimiter == '_' && rest.starts_with(char::is_alphanumeric);
+            let is_closing = run_close == run
+                && !content.is_empty()
+                && !content.ends_with(char::is_whitespace)
+                && !is_intraword;
+            if is_closing {
+                let spans = Self::parse_spans(content);
+                let span = if run == 2 {
+                    RichTextSpan::Strong(spans)
+                } else {
+                    RichTextSpan::Emphasis(spans)
+                };
+                return Some((span, rest));
+            }
+        }
+
+        None
+    }
+
+    /// Parses `[text](url)` at the start of `text`, returning the span and the
+    /// text after it.
+    fn parse_link(text: &str) -> Option<(RichTextSpan, &str)> {
+        let mut depth = 0;
+        let label_end = text.char_indices().find_map(|(offset, c)| {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => {}
+            }
+            (depth == 0).then_some(offset)
+        })?;
+        let label = &text[1..label_end];
+
+        let rest = text[label_end + 1..].strip_prefix('(')?;
+        let url_end = rest.find(')')?;
+        let url = rest[..url_end].trim();
+
+        let is_url_safe = URL_SCHEMES
+            .iter()
+            .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme))
+            && !url.contains(char::is_whitespace);
+        if label.trim().is_empty() || !is_url_safe {
+            return None;
+        }
+
+        let span = RichTextSpan::Link {
+            spans: Self::parse_spans(label),
+            url: url.to_string(),
+        };
+        Some((span, &rest[url_end + 1..]))
+    }
+
+    /// Writes spans without markup.
+    fn fmt_spans(f: &mut fmt::Formatter<'_>, spans: &[RichTextSpan]) -> fmt::Result {
+        spans.iter().try_for_each(|span| match span {
+            RichTextSpan::Text(text) => f.write_str(text),
+            RichTextSpan::Emphasis(spans) | RichTextSpan::Strong(spans) => {
+                Self::fmt_spans(f, spans)
+            }
+            RichTextSpan::Link { spans, url } => {
+                Self::fmt_spans(f, spans)?;
+                write!(f, " ({url})")
+            }
+        })
+    }
+}
+
+impl Display for RichText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.blocks
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, block)| {
+                if index > 0 {
+                    f.write_str("\n\n")?;
+                }
+                match block {
+                    RichTextBlock::Paragraph(spans) => Self::fmt_spans(f, spans),
+                    RichTextBlock::ListBulleted(items) => {
+                        items.iter().enumerate().try_for_each(|(index, item)| {
+                            if index > 0 {
+                                f.write_str("\n")?;
+                            }
+                            f.write_str("- ")?;
+                            Self::fmt_spans(f, item)
+                        })
+                    }
+                    RichTextBlock::ListNumbered(items) => {
+                        items.iter().enumerate().try_for_each(|(index, item)| {
+                            if index > 0 {
+                                f.write_str("\n")?;
+                            }
+                            write!(f, "{}. ", index + 1)?;
+                            Self::fmt_spans(f, item)
+                        })
+                    }
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RichText, RichTextBlock, RichTextSpan};
+
+    fn text(text: &str) -> RichTextSpan {
+        RichTextSpan::Text(text.to_string())
+    }
+
+    /// Returns the spans of rich text that is one paragraph.
+    fn paragraph(rich_text: &str) -> Vec<RichTextSpan> {
+        match RichText::parse(rich_text).blocks() {
+            [RichTextBlock::Paragraph(spans)] => spans.clone(),
+            blocks => panic!("Expected one paragraph, but got: {blocks:?}"),
+        }
+    }
+
+    #[test]
+    fn blank_lines_separate_paragraphs() {
+        let rich_text = RichText::parse("Sit nearby.\nStay quiet.\n\n\nOffer tea.");
+
+        assert_eq!(
+            &[
+                RichTextBlock::Paragraph(vec![text("Sit nearby. Stay quiet.")]),
+                RichTextBlock::Paragraph(vec![text("Offer tea.")]),
+            ],
+            rich_text.blocks()
+        );
+    }
+
+    #[test]
+    fn lists_are_parsed_with_continuation_lines() {
+        let rich_text =
+            RichText::parse("- Sit nearby\n  and stay quiet.\n* Offer tea.\n\n1. Wait.\n2. Leave.");
+
+        assert_eq!(
+            &[
+                RichTextBlock::ListBulleted(vec![
+                    vec![text("Sit nearby and stay quiet.")],
+                    vec![text("Offer tea.")],
+                ]),
+                RichTextBlock::ListNumbered(vec![vec![text("Wait.")], vec![text("Leave.")]]),
+            ],
+            rich_text.blocks()
+        );
+    }
+
+    #[test]
+    fn emphasis_and_strong_are_parsed() {
+        assert_eq!(
+            vec![
+                RichTextSpan::Emphasis(vec![text("gently")]),
+                text(" and "),
+                RichTextSpan::Strong(vec![text("quietly")]),
+                text(", "),
+                RichTextSpan::Emphasis(vec![text("softly")]),
+                text(" and "),
+                RichTextSpan::Strong(vec![text("slowly")]),
+            ],
+            paragraph("*gently* and **quietly**, _softly_ and __slowly__")
+        );
+    }
+
+    #[test]
+    fn intraword_underscores_are_text() {
+        assert_eq!(
+            vec![text("Use snake_case_names and _half_open.")],
+            paragraph("Use snake_case_names and _half_open.")
+        );
+    }
+
+    #[test]
+    fn placeholder_underscores_are_text() {
+        assert_eq!(
+            vec![text("Ask \"do you want ____?\"")],
+            paragraph("Ask \"do you want ____?\"")
+        );
+    }
+
+    #[test]
+    fn spaced_asterisks_are_text() {
+        assert_eq!(vec![text("2 * 3 * 4")], paragraph("2 * 3 * 4"));
+    }
+
+    #[test]
+    fn escaped_punctuation_is_text() {
+        assert_eq!(
+            vec![text("*not emphasis* and [not a link]")],
+            paragraph("\\*not emphasis\\* and \\[not a link]")
+        );
+    }
+
+    #[test]
+    fn links_with_allowed_schemes_are_parsed() {
+        assert_eq!(
+            vec![
+                text("Call "),
+                RichTextSpan::Link {
+                    spans: vec![text("a helpline")],
+                    url: String::from("https://example.com/help"),
+                },
+                text(" or "),
+                RichTextSpan::Link {
+                    spans: vec![text("email")],
+                    url: String::from("mailto:help@example.com"),
+                },
+                text("."),
+            ],
+            paragraph(
+                "Call [a helpline](https://example.com/help) or [email](mailto:help@example.com)."
+            )
+        );
+    }
+
+    #[test]
+    fn links_with_other_schemes_are_text() {
+        assert_eq!(
+            vec![text("[click](javascript:alert(1)) and [empty](https://)")],
+            paragraph("[click](javascript:alert(1)) and [empty](https://)")
+        );
+    }
+
+    #[test]
+    fn display_writes_text_without_markup() {
+        let rich_text =
+            RichText::parse("*Sit* with them.\n\n- Offer [tea](https://example.com).\n- **Wait**.");
+
+        assert_eq!(
+            "Sit with them.\n\n- Offer tea (https://example.com).\n- Wait.",
+            rich_text.to_string()
+        );
+    }
+}
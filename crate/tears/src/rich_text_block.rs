@@ -0,0 +1,14 @@
+use crate::RichTextSpan;
+
+/// Paragraph or list in [`RichText`].
+///
+/// [`RichText`]: crate::RichText
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RichTextBlock {
+    /// Text separated from other blocks by a blank line.
+    Paragraph(Vec<RichTextSpan>),
+    /// Lines that each start with `- ` or `* `.
+    ListBulleted(Vec<Vec<RichTextSpan>>),
+    /// Lines that each start with a number and `. `, e.g. `1. `.
+    ListNumbered(Vec<Vec<RichTextSpan>>),
+}
@@ -0,0 +1,20 @@
+/// Inline part of a [`RichTextBlock`].
+///
+/// [`RichTextBlock`]: crate::RichTextBlock
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RichTextSpan {
+    /// Text that is written as is.
+    Text(String),
+    /// `*text*` or `_text_`: text that is stressed.
+    Emphasis(Vec<RichTextSpan>),
+    /// `**text**` or `__text__`: text that is important.
+    Strong(Vec<RichTextSpan>),
+    /// `[text](https://example.com)`: a link to further reading.
+    Link {
+        /// Text of the link.
+        spans: Vec<RichTextSpan>,
+        /// Address that the link goes to, which is always `http`, `https`,
+        /// or `mailto`.
+        url: String,
+    },
+}
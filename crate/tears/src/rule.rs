@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+
+use crate::{Predicate, PredicateOutcome, Situation, Suggestion, SuggestionContext};
+
+/// Produces a suggestion when every one of its predicates is satisfied.
+///
+/// e.g. `mood <= Cautious && trust == Absent`.
+///
+/// When more than one rule matches a situation, the most specific rule is
+/// used, then the rule with the highest priority. See [`RuleSet::select`].
+///
+/// [`RuleSet::select`]: crate::RuleSet::select
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    /// Identifies the rule to content authors, e.g. `"absent-overwhelmed"`.
+    pub id: Cow<'static, str>,
+    /// Conditions that must all be satisfied for the rule to match.
+    ///
+    /// A rule without predicates matches every situation.
+    pub predicates: Vec<Predicate>,
+    /// Decides between matching rules that are equally specific. Higher
+    /// priorities win.
+    pub priority: i32,
+    /// Suggestion to show when the rule is used.
+    pub suggestion: Suggestion,
+}
+
+impl Rule {
+    /// Returns a new `Rule` with a priority of `0`.
+    pub fn new(
+        id: impl Into<Cow<'static, str>>,
+        predicates: Vec<Predicate>,
+        suggestion: Suggestion,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            predicates,
+            priority: 0,
+            suggestion,
+        }
+    }
+
+    /// Returns this rule with the given priority.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the number of dimensions this rule checks.
+    ///
+    /// Predicates on the same dimension count once, so
+    /// `mood >= Closed && mood <= Cautious` is as specific as
+    /// `mood == Closed`.
+    pub fn specificity(&self) -> usize {
+        let mut dimensions = self
+            .predicates
+            .iter()
+            .map(Predicate::dimension)
+            .collect::<Vec<_>>();
+        dimensions.sort_unstable();
+        dimensions.dedup();
+        dimensions.len()
+    }
+
+    /// Returns the outcome of each predicate for the situation.
+    pub fn evaluate(&self, situation: &Situation) -> Vec<(Predicate, PredicateOutcome)> {
+        self.predicates
+            .iter()
+            .map(|predicate| (*predicate, predicate.evaluate(situation)))
+            .collect()
+    }
+
+    /// Returns whether every predicate is satisfied by the situation.
+    pub fn matches(&self, situation: &Situation) -> bool {
+        self.predicates
+            .iter()
+            .all(|predicate| predicate.evaluate(situation).is_satisfied())
+    }
+
+    /// Returns the context dimensions that this rule requires a particular
+    /// value for.
+    pub fn context(&self) -> SuggestionContext {
+        self.predicates.iter().fold(
+            SuggestionContext::new(),
+            |context, predicate| match *predicate {
+                Predicate::AgeGroup(age_group) => context.with_age_group(age_group),
+                Predicate::Relationship(relationship) => context.with_relationship(relationship),
+                Predicate::Setting(setting) => context.with_setting(setting),
+                Predicate::Trust(_) | Predicate::Mood(..) => context,
+            },
+        )
+    }
+}
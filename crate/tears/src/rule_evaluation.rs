@@ -0,0 +1,35 @@
+use std::borrow::Cow;
+
+use crate::{Predicate, PredicateOutcome, Rule, Situation};
+
+/// How a [`Rule`] was evaluated for a [`Situation`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleEvaluation {
+    /// ID of the rule.
+    pub rule_id: Cow<'static, str>,
+    /// See [`Rule::specificity`].
+    pub specificity: usize,
+    /// See [`Rule::priority`].
+    pub priority: i32,
+    /// Outcome of each of the rule's predicates.
+    pub predicate_outcomes: Vec<(Predicate, PredicateOutcome)>,
+}
+
+impl RuleEvaluation {
+    /// Evaluates the rule for the situation.
+    pub fn new(rule: &Rule, situation: &Situation) -> Self {
+        Self {
+            rule_id: rule.id.clone(),
+            specificity: rule.specificity(),
+            priority: rule.priority,
+            predicate_outcomes: rule.evaluate(situation),
+        }
+    }
+
+    /// Returns whether every predicate was satisfied.
+    pub fn is_match(&self) -> bool {
+        self.predicate_outcomes
+            .iter()
+            .all(|(_predicate, outcome)| outcome.is_satisfied())
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt::{self, Display};
+
+use crate::{RuleEvaluation, Situation, Trust};
+
+/// Which [`Rule`] was used for a [`Situation`], and why.
+///
+/// This is for content authors to check that the rules behave as intended.
+/// The alternate form, `{:#}`, also lists the rules that did not match.
+///
+/// [`Rule`]: crate::Rule
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleExplanation {
+    /// The situation that the rules were evaluated for.
+    pub situation: Situation,
+    /// Trust level the rules were evaluated with, when the situation's trust
+    /// level is unknown, see [`SuggestionCatalog::explain`].
+    ///
+    /// [`SuggestionCatalog::explain`]: crate::SuggestionCatalog::explain
+    pub trust_assumed: Option<Trust>,
+    /// How each rule was evaluated, in the order of the rule set.
+    pub evaluations: Vec<RuleEvaluation>,
+    /// Index into `evaluations` of the rule that was used.
+    pub fired: Option<usize>,
+}
+
+impl RuleExplanation {
+    /// Returns the evaluation of the rule that was used.
+    pub fn fired(&self) -> Option<&RuleEvaluation> {
+        self.fired.map(|index| &self.evaluations[index])
+    }
+
+    /// Returns the rules that matched but were not used, with the reason each
+    /// one lost to the rule that was used.
+    pub fn overruled(&self) -> impl Iterator<Item = (&RuleEvaluation, String)> {
+        let fired = self.fired();
+        self.evaluations
+            .iter()
+            .enumerate()
+            .filter(move |(index, evaluation)| Some(*index) != self.fired && evaluation.is_match())
+            .filter_map(move |(_index, evaluation)| {
+                let fired = fired?;
+                let reason = if evaluation.specificity != fired.specificity {
+                    format!(
+                        "less specific ({} < {})",
+                        evaluation.specificity, fired.specificity
+                    )
+                } else if evaluation.priority != fired.priority {
+                    format!(
+                        "lower priority ({} < {})",
+                        evaluation.priority, fired.priority
+                    )
+                } else {
+                    String::from("same specificity and priority, but listed later")
+                };
+                Some((evaluation, reason))
+            })
+    }
+
+    /// Returns the rules that did not match.
+    pub fn unmatched(&self) -> impl Iterator<Item = &RuleEvaluation> {
+        self.evaluations
+            .iter()
+            .filter(|evaluation| !evaluation.is_match())
+    }
+}
+
+impl Display for RuleExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Situation: {}", self.situation)?;
+        if let Some(trust) = self.trust_assumed {
+            writeln!(
+                f,
+                "Trust unknown, so the most cautious trust level with a suggestion is assumed: {}",
+                trust.name()
+            )?;
+        }
+
+        match self.fired() {
+            Some(fired) => {
+                writeln!(
+                    f,
+                    "Fired: `{}` (specificity {}, priority {})",
+                    fired.rule_id, fired.specificity, fired.priority
+                )?;
+                if fired.predicate_outcomes.is_empty() {
+                    writeln!(f, "  no predicates, matches every situation")?;
+                }
+                fired
+                    .predicate_outcomes
+                    .iter()
+                    .try_for_each(|(predicate, _outcome)| writeln!(f, "  {predicate}: yes"))?;
+            }
+            None => match (self.situation.trust, self.situation.mood) {
+                (Some(_trust), None) => writeln!(
+                    f,
+                    "Fired: none, rules need a mood, so the general guidance for the trust \
+                     level is shown"
+                )?,
+                (None, None) => writeln!(f, "Fired: none, rules need a mood")?,
+                (_trust, Some(_mood)) => writeln!(f, "Fired: none, no rule matched")?,
+            },
+        }
+
+        let mut overruled = self.overruled().peekable();
+        if overruled.peek().is_some() {
+            writeln!(f, "Matched, but overruled:")?;
+            overruled.try_for_each(|(evaluation, reason)| {
+                writeln!(f, "  `{}`: {reason}", evaluation.rule_id)
+            })?;
+        }
+
+        let unmatched_count = self.unmatched().count();
+        if f.alternate() {
+            if unmatched_count > 0 {
+                writeln!(f, "Not matched:")?;
+            }
+            self.unmatched().try_for_each(|evaluation| {
+                let failed = evaluation
+                    .predicate_outcomes
+                    .iter()
+                    .filter(|(_predicate, outcome)| !outcome.is_satisfied())
+                    .map(|(predicate, outcome)| format!("{predicate}: {outcome}"))
+                    .collect::<Vec<_>>();
+                writeln!(f, "  `{}`: {}", evaluation.rule_id, failed.join(", "))
+            })?;
+        } else if unmatched_count > 0 {
+            writeln!(f, "{unmatched_count} other rules did not match.")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::cmp::Reverse;
+
+use crate::{Rule, RuleEvaluation, RuleExplanation, Situation};
+
+/// Rules that suggestions are selected from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// Returns a new `RuleSet`.
+    ///
+    /// The order of `rules` only matters when two matching rules are equally
+    /// specific and have the same priority, in which case the earlier rule is
+    /// used.
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self { rules }
+    }
+
+    /// Returns an iterator over the rules, in the order they were given.
+    pub fn iter(&self) -> impl Iterator<Item = &Rule> {
+        self.rules.iter()
+    }
+
+    /// Returns the rule to use for the situation, if any rule matches.
+    ///
+    /// Of the matching rules, the one with the highest
+    /// [`specificity`](Rule::specificity) is used, then the one with the
+    /// highest [`priority`](Rule::priority), then the earliest one.
+    pub fn select(&self, situation: &Situation) -> Option<&Rule> {
+        self.select_index(situation).map(|index| &self.rules[index])
+    }
+
+    /// Returns how each rule was evaluated for the situation, and which rule
+    /// was used.
+    pub fn explain(&self, situation: &Situation) -> RuleExplanation {
+        let evaluations = self
+            .rules
+            .iter()
+            .map(|rule| RuleEvaluation::new(rule, situation))
+            .collect();
+
+        RuleExplanation {
+            situation: *situation,
+            trust_assumed: None,
+            evaluations,
+            fired: self.select_index(situation),
+        }
+    }
+
+    fn select_index(&self, situation: &Situation) -> Option<usize> {
+        self.rules
+            .iter()
+            .enumerate()
+            .filter(|(_index, rule)| rule.matches(situation))
+            .min_by_key(|(index, rule)| {
+                (Reverse(rule.specificity()), Reverse(rule.priority), *index)
+            })
+            .map(|(index, _rule)| index)
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{Mood, Trust, UiText};
+
+/// A part of a conversation that there are example sayings for, e.g. how to
+/// open it.
+///
+/// Examples respect the trust and mood level they are given for.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SayingKind {
+    /// How to start talking.
+    Opener,
+    /// What to say when the person responds.
+    FollowUp,
+    /// How to end the conversation, or leave, gently.
+    Exit,
+    /// What not to say.
+    DontSay,
+}
+
+impl SayingKind {
+    /// Returns an iterator over all variants of this `SayingKind` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = SayingKind> {
+        [
+            SayingKind::Opener,
+            SayingKind::FollowUp,
+            SayingKind::Exit,
+            SayingKind::DontSay,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the identifier of this kind within content keys, e.g.
+    /// `"follow_up"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            SayingKind::Opener => "opener",
+            SayingKind::FollowUp => "follow_up",
+            SayingKind::Exit => "exit",
+            SayingKind::DontSay => "dont_say",
+        }
+    }
+
+    /// Returns the label for this kind in the user interface.
+    pub fn ui_text(self) -> UiText {
+        match self {
+            SayingKind::Opener => UiText::SayingOpener,
+            SayingKind::FollowUp => UiText::SayingFollowUp,
+            SayingKind::Exit => UiText::SayingExit,
+            SayingKind::DontSay => UiText::SayingDontSay,
+        }
+    }
+
+    /// Returns example sayings of this kind for the trust and mood level.
+    pub fn examples(self, trust: Trust, mood: Mood) -> &'static [&'static str] {
+        let [openers, follow_ups, exits, dont_says] = sayings(trust, mood);
+        match self {
+            SayingKind::Opener => openers,
+            SayingKind::FollowUp => follow_ups,
+            SayingKind::Exit => exits,
+            SayingKind::DontSay => dont_says,
+        }
+    }
+}
+
+/// Returns the openers, follow-ups, exits, and what not to say, for the trust
+/// and mood level.
+fn sayings(trust: Trust, mood: Mood) -> [&'static [&'static str]; 4] {
+    match (trust, mood) {
+        (Trust::Absent, Mood::_01_Anguished) => [
+            &[
+                "\"I'm going to find someone to be with you.\"",
+                "\"I'll stay over there, in case you need anything.\"",
+            ],
+            &[
+                "\"Help is on the way.\"",
+                "\"You don't have to say anything.\"",
+            ],
+            &[
+                "\"I'll be just outside.\"",
+                "\"Someone you know is coming.\"",
+            ],
+            &[
+                "\"What's wrong? Talk to me.\"",
+                "\"Calm down, it's not that bad.\"",
+            ],
+        ],
+        (Trust::Absent, Mood::_02_Closed) => [
+            &[
+                "\"I left some tea for you.\"",
+                "\"This is for you. No need to say anything.\"",
+            ],
+            &["\"You're welcome.\"", "\"Okay.\""],
+            &[
+                "\"I'll leave you be.\"",
+                "\"I'll be around if you need anything.\"",
+            ],
+            &["\"Why won't you talk to me?\"", "\"Cheer up!\""],
+        ],
+        (Trust::Absent, Mood::_03_Cautious) => [
+            &[
+                "\"Do you want some water? You don't have to answer.\"",
+                "\"Tea? It's fine if not.\"",
+            ],
+            &["\"Okay, I'll bring it.\"", "\"No problem.\""],
+            &[
+                "\"I'll be in the kitchen if you change your mind.\"",
+                "\"I'll leave you to it.\"",
+            ],
+            &[
+                "\"Why are you being like this?\"",
+                "\"You have to eat something.\"",
+            ],
+        ],
+        (Trust::Absent, Mood::_04_Unsettled) => [
+            &[
+                "\"Would you like to say anything?\"",
+                "\"I'm listening, if you want to talk.\"",
+            ],
+            &["\"That sounds hard.\"", "\"Take your time.\""],
+            &[
+                "\"Thank you for telling me.\"",
+                "\"I'll let you be. I'm around if you need me.\"",
+            ],
+            &[
+                "\"You should just ignore them.\"",
+                "\"I know exactly how you feel.\"",
+            ],
+        ],
+        (Trust::Absent, Mood::_05_Calm) => [
+            &[
+                "\"It's good to see you.\"",
+                "\"Nice day for a walk, isn't it?\"",
+            ],
+            &["\"That sounds nice.\"", "\"Tell me more, if you like.\""],
+            &[
+                "\"I'll let you get on. See you soon.\"",
+                "\"It was nice to chat.\"",
+            ],
+            &[
+                "\"So, are you finally over it?\"",
+                "\"See, it wasn't that bad.\"",
+            ],
+        ],
+        (Trust::Absent, Mood::_06_Hopeful) => [
+            &[
+                "\"Want to grab a coffee sometime?\"",
+                "\"I saw something that made me think of you.\"",
+            ],
+            &["\"I'd like that.\"", "\"What would you like to do?\""],
+            &["\"This was fun. Let's do it again.\"", "\"See you soon.\""],
+            &[
+                "\"Good, you're back to normal.\"",
+                "\"Don't go falling apart again.\"",
+            ],
+        ],
+        (Trust::Present, Mood::_01_Anguished) => [
+            &["\"I'm here.\"", "\"I'm not going anywhere.\""],
+            &[
+                "\"You're not alone.\"",
+                "\"We'll get through this together.\"",
+            ],
+            &[
+                "\"I'll stay until someone else is here.\"",
+                "\"I'm just next door, and I'll check on you soon.\"",
+            ],
+            &["\"Pull yourself together.\"", "\"Why would you do this?\""],
+        ],
+        (Trust::Present, Mood::_02_Closed) => [
+            &[
+                "\"I'll sit over here for a while.\"",
+                "\"No need to talk. I'm just nearby.\"",
+            ],
+            &["\"Okay.\"", "\"I'm still here.\""],
+            &[
+                "\"I'll be in the next room.\"",
+                "\"Come and find me whenever you want.\"",
+            ],
+            &["\"You can't keep it bottled up.\"", "\"Look at me.\""],
+        ],
+        (Trust::Present, Mood::_03_Cautious) => [
+            &[
+                "\"Want some tea? It's fine if not.\"",
+                "\"I'm making a snack. Want one?\"",
+            ],
+            &["\"Sure.\"", "\"Okay, maybe later.\""],
+            &[
+                "\"I'll be around.\"",
+                "\"Let me know if you change your mind.\"",
+            ],
+            &["\"Why are you so upset?\"", "\"You need to get out more.\""],
+        ],
+        (Trust::Present, Mood::_04_Unsettled) => [
+            &[
+                "\"Would you like to say anything?\"",
+                "\"I'm here if you want to talk.\"",
+            ],
+            &[
+                "\"That makes sense.\"",
+                "\"Do you want ideas, or just to talk?\"",
+            ],
+            &[
+                "\"Thanks for telling me.\"",
+                "\"We can talk more whenever you want.\"",
+            ],
+            &["\"Others have it worse.\"", "\"If I were you, I would...\""],
+        ],
+        (Trust::Present, Mood::_05_Calm) => [
+            &[
+                "\"Fancy a walk?\"",
+                "\"How about we watch something together?\"",
+            ],
+            &[
+                "\"I'm glad you're here.\"",
+                "\"What would you like to do next?\"",
+            ],
+            &["\"I enjoyed this.\"", "\"Same time next week?\""],
+            &[
+                "\"Finally, you're smiling.\"",
+                "\"Let's talk about what happened.\"",
+            ],
+        ],
+        (Trust::Present, Mood::_06_Hopeful) => [
+            &[
+                "\"Let's plan something fun.\"",
+                "\"Remember when we went to the beach?\"",
+            ],
+            &["\"That sounds great.\"", "\"You choose.\""],
+            &[
+                "\"Today was good. Thank you.\"",
+                "\"Looking forward to next time.\"",
+            ],
+            &["\"You were such a mess back then.\"", "\"Don't ruin it.\""],
+        ],
+    }
+}
+
+impl Display for SayingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id().fmt(f)
+    }
+}
+
+impl FromStr for SayingKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SayingKind::iter()
+            .find(|saying_kind| saying_kind.id() == s)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Where you are with the receiving person.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Setting {
+    /// At their home, or yours.
+    Home,
+    /// At work or school.
+    Work,
+    /// In a hospital or clinic.
+    Hospital,
+    /// In a public place, e.g. on the street or in a shop.
+    Public,
+}
+
+impl Setting {
+    /// Returns an iterator over all variants of this `Setting` enum.
+    pub fn iter() -> impl Iterator<Item = Setting> {
+        [
+            Setting::Home,
+            Setting::Work,
+            Setting::Hospital,
+            Setting::Public,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the name of this setting, e.g. `"Home"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Setting::Home => "Home",
+            Setting::Work => "Work",
+            Setting::Hospital => "Hospital",
+            Setting::Public => "Public",
+        }
+    }
+}
+
+impl Display for Setting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for Setting {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Setting::iter()
+            .find(|setting| setting.name() == s)
+            .ok_or(())
+    }
+}
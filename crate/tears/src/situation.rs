@@ -0,0 +1,61 @@
+use std::fmt::{self, Display};
+
+use crate::{Mood, SuggestionContext, Trust};
+
+/// What is known about the receiving person, which [`Rule`]s are matched
+/// against.
+///
+/// [`Rule`]: crate::Rule
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Situation {
+    /// Whether the receiving person trusts you, if known.
+    pub trust: Option<Trust>,
+    /// The receiving person's mood, if known.
+    pub mood: Option<Mood>,
+    /// Other details about the situation.
+    pub context: SuggestionContext,
+}
+
+impl Situation {
+    /// Returns a new `Situation`.
+    pub fn new(trust: Option<Trust>, mood: Option<Mood>, context: SuggestionContext) -> Self {
+        Self {
+            trust,
+            mood,
+            context,
+        }
+    }
+}
+
+impl Display for Situation {
+    /// Writes the known dimensions, e.g. `trust = Absent, mood = Closed`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            trust,
+            mood,
+            context,
+        } = self;
+        let dimensions = [
+            trust.map(|trust| format!("trust = {trust}")),
+            mood.map(|mood| format!("mood = {mood}")),
+            context
+                .age_group
+                .map(|age_group| format!("age group = {age_group}")),
+            context
+                .relationship
+                .map(|relationship| format!("relationship = {relationship}")),
+            context
+                .setting
+                .map(|setting| format!("setting = {setting}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if dimensions.is_empty() {
+            "nothing known".fmt(f)
+        } else {
+            dimensions.join(", ").fmt(f)
+        }
+    }
+}
@@ -1,23 +1,128 @@
+use std::{borrow::Cow, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Principle, ReadingLevel, SuggestionError, Template};
+
 /// A suggestion to show for a given trust + mood level.
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Suggestion {
     /// Action to take, e.g. `"Stay Away"`.
-    pub action: &'static str,
+    pub action: Cow<'static, str>,
     /// Description or rationale.
     ///
     /// e.g.
     ///
     /// > Your presence pressurizes the person to be aware of you, and does not
     /// > allow them to settle down.
-    pub description: &'static str,
+    pub description: Cow<'static, str>,
+    /// One line version of the description, shown instead of it at
+    /// [`ReadingLevel::Glance`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glance: Option<Cow<'static, str>>,
+    /// Longer explanation, shown after the description at
+    /// [`ReadingLevel::Extended`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extended: Option<Cow<'static, str>>,
+    /// Principles that this suggestion applies, in order of importance.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub principles: Vec<Principle>,
+    /// How long to wait quietly after acting on this suggestion, before
+    /// speaking again or leaving, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_seconds: Option<u32>,
 }
 
 impl Suggestion {
     pub fn action(&self) -> &str {
-        self.action
+        &self.action
     }
 
     pub fn description(&self) -> &str {
-        self.description
+        &self.description
+    }
+
+    pub fn glance(&self) -> Option<&str> {
+        self.glance.as_deref()
+    }
+
+    pub fn extended(&self) -> Option<&str> {
+        self.extended.as_deref()
+    }
+
+    pub fn principles(&self) -> &[Principle] {
+        &self.principles
+    }
+
+    /// Returns how long to wait quietly after acting on this suggestion, if
+    /// the suggestion recommends waiting.
+    pub fn wait(&self) -> Option<Duration> {
+        self.wait_seconds
+            .map(|wait_seconds| Duration::from_secs(u64::from(wait_seconds)))
+    }
+
+    /// Returns the texts to show under the action at the given reading
+    /// level, in order.
+    ///
+    /// At [`ReadingLevel::Glance`], suggestions without glance text only show
+    /// the action.
+    pub fn description_at(&self, reading_level: ReadingLevel) -> Vec<&str> {
+        match reading_level {
+            ReadingLevel::Glance => self.glance().into_iter().collect(),
+            ReadingLevel::Standard => vec![self.description()],
+            ReadingLevel::Extended => std::iter::once(self.description())
+                .chain(self.extended())
+                .collect(),
+        }
+    }
+
+    /// Returns the problems that prevent this suggestion from being saved to
+    /// a [`CatalogFile`].
+    ///
+    /// [`CatalogFile`]: crate::CatalogFile
+    pub fn validate(&self) -> Vec<SuggestionError> {
+        let mut errors = Vec::new();
+        if self.action.trim().is_empty() {
+            errors.push(SuggestionError::ActionEmpty);
+        } else if self.action.contains('\n') {
+            errors.push(SuggestionError::ActionMultiLine);
+        }
+        if self.description.trim().is_empty() {
+            errors.push(SuggestionError::DescriptionEmpty);
+        }
+        if let Err(error) = Template::parse(&self.action) {
+            errors.push(SuggestionError::ActionTemplate { error });
+        }
+        if let Err(error) = Template::parse(&self.description) {
+            errors.push(SuggestionError::DescriptionTemplate { error });
+        }
+        if let Some(glance) = self.glance() {
+            if glance.contains('\n') {
+                errors.push(SuggestionError::GlanceMultiLine);
+            }
+            if let Err(error) = Template::parse(glance) {
+                errors.push(SuggestionError::GlanceTemplate { error });
+            }
+        }
+        if let Some(Err(error)) = self.extended().map(Template::parse) {
+            errors.push(SuggestionError::ExtendedTemplate { error });
+        }
+        if self.principles.is_empty() {
+            errors.push(SuggestionError::PrinciplesEmpty);
+        }
+        let principle_repeated = self
+            .principles
+            .iter()
+            .enumerate()
+            .find(|(index, principle)| self.principles[..*index].contains(principle));
+        if let Some((_, principle)) = principle_repeated {
+            errors.push(SuggestionError::PrincipleRepeated {
+                principle: *principle,
+            });
+        }
+        if self.wait_seconds == Some(0) {
+            errors.push(SuggestionError::WaitZero);
+        }
+        errors
     }
 }
@@ -0,0 +1,671 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::{
+    AgeGroup, CatalogCell, CatalogFile, Comparison, ContentKey, Guidance, GuidanceBasis, Mood,
+    MoodRange, Predicate, Principle, Rule, RuleExplanation, RuleSet, Situation, Suggestion,
+    SuggestionContext, Trust,
+};
+
+/// Suggestions for each trust and mood level.
+///
+/// Suggestions may be narrowed down by a [`SuggestionContext`], such as the
+/// relationship with the person or the setting. The most specific suggestion
+/// that matches the context is used, falling back to the suggestion for the
+/// trust and mood level.
+///
+/// Suggestions are selected by a [`RuleSet`]. Each entry for a trust and mood
+/// level becomes a rule that requires that trust level, mood, and context.
+/// Content authors may add rules of their own, which are selected together
+/// with the entries, see [`SuggestionCatalog::with_rules`].
+///
+/// The catalog also holds age specific variants of each mood's symptoms, as
+/// the same mood shows differently in a child than in an adult.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuggestionCatalog {
+    /// Suggestions for when both the trust and mood levels are known.
+    suggestions: HashMap<(Trust, Mood), Suggestion>,
+    /// Suggestions that only apply in a non-empty context.
+    context_suggestions: HashMap<(Trust, Mood, SuggestionContext), Suggestion>,
+    /// General guidance for when only the trust level is known.
+    trust_suggestions: HashMap<Trust, Suggestion>,
+    /// Symptoms of a mood for a particular age group.
+    age_symptoms: HashMap<(Mood, AgeGroup), Cow<'static, str>>,
+    /// Rules written by content authors, in addition to the entries above.
+    rules: Vec<Rule>,
+    /// Rules for `rules` and every suggestion entry, used for lookups.
+    rule_set: RuleSet,
+}
+
+impl SuggestionCatalog {
+    /// Priority of the rule for each suggestion entry, below the default
+    /// priority of rules written by content authors.
+    pub const ENTRY_PRIORITY: i32 = -1;
+
+    /// Returns a new `SuggestionCatalog`.
+    ///
+    /// Entries in `context_suggestions` with an empty context are ignored, as
+    /// `suggestions` already holds those.
+    pub fn new(
+        suggestions: HashMap<(Trust, Mood), Suggestion>,
+        mut context_suggestions: HashMap<(Trust, Mood, SuggestionContext), Suggestion>,
+        trust_suggestions: HashMap<Trust, Suggestion>,
+        age_symptoms: HashMap<(Mood, AgeGroup), Cow<'static, str>>,
+    ) -> Self {
+        context_suggestions.retain(|(_trust, _mood, context), _suggestion| !context.is_empty());
+
+        let rules = Vec::new();
+        let rule_set = rule_set_build(&rules, &suggestions, &context_suggestions);
+
+        Self {
+            suggestions,
+            context_suggestions,
+            trust_suggestions,
+            age_symptoms,
+            rules,
+            rule_set,
+        }
+    }
+
+    /// Returns this catalog with the given rules added.
+    ///
+    /// These rules are selected together with the rules for each suggestion
+    /// entry: the most specific rule that matches is used, then the one with
+    /// the highest priority. Entries have a priority of
+    /// [`ENTRY_PRIORITY`](Self::ENTRY_PRIORITY), so
+    /// a rule is used over an entry that is just as specific, e.g. a rule for
+    /// `trust == Absent` and `mood <= Closed` over the entry for `Absent` and
+    /// `Closed`, but not over the one for colleagues at work.
+    ///
+    /// Rules are not translated by [`Localizer::suggestion_catalog`].
+    ///
+    /// [`Localizer::suggestion_catalog`]: crate::Localizer::suggestion_catalog
+    pub fn with_rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules.extend(rules);
+        self.rule_set = rule_set_build(&self.rules, &self.suggestions, &self.context_suggestions);
+        self
+    }
+
+    /// Returns the catalog of suggestions that ship with `tears`.
+    ///
+    /// Every suggestion is read from the built in [`CatalogFile`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the built in catalog file or a built in suggestion is
+    /// invalid. The editor does not save invalid catalog files, so this only
+    /// happens if the file is edited by hand.
+    pub fn builtin() -> Self {
+        let catalog_file = match CatalogFile::builtin() {
+            Ok(catalog_file) => catalog_file,
+            Err(error) => panic!("The built in catalog file is invalid: {error}"),
+        };
+
+        let suggestion_catalog = Self::from_catalog_file(&catalog_file);
+        let rule_error = suggestion_catalog.iter_rules().find_map(|rule| {
+            rule.suggestion
+                .validate()
+                .into_iter()
+                .next()
+                .map(|error| (rule.id.clone(), error))
+        });
+        if let Some((rule_id, error)) = rule_error {
+            panic!("The built in suggestion `{rule_id}` is invalid: {error}");
+        }
+
+        suggestion_catalog
+    }
+
+    /// Returns the catalog of suggestions in the given [`CatalogFile`], e.g.
+    /// one read when the server starts rather than when `tears` is built.
+    pub fn from_catalog_file(catalog_file: &CatalogFile) -> Self {
+        let mut suggestions = HashMap::new();
+        let mut context_suggestions = HashMap::new();
+        let mut trust_suggestions = HashMap::new();
+        catalog_file
+            .iter()
+            .for_each(|(cell, suggestion)| match cell {
+                CatalogCell::Suggestion(trust, mood) => {
+                    suggestions.insert((trust, mood), suggestion.clone());
+                }
+                CatalogCell::ContextSuggestion(trust, mood, context) => {
+                    context_suggestions.insert((trust, mood, context), suggestion.clone());
+                }
+                CatalogCell::TrustSuggestion(trust) => {
+                    trust_suggestions.insert(trust, suggestion.clone());
+                }
+            });
+        let age_symptoms = catalog_file
+            .iter_age_symptoms()
+            .map(|(mood, age_group, symptoms)| {
+                ((mood, age_group), Cow::Owned(symptoms.to_string()))
+            })
+            .collect();
+
+        Self::new(
+            suggestions,
+            context_suggestions,
+            trust_suggestions,
+            age_symptoms,
+        )
+        .with_rules(catalog_file.rules().to_vec())
+    }
+
+    /// Returns the suggestion for the given trust and mood levels.
+    pub fn get(&self, trust: Trust, mood: Mood) -> Option<&Suggestion> {
+        self.suggestions.get(&(trust, mood))
+    }
+
+    /// Returns the suggestion for the given trust and mood levels that only
+    /// applies in exactly the given context.
+    pub fn get_for_context(
+        &self,
+        trust: Trust,
+        mood: Mood,
+        context: SuggestionContext,
+    ) -> Option<&Suggestion> {
+        if context.is_empty() {
+            self.get(trust, mood)
+        } else {
+            self.context_suggestions.get(&(trust, mood, context))
+        }
+    }
+
+    /// Returns the suggestion of the rule selected for the given trust and mood
+    /// levels and context, and the context that rule requires.
+    ///
+    /// Of the suggestion entries, the one for the most specific context is
+    /// used. When two contexts are equally specific, the same order as
+    /// [`SuggestionContext::fallbacks`] is used.
+    pub fn get_in_context(
+        &self,
+        trust: Trust,
+        mood: Mood,
+        context: SuggestionContext,
+    ) -> Option<(SuggestionContext, &Suggestion)> {
+        let situation = Situation::new(Some(trust), Some(mood), context);
+        self.rule_set
+            .select(&situation)
+            .map(|rule| (rule.context(), &rule.suggestion))
+    }
+
+    /// Returns which rule is selected for the situation, and why.
+    ///
+    /// This follows [`SuggestionCatalog::lookup_range_in_context`]:
+    ///
+    /// * When the trust level is unknown, the rules are evaluated with the most
+    ///   cautious trust level that has a suggestion, which is recorded in
+    ///   [`RuleExplanation::trust_assumed`].
+    /// * When the mood is unknown, no rule is used, as the general guidance for
+    ///   the trust level is shown instead.
+    pub fn explain(&self, situation: &Situation) -> RuleExplanation {
+        match (situation.trust, situation.mood) {
+            (None, Some(_mood)) => Trust::iter()
+                .map(|trust| {
+                    let situation_assumed =
+                        Situation::new(Some(trust), situation.mood, situation.context);
+                    RuleExplanation {
+                        situation: *situation,
+                        trust_assumed: Some(trust),
+                        ..self.rule_set.explain(&situation_assumed)
+                    }
+                })
+                .find(|explanation| explanation.fired.is_some())
+                .unwrap_or_else(|| self.rule_set.explain(situation)),
+            (_trust, Some(_mood)) => self.rule_set.explain(situation),
+            (_trust, None) => RuleExplanation {
+                fired: None,
+                ..self.rule_set.explain(situation)
+            },
+        }
+    }
+
+    /// Returns the rules used to select suggestions.
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    /// Returns the general guidance for the given trust level.
+    pub fn get_for_trust(&self, trust: Trust) -> Option<&Suggestion> {
+        self.trust_suggestions.get(&trust)
+    }
+
+    /// Returns the symptoms of the mood for the given age group, if they differ
+    /// from [`Mood::symptoms`].
+    pub fn symptoms_for_age(&self, mood: Mood, age_group: AgeGroup) -> Option<&str> {
+        self.age_symptoms
+            .get(&(mood, age_group))
+            .map(|symptoms| symptoms.as_ref())
+    }
+
+    /// Returns an iterator over the suggestions for each trust and mood level.
+    pub fn iter(&self) -> impl Iterator<Item = (Trust, Mood, &Suggestion)> {
+        self.suggestions
+            .iter()
+            .map(|((trust, mood), suggestion)| (*trust, *mood, suggestion))
+    }
+
+    /// Returns an iterator over the suggestions that only apply in a non-empty
+    /// context.
+    pub fn iter_context(
+        &self,
+    ) -> impl Iterator<Item = (Trust, Mood, SuggestionContext, &Suggestion)> {
+        self.context_suggestions
+            .iter()
+            .map(|((trust, mood, context), suggestion)| (*trust, *mood, *context, suggestion))
+    }
+
+    /// Returns an iterator over the general guidance for each trust level.
+    pub fn iter_trust(&self) -> impl Iterator<Item = (Trust, &Suggestion)> {
+        self.trust_suggestions
+            .iter()
+            .map(|(trust, suggestion)| (*trust, suggestion))
+    }
+
+    /// Returns an iterator over the rules added by
+    /// [`SuggestionCatalog::with_rules`].
+    pub fn iter_rules(&self) -> impl Iterator<Item = &Rule> {
+        self.rules.iter()
+    }
+
+    /// Returns an iterator over the age specific symptoms of each mood.
+    pub fn iter_age_symptoms(&self) -> impl Iterator<Item = (Mood, AgeGroup, &str)> {
+        self.age_symptoms
+            .iter()
+            .map(|((mood, age_group), symptoms)| (*mood, *age_group, symptoms.as_ref()))
+    }
+
+    /// Returns the trust and mood levels whose suggestion applies the given
+    /// principle, in order of trust level and then mood.
+    ///
+    /// A trust and mood level is included if the principle is applied in any
+    /// context, e.g. only for colleagues at work.
+    pub fn principle_states(&self, principle: Principle) -> Vec<(Trust, Mood)> {
+        let applies = |suggestion: &Suggestion| suggestion.principles().contains(&principle);
+        Trust::iter()
+            .flat_map(|trust| Mood::iter().map(move |mood| (trust, mood)))
+            .filter(|(trust, mood)| {
+                self.get(*trust, *mood).is_some_and(applies)
+                    || self
+                        .iter_context()
+                        .any(|(trust_context, mood_context, _, suggestion)| {
+                            (trust_context, mood_context) == (*trust, *mood) && applies(suggestion)
+                        })
+            })
+            .collect()
+    }
+
+    /// Returns the guidance for whichever of the trust and mood levels are
+    /// known.
+    ///
+    /// * When both are known, the suggestion for that trust and mood is used.
+    /// * When only the mood is known, the suggestion for the most cautious
+    ///   trust level is used, i.e. as if the person does not trust you.
+    /// * When only the trust level is known, the general guidance for that
+    ///   trust level is used.
+    pub fn lookup(&self, trust: Option<Trust>, mood: Option<Mood>) -> Option<Guidance> {
+        self.lookup_range(trust, mood.map(MoodRange::from))
+    }
+
+    /// Returns the guidance for whichever of the trust level and mood range
+    /// are known.
+    ///
+    /// The suggestion is for the lower bound of the mood range, as that is the
+    /// safer mood to act on. If the suggestion for the upper bound is
+    /// different, it is returned in [`Guidance::upper_bound`].
+    ///
+    /// See [`SuggestionCatalog::lookup`] for how partial information is
+    /// handled.
+    pub fn lookup_range(
+        &self,
+        trust: Option<Trust>,
+        mood_range: Option<MoodRange>,
+    ) -> Option<Guidance> {
+        self.lookup_range_in_context(trust, mood_range, SuggestionContext::default())
+    }
+
+    /// Returns the guidance for whichever of the trust level and mood range
+    /// are known, narrowed down by the context.
+    ///
+    /// The context only applies when the mood is known, as the general
+    /// guidance for a trust level does not vary by context.
+    ///
+    /// See [`SuggestionCatalog::lookup_range`] for how the mood range is
+    /// handled.
+    pub fn lookup_range_in_context(
+        &self,
+        trust: Option<Trust>,
+        mood_range: Option<MoodRange>,
+        context: SuggestionContext,
+    ) -> Option<Guidance> {
+        let (context_matched, suggestion, basis) = match (trust, mood_range) {
+            (Some(trust), Some(mood_range)) => {
+                let (context_matched, suggestion) =
+                    self.get_in_context(trust, mood_range.lower(), context)?;
+                (context_matched, suggestion, GuidanceBasis::TrustAndMood)
+            }
+            (None, Some(mood_range)) => {
+                let (context_matched, suggestion) =
+                    self.get_most_cautious(mood_range.lower(), context)?;
+                (context_matched, suggestion, GuidanceBasis::MoodOnly)
+            }
+            (Some(trust), None) => (
+                SuggestionContext::default(),
+                self.get_for_trust(trust)?,
+                GuidanceBasis::TrustOnly,
+            ),
+            (None, None) => return None,
+        };
+
+        let upper_bound = mood_range
+            .filter(|mood_range| !mood_range.is_single())
+            .and_then(|mood_range| match trust {
+                Some(trust) => self.get_in_context(trust, mood_range.upper(), context),
+                None => self.get_most_cautious(mood_range.upper(), context),
+            })
+            .map(|(_context, upper_bound)| upper_bound)
+            .filter(|upper_bound| *upper_bound != suggestion)
+            .cloned();
+
+        Some(Guidance {
+            suggestion: suggestion.clone(),
+            basis,
+            upper_bound,
+            context: context_matched,
+        })
+    }
+
+    /// Returns the suggestion for the given mood and context at the most
+    /// cautious trust level that has one.
+    fn get_most_cautious(
+        &self,
+        mood: Mood,
+        context: SuggestionContext,
+    ) -> Option<(SuggestionContext, &Suggestion)> {
+        // `Trust::iter()` begins with the most cautious variant.
+        Trust::iter().find_map(|trust| self.get_in_context(trust, mood, context))
+    }
+}
+
+/// Returns the rules for `rules` and each suggestion entry.
+///
+/// For each trust and mood level, the context entries are listed from the most
+/// to the least specific, in the same order as
+/// [`SuggestionContext::fallbacks`], followed by the entry that applies in any
+/// context.
+fn rule_set_build(
+    rules: &[Rule],
+    suggestions: &HashMap<(Trust, Mood), Suggestion>,
+    context_suggestions: &HashMap<(Trust, Mood, SuggestionContext), Suggestion>,
+) -> RuleSet {
+    let mut contexts = context_suggestions
+        .keys()
+        .map(|(_trust, _mood, context)| *context)
+        .collect::<Vec<_>>();
+    contexts.sort_unstable_by_key(|context| context.specificity_order());
+    contexts.dedup();
+    let contexts = &contexts;
+
+    let entry_rules = Trust::iter().flat_map(|trust| {
+        Mood::iter().flat_map(move |mood| {
+            contexts
+                .iter()
+                .copied()
+                .chain(std::iter::once(SuggestionContext::default()))
+                .filter_map(move |context| {
+                    let suggestion = if context.is_empty() {
+                        suggestions.get(&(trust, mood))
+                    } else {
+                        context_suggestions.get(&(trust, mood, context))
+                    }?;
+                    Some(entry_rule(trust, mood, context, suggestion))
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    RuleSet::new(rules.iter().cloned().chain(entry_rules).collect())
+}
+
+/// Returns the rule for a suggestion entry.
+///
+/// The rule's ID is the entry's content key without the `.action` suffix, e.g.
+/// `suggestion.absent.closed.colleague+work`.
+fn entry_rule(
+    trust: Trust,
+    mood: Mood,
+    context: SuggestionContext,
+    suggestion: &Suggestion,
+) -> Rule {
+    let content_key = if context.is_empty() {
+        ContentKey::SuggestionAction(trust, mood)
+    } else {
+        ContentKey::ContextSuggestionAction(trust, mood, context)
+    };
+    let content_key = content_key.to_string();
+    let id = content_key
+        .strip_suffix(".action")
+        .unwrap_or(&content_key)
+        .to_string();
+
+    let predicates = [
+        Some(Predicate::Trust(trust)),
+        Some(Predicate::Mood(Comparison::Eq, mood)),
+        context.age_group.map(Predicate::AgeGroup),
+        context.relationship.map(Predicate::Relationship),
+        context.setting.map(Predicate::Setting),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Rule::new(id, predicates, suggestion.clone()).with_priority(SuggestionCatalog::ENTRY_PRIORITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CatalogFile, Mood, Relationship, RuleExplanation, Setting, Situation, SuggestionCatalog,
+        SuggestionContext, Trust,
+    };
+
+    const RULE_ABSENT_OVERWHELMED: &str = r#"
+[[rules]]
+id = "absent-overwhelmed"
+when = ["trust == Absent", "mood <= Closed"]
+action = "Stay close, and say little"
+description = "Your presence is enough for now."
+principles = ["no_pressure"]
+"#;
+
+    const RULE_ABSENT_OVERWHELMED_AT_WORK: &str = r#"
+[[rules]]
+id = "absent-overwhelmed-at-work"
+when = ["trust == Absent", "mood <= Closed", "setting == Work"]
+action = "Find them a quiet room"
+description = "Let them step away from their desk."
+principles = ["give_space"]
+"#;
+
+    /// Returns the built in catalog with the given rules added to its file.
+    fn catalog_with_rules(rules_toml: &[&str]) -> SuggestionCatalog {
+        let catalog_toml = format!(
+            "{}{}",
+            include_str!("../catalog/suggestions.toml"),
+            rules_toml.concat()
+        );
+        let catalog_file = CatalogFile::parse(&catalog_toml).expect("catalog file is valid");
+        SuggestionCatalog::from_catalog_file(&catalog_file)
+    }
+
+    /// Returns the action selected for the trust level, mood, and context.
+    fn action(
+        suggestion_catalog: &SuggestionCatalog,
+        trust: Trust,
+        mood: Mood,
+        context: SuggestionContext,
+    ) -> &str {
+        suggestion_catalog
+            .get_in_context(trust, mood, context)
+            .map(|(_context, suggestion)| suggestion.action())
+            .expect("a rule matches")
+    }
+
+    /// Returns the ID of the rule that fired in the explanation.
+    fn fired_id(explanation: &RuleExplanation) -> Option<&str> {
+        explanation
+            .fired()
+            .map(|evaluation| evaluation.rule_id.as_ref())
+    }
+
+    #[test]
+    fn rule_wins_over_entry_as_specific() {
+        let suggestion_catalog = catalog_with_rules(&[RULE_ABSENT_OVERWHELMED]);
+
+        // Both the rule and the entry for `Absent` and `Closed` check two
+        // dimensions, and entries have a lower priority.
+        for mood in [Mood::_01_Anguished, Mood::_02_Closed] {
+            assert_eq!(
+                "Stay close, and say little",
+                action(
+                    &suggestion_catalog,
+                    Trust::Absent,
+                    mood,
+                    SuggestionContext::new()
+                )
+            );
+        }
+
+        let explanation = suggestion_catalog.explain(&Situation::new(
+            Some(Trust::Absent),
+            Some(Mood::_02_Closed),
+            SuggestionContext::new(),
+        ));
+        assert_eq!(Some("absent-overwhelmed"), fired_id(&explanation));
+        assert_eq!(
+            vec![(
+                "suggestion.absent.closed",
+                String::from("lower priority (-1 < 0)")
+            )],
+            explanation
+                .overruled()
+                .map(|(evaluation, reason)| (evaluation.rule_id.as_ref(), reason))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn more_specific_entry_wins_over_rule() {
+        let suggestion_catalog = catalog_with_rules(&[RULE_ABSENT_OVERWHELMED]);
+        let suggestion_catalog_builtin = SuggestionCatalog::builtin();
+        let colleague_at_work = SuggestionContext::new()
+            .with_relationship(Relationship::Colleague)
+            .with_setting(Setting::Work);
+
+        assert_eq!(
+            action(
+                &suggestion_catalog_builtin,
+                Trust::Absent,
+                Mood::_02_Closed,
+                colleague_at_work
+            ),
+            action(
+                &suggestion_catalog,
+                Trust::Absent,
+                Mood::_02_Closed,
+                colleague_at_work
+            )
+        );
+
+        let explanation = suggestion_catalog.explain(&Situation::new(
+            Some(Trust::Absent),
+            Some(Mood::_02_Closed),
+            colleague_at_work,
+        ));
+        assert_eq!(
+            Some("suggestion.absent.closed.colleague+work"),
+            fired_id(&explanation)
+        );
+        assert!(explanation
+            .overruled()
+            .any(
+                |(evaluation, reason)| evaluation.rule_id == "absent-overwhelmed"
+                    && reason == "less specific (2 < 4)"
+            ));
+    }
+
+    #[test]
+    fn entries_apply_where_no_rule_matches() {
+        let suggestion_catalog = catalog_with_rules(&[RULE_ABSENT_OVERWHELMED]);
+        let suggestion_catalog_builtin = SuggestionCatalog::builtin();
+
+        for (trust, mood) in [
+            (Trust::Absent, Mood::_03_Cautious),
+            (Trust::Present, Mood::_02_Closed),
+        ] {
+            assert_eq!(
+                action(
+                    &suggestion_catalog_builtin,
+                    trust,
+                    mood,
+                    SuggestionContext::new()
+                ),
+                action(&suggestion_catalog, trust, mood, SuggestionContext::new())
+            );
+        }
+    }
+
+    #[test]
+    fn more_specific_rule_wins_between_rules() {
+        // Listed after the more general rule, so only specificity decides.
+        let suggestion_catalog =
+            catalog_with_rules(&[RULE_ABSENT_OVERWHELMED, RULE_ABSENT_OVERWHELMED_AT_WORK]);
+
+        assert_eq!(
+            "Find them a quiet room",
+            action(
+                &suggestion_catalog,
+                Trust::Absent,
+                Mood::_02_Closed,
+                SuggestionContext::new().with_setting(Setting::Work)
+            )
+        );
+        assert_eq!(
+            "Stay close, and say little",
+            action(
+                &suggestion_catalog,
+                Trust::Absent,
+                Mood::_02_Closed,
+                SuggestionContext::new().with_setting(Setting::Home)
+            )
+        );
+    }
+
+    #[test]
+    fn explain_follows_lookup_when_trust_or_mood_is_unknown() {
+        let suggestion_catalog = SuggestionCatalog::builtin();
+
+        // Lookups without a trust level use the most cautious one.
+        let explanation = suggestion_catalog.explain(&Situation::new(
+            None,
+            Some(Mood::_02_Closed),
+            SuggestionContext::new(),
+        ));
+        assert_eq!(Some(Trust::Absent), explanation.trust_assumed);
+        assert_eq!(Some("suggestion.absent.closed"), fired_id(&explanation));
+        assert_eq!(
+            suggestion_catalog.get(Trust::Absent, Mood::_02_Closed),
+            suggestion_catalog
+                .lookup(None, Some(Mood::_02_Closed))
+                .map(|guidance| guidance.suggestion)
+                .as_ref()
+        );
+
+        // Lookups without a mood use the general guidance for the trust level.
+        let explanation = suggestion_catalog.explain(&Situation::new(
+            Some(Trust::Absent),
+            None,
+            SuggestionContext::new(),
+        ));
+        assert_eq!(None, fired_id(&explanation));
+    }
+}
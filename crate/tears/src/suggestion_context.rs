@@ -0,0 +1,187 @@
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display},
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{AgeGroup, Relationship, Setting};
+
+/// Optional details about the situation that narrow down a suggestion.
+///
+/// A suggestion in the catalog applies to a context. Dimensions that are
+/// `None` match any value, so the default context applies everywhere.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SuggestionContext {
+    /// Age group of the receiving person.
+    pub age_group: Option<AgeGroup>,
+    /// How you are related to the receiving person.
+    pub relationship: Option<Relationship>,
+    /// Where you are with the receiving person.
+    pub setting: Option<Setting>,
+}
+
+impl SuggestionContext {
+    /// Returns a context where every dimension is unknown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns this context with the given age group.
+    pub fn with_age_group(mut self, age_group: AgeGroup) -> Self {
+        self.age_group = Some(age_group);
+        self
+    }
+
+    /// Returns this context with the given relationship.
+    pub fn with_relationship(mut self, relationship: Relationship) -> Self {
+        self.relationship = Some(relationship);
+        self
+    }
+
+    /// Returns this context with the given setting.
+    pub fn with_setting(mut self, setting: Setting) -> Self {
+        self.setting = Some(setting);
+        self
+    }
+
+    /// Returns whether every dimension is unknown.
+    pub fn is_empty(&self) -> bool {
+        self.specificity() == 0
+    }
+
+    /// Returns the number of dimensions that are known.
+    pub fn specificity(&self) -> usize {
+        usize::from(self.age_group.is_some())
+            + usize::from(self.relationship.is_some())
+            + usize::from(self.setting.is_some())
+    }
+
+    /// Returns the identifier of this context within content keys and catalog
+    /// files, e.g. `"colleague+work"`, or an empty string for the empty
+    /// context.
+    pub fn id(&self) -> String {
+        self.age_group
+            .map(|age_group| age_group.id().to_string())
+            .into_iter()
+            .chain(
+                self.relationship
+                    .map(|relationship| relationship.name().to_lowercase()),
+            )
+            .chain(self.setting.map(|setting| setting.name().to_lowercase()))
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    /// Returns the non-empty context with the given identifier, where each
+    /// dimension appears at most once.
+    pub fn from_id(id: &str) -> Option<Self> {
+        id.split('+')
+            .try_fold(Self::default(), |mut context, part| {
+                let dimension_repeated = if let Some(age_group) = AgeGroup::from_id(part) {
+                    context.age_group.replace(age_group).is_some()
+                } else if let Some(relationship) = Relationship::iter()
+                    .find(|relationship| relationship.name().eq_ignore_ascii_case(part))
+                {
+                    context.relationship.replace(relationship).is_some()
+                } else {
+                    let setting = Setting::iter()
+                        .find(|setting| setting.name().eq_ignore_ascii_case(part))?;
+                    context.setting.replace(setting).is_some()
+                };
+
+                (!dimension_repeated).then_some(context)
+            })
+    }
+
+    /// Returns a key that sorts contexts from the most to the least specific.
+    ///
+    /// Equally specific contexts are in the same order as
+    /// [`SuggestionContext::fallbacks`], then by each dimension's value.
+    pub(crate) fn specificity_order(self) -> impl Ord {
+        (
+            Reverse(self.specificity()),
+            self.age_group.is_none(),
+            self.relationship.is_none(),
+            self.setting.is_none(),
+            self.age_group,
+            self.relationship,
+            self.setting,
+        )
+    }
+
+    /// Returns this context and every more general context, from the most to
+    /// the least specific.
+    ///
+    /// Each general context drops some of the known dimensions. When two
+    /// contexts are equally specific, the one that keeps the age group comes
+    /// first, then the one that keeps the relationship. The last context is
+    /// always the empty context.
+    pub fn fallbacks(self) -> impl Iterator<Item = SuggestionContext> {
+        let mut contexts = Vec::with_capacity(8);
+        [self.age_group, None]
+            .into_iter()
+            .flat_map(|age_group| {
+                [self.relationship, None]
+                    .into_iter()
+                    .map(move |relationship| (age_group, relationship))
+            })
+            .flat_map(|(age_group, relationship)| {
+                [self.setting, None]
+                    .into_iter()
+                    .map(move |setting| SuggestionContext {
+                        age_group,
+                        relationship,
+                        setting,
+                    })
+            })
+            .for_each(|context| {
+                if !contexts.contains(&context) {
+                    contexts.push(context);
+                }
+            });
+        // Stable, so equally specific contexts keep the dimension order above.
+        contexts.sort_by_key(|context| std::cmp::Reverse(context.specificity()));
+
+        contexts.into_iter()
+    }
+}
+
+impl Display for SuggestionContext {
+    /// Writes the known dimensions joined by `+`, e.g. `Colleague+Work`, or
+    /// nothing for the empty context.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self
+            .age_group
+            .map(AgeGroup::name)
+            .into_iter()
+            .chain(self.relationship.map(Relationship::name))
+            .chain(self.setting.map(Setting::name))
+            .collect::<Vec<_>>();
+
+        names.join("+").fmt(f)
+    }
+}
+
+impl Serialize for SuggestionContext {
+    /// Serializes the context as its [`id`](SuggestionContext::id).
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for SuggestionContext {
+    /// Deserializes a non-empty context from its
+    /// [`id`](SuggestionContext::id).
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        Self::from_id(&id)
+            .ok_or_else(|| de::Error::custom(format!("`{id}` is not a known context")))
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{Principle, TemplateError};
+
+/// Problems with the text of a [`Suggestion`].
+///
+/// [`Suggestion`]: crate::Suggestion
+#[derive(Clone, Debug, Hash, PartialEq, Eq, thiserror::Error)]
+pub enum SuggestionError {
+    /// The action is empty or only whitespace.
+    #[error("The action must not be empty.")]
+    ActionEmpty,
+    /// The action spans more than one line.
+    #[error("The action must be a single line.")]
+    ActionMultiLine,
+    /// The description is empty or only whitespace.
+    #[error("The description must not be empty.")]
+    DescriptionEmpty,
+    /// The glance text spans more than one line.
+    #[error("The glance text must be a single line.")]
+    GlanceMultiLine,
+    /// The suggestion is not tagged with any principle.
+    #[error("The suggestion must apply at least one principle.")]
+    PrinciplesEmpty,
+    /// The suggestion is tagged with the same principle more than once.
+    #[error("The principle `{principle}` is listed more than once.")]
+    PrincipleRepeated {
+        /// The principle that is listed more than once.
+        principle: Principle,
+    },
+    /// The wait is zero seconds long.
+    #[error("The wait must be at least one second, or left out.")]
+    WaitZero,
+    /// The action is not a valid template.
+    #[error("The action is not a valid template.")]
+    ActionTemplate {
+        /// The underlying error.
+        #[source]
+        error: TemplateError,
+    },
+    /// The description is not a valid template.
+    #[error("The description is not a valid template.")]
+    DescriptionTemplate {
+        /// The underlying error.
+        #[source]
+        error: TemplateError,
+    },
+    /// The glance text is not a valid template.
+    #[error("The glance text is not a valid template.")]
+    GlanceTemplate {
+        /// The underlying error.
+        #[source]
+        error: TemplateError,
+    },
+    /// The extended text is not a valid template.
+    #[error("The extended text is not a valid template.")]
+    ExtendedTemplate {
+        /// The underlying error.
+        #[source]
+        error: TemplateError,
+    },
+}
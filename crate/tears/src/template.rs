@@ -0,0 +1,401 @@
+use std::str::FromStr;
+
+use crate::{
+    PersonProfile, Predicate, PredicateOutcome, ProfilePlaceholder, Situation, TemplateError,
+    TemplateNode,
+};
+
+/// Suggestion text with parts that depend on the situation and the person.
+///
+/// The syntax is:
+///
+/// * `{their name}`: a [`ProfilePlaceholder`] filled in from the person's
+///   profile, or with fallback text when it is not known.
+/// * `{if trust=Present}...{else}...{end}`: text that is only written when a
+///   [`Predicate`] on the situation is satisfied. `{else}` is optional.
+/// * `{plural their triggers|this trigger|these triggers}`: the first text when
+///   the profile has exactly one value for the variable, otherwise the second.
+/// * `{{` and `}}`: a literal `{` and `}`.
+///
+/// Templates only ever write text, so they are safe to render from content
+/// files.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Template {
+    /// Parts of the template, in order.
+    nodes: Vec<TemplateNode>,
+}
+
+/// An `{if ...}` that has not been closed yet.
+struct ConditionalOpen {
+    condition: String,
+    predicate: Predicate,
+    nodes_then: Vec<TemplateNode>,
+    nodes_else: Option<Vec<TemplateNode>>,
+}
+
+impl Template {
+    /// Parses a template, failing on unknown variables and unbalanced tags.
+    pub fn parse(text: &str) -> Result<Self, TemplateError> {
+        let mut nodes = Vec::new();
+        let mut conditionals_open = Vec::<ConditionalOpen>::new();
+        let mut text_pending = String::new();
+
+        let mut chars = text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let c_next = chars.peek().map(|(_, c_next)| *c_next);
+            match (c, c_next) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    text_pending.push(c);
+                    continue;
+                }
+                ('}', _) => return Err(TemplateError::BraceUnopened { offset }),
+                ('{', _) => {}
+                _ => {
+                    text_pending.push(c);
+                    continue;
+                }
+            }
+
+            let close = text[offset..]
+                .find('}')
+                .map(|close| offset + close)
+                .ok_or(TemplateError::BraceUnclosed { offset })?;
+            while chars.next_if(|(index, _)| *index <= close).is_some() {}
+
+            if !text_pending.is_empty() {
+                nodes_current(&mut nodes, &mut conditionals_open)
+                    .push(TemplateNode::Text(std::mem::take(&mut text_pending)));
+            }
+
+            let tag = text[offset + 1..close].trim();
+            if tag == "end" {
+                let conditional_open = conditionals_open
+                    .pop()
+                    .ok_or(TemplateError::IfMissing { tag: "end", offset })?;
+                nodes_current(&mut nodes, &mut conditionals_open).push(TemplateNode::Conditional {
+                    predicate: conditional_open.predicate,
+                    nodes_then: conditional_open.nodes_then,
+                    nodes_else: conditional_open.nodes_else.unwrap_or_default(),
+                });
+            } else if tag == "else" {
+                let conditional_open =
+                    conditionals_open
+                        .last_mut()
+                        .ok_or(TemplateError::IfMissing {
+                            tag: "else",
+                            offset,
+                        })?;
+                if conditional_open.nodes_else.is_some() {
+                    return Err(TemplateError::ElseRepeated {
+                        condition: conditional_open.condition.clone(),
+                    });
+                }
+                conditional_open.nodes_else = Some(Vec::new());
+            } else if let Some(condition) = tag.strip_prefix("if ") {
+                let condition = condition.trim().to_string();
+                let predicate = Predicate::from_str(&condition).map_err(|()| {
+                    TemplateError::ConditionInvalid {
+                        condition: condition.clone(),
+                    }
+                })?;
+                conditionals_open.push(ConditionalOpen {
+                    condition,
+                    predicate,
+                    nodes_then: Vec::new(),
+                    nodes_else: None,
+                });
+            } else if let Some(content) = tag.strip_prefix("plural ") {
+                let plural_invalid = || TemplateError::PluralInvalid {
+                    content: content.trim().to_string(),
+                };
+                let mut parts = content.split('|');
+                let (Some(variable), Some(one), Some(other), None) =
+                    (parts.next(), parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(plural_invalid());
+                };
+                let profile_placeholder =
+                    ProfilePlaceholder::from_str(variable.trim()).map_err(|()| plural_invalid())?;
+                nodes_current(&mut nodes, &mut conditionals_open).push(TemplateNode::Plural {
+                    profile_placeholder,
+                    one: one.to_string(),
+                    other: other.to_string(),
+                });
+            } else {
+                let profile_placeholder = ProfilePlaceholder::from_str(tag).map_err(|()| {
+                    TemplateError::VariableUnknown {
+                        name: tag.to_string(),
+                        variables_known: ProfilePlaceholder::iter()
+                            .map(|profile_placeholder| format!("`{profile_placeholder}`"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    }
+                })?;
+                nodes_current(&mut nodes, &mut conditionals_open)
+                    .push(TemplateNode::Variable(profile_placeholder));
+            }
+        }
+
+        if let Some(conditional_open) = conditionals_open.pop() {
+            return Err(TemplateError::IfUnclosed {
+                condition: conditional_open.condition,
+            });
+        }
+        if !text_pending.is_empty() {
+            nodes.push(TemplateNode::Text(text_pending));
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// Returns the parts of this template.
+    pub fn nodes(&self) -> &[TemplateNode] {
+        &self.nodes
+    }
+
+    /// Returns the text for the situation, with the profile filled in.
+    ///
+    /// Variables that the profile has no value for are filled in with the
+    /// text returned by `fallback`.
+    pub fn render<'f>(
+        &self,
+        situation: &Situation,
+        person_profile: &PersonProfile,
+        fallback: impl Fn(ProfilePlaceholder) -> &'f str,
+    ) -> String {
+        let mut rendered = String::new();
+        render_nodes(
+            &self.nodes,
+            situation,
+            person_profile,
+            &fallback,
+            &mut rendered,
+        );
+        rendered
+    }
+}
+
+/// Returns the nodes that parsed nodes are added to: those of the innermost
+/// open `{if ...}`, or the top level.
+fn nodes_current<'nodes>(
+    nodes: &'nodes mut Vec<TemplateNode>,
+    conditionals_open: &'nodes mut [ConditionalOpen],
+) -> &'nodes mut Vec<TemplateNode> {
+    match conditionals_open.last_mut() {
+        Some(ConditionalOpen {
+            nodes_else: Some(nodes_else),
+            ..
+        }) => nodes_else,
+        Some(ConditionalOpen { nodes_then, .. }) => nodes_then,
+        None => nodes,
+    }
+}
+
+fn render_nodes<'f>(
+    nodes: &[TemplateNode],
+    situation: &Situation,
+    person_profile: &PersonProfile,
+    fallback: &impl Fn(ProfilePlaceholder) -> &'f str,
+    rendered: &mut String,
+) {
+    nodes.iter().for_each(|node| match node {
+        TemplateNode::Text(text) => rendered.push_str(text),
+        TemplateNode::Variable(profile_placeholder) => {
+            match person_profile.value(*profile_placeholder) {
+                Some(value) => rendered.push_str(&value),
+                None => rendered.push_str(fallback(*profile_placeholder)),
+            }
+        }
+        TemplateNode::Conditional {
+            predicate,
+            nodes_then,
+            nodes_else,
+        } => {
+            let nodes_chosen = match predicate.evaluate(situation) {
+                PredicateOutcome::Satisfied => nodes_then,
+                PredicateOutcome::Unsatisfied | PredicateOutcome::Unknown => nodes_else,
+            };
+            render_nodes(nodes_chosen, situation, person_profile, fallback, rendered);
+        }
+        TemplateNode::Plural {
+            profile_placeholder,
+            one,
+            other,
+        } => {
+            if person_profile.count(*profile_placeholder) == 1 {
+                rendered.push_str(one);
+            } else {
+                rendered.push_str(other);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Mood, PersonProfile, Situation, SuggestionContext, Template, TemplateError, Trust,
+    };
+
+    fn render(text: &str, situation: &Situation, person_profile: &PersonProfile) -> String {
+        Template::parse(text)
+            .expect("Expected template to parse.")
+            .render(situation, person_profile, |_| "them")
+    }
+
+    fn situation(trust: Option<Trust>, mood: Option<Mood>) -> Situation {
+        Situation::new(trust, mood, SuggestionContext::default())
+    }
+
+    #[test]
+    fn variable_is_filled_from_profile_or_fallback() {
+        let mut person_profile = PersonProfile::new();
+        let text = "Sit with {their name}.";
+
+        assert_eq!(
+            "Sit with them.",
+            render(text, &Situation::default(), &person_profile)
+        );
+
+        person_profile.name = String::from("Sam");
+        assert_eq!(
+            "Sit with Sam.",
+            render(text, &Situation::default(), &person_profile)
+        );
+    }
+
+    #[test]
+    fn if_else_is_chosen_by_situation() {
+        let person_profile = PersonProfile::new();
+        let text = "{if trust=Present}Stay.{else}Go.{end}";
+
+        let present = situation(Some(Trust::Present), None);
+        let absent = situation(Some(Trust::Absent), None);
+        let unknown = situation(None, None);
+        assert_eq!("Stay.", render(text, &present, &person_profile));
+        assert_eq!("Go.", render(text, &absent, &person_profile));
+        assert_eq!("Go.", render(text, &unknown, &person_profile));
+    }
+
+    #[test]
+    fn if_without_else_writes_nothing_when_unsatisfied() {
+        let person_profile = PersonProfile::new();
+        let text = "Wait.{if trust=Present} Then stay.{end}";
+
+        let absent = situation(Some(Trust::Absent), None);
+        assert_eq!("Wait.", render(text, &absent, &person_profile));
+    }
+
+    #[test]
+    fn nested_if_else_is_chosen_by_situation() {
+        let person_profile = PersonProfile::new();
+        let text = "{if trust=Present}A{if mood<=Cautious}B{else}C{end}D{else}E{end}";
+
+        let present_closed = situation(Some(Trust::Present), Some(Mood::_02_Closed));
+        let present_calm = situation(Some(Trust::Present), Some(Mood::_05_Calm));
+        let absent_closed = situation(Some(Trust::Absent), Some(Mood::_02_Closed));
+        assert_eq!("ABD", render(text, &present_closed, &person_profile));
+        assert_eq!("ACD", render(text, &present_calm, &person_profile));
+        assert_eq!("E", render(text, &absent_closed, &person_profile));
+    }
+
+    #[test]
+    fn double_braces_are_literal_braces() {
+        let person_profile = PersonProfile::new();
+
+        assert_eq!(
+            "{their name} and }",
+            render(
+                "{{their name}} and }}",
+                &Situation::default(),
+                &person_profile
+            )
+        );
+    }
+
+    #[test]
+    fn plural_is_chosen_by_count() {
+        let mut person_profile = PersonProfile::new();
+        let text = "Avoid {plural their triggers|this trigger|these triggers}.";
+
+        assert_eq!(
+            "Avoid these triggers.",
+            render(text, &Situation::default(), &person_profile)
+        );
+
+        person_profile.triggers = vec![String::from("crowds")];
+        assert_eq!(
+            "Avoid this trigger.",
+            render(text, &Situation::default(), &person_profile)
+        );
+
+        person_profile.triggers.push(String::from("noise"));
+        assert_eq!(
+            "Avoid these triggers.",
+            render(text, &Situation::default(), &person_profile)
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_unbalanced_braces() {
+        assert_eq!(
+            Err(TemplateError::BraceUnclosed { offset: 4 }),
+            Template::parse("Sit {their name")
+        );
+        assert_eq!(
+            Err(TemplateError::BraceUnopened { offset: 3 }),
+            Template::parse("Sit} down")
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_unbalanced_tags() {
+        assert_eq!(
+            Err(TemplateError::IfUnclosed {
+                condition: String::from("trust=Present"),
+            }),
+            Template::parse("{if trust=Present}Stay.")
+        );
+        assert_eq!(
+            Err(TemplateError::IfMissing {
+                tag: "else",
+                offset: 5,
+            }),
+            Template::parse("Stay.{else}Go.{end}")
+        );
+        assert_eq!(
+            Err(TemplateError::IfMissing {
+                tag: "end",
+                offset: 5,
+            }),
+            Template::parse("Stay.{end}")
+        );
+        assert_eq!(
+            Err(TemplateError::ElseRepeated {
+                condition: String::from("trust=Present"),
+            }),
+            Template::parse("{if trust=Present}A{else}B{else}C{end}")
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_unknown_content() {
+        assert!(matches!(
+            Template::parse("{their age}"),
+            Err(TemplateError::VariableUnknown { name, .. }) if name == "their age"
+        ));
+        assert_eq!(
+            Err(TemplateError::ConditionInvalid {
+                condition: String::from("trust=Maybe"),
+            }),
+            Template::parse("{if trust=Maybe}A{end}")
+        );
+        assert_eq!(
+            Err(TemplateError::PluralInvalid {
+                content: String::from("their triggers|this trigger"),
+            }),
+            Template::parse("{plural their triggers|this trigger}")
+        );
+    }
+}
@@ -0,0 +1,66 @@
+/// Problems with the template syntax in suggestion text.
+///
+/// See [`Template`] for the syntax.
+///
+/// [`Template`]: crate::Template
+#[derive(Clone, Debug, Hash, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    /// A `{` is not closed by a `}`.
+    #[error("`{{` at byte {offset} is not closed. Write `{{{{` for a literal brace.")]
+    BraceUnclosed {
+        /// Byte offset of the `{`.
+        offset: usize,
+    },
+    /// A `}` has no `{` before it.
+    #[error("`}}` at byte {offset} has no opening brace. Write `}}}}` for a literal brace.")]
+    BraceUnopened {
+        /// Byte offset of the `}`.
+        offset: usize,
+    },
+    /// A `{name}` does not refer to a known variable.
+    #[error("`{{{name}}}` is not a known variable. Known variables are: {variables_known}.")]
+    VariableUnknown {
+        /// Text between the braces.
+        name: String,
+        /// The known variables, in braces and separated by commas.
+        variables_known: String,
+    },
+    /// The condition of an `{if ...}` is not a valid predicate.
+    #[error(
+        "`{{if {condition}}}` has an invalid condition. \
+        Conditions look like `trust=Present` or `mood<=Cautious`."
+    )]
+    ConditionInvalid {
+        /// Text after `if`.
+        condition: String,
+    },
+    /// An `{if ...}` has no `{end}`.
+    #[error("`{{if {condition}}}` is not closed by `{{end}}`.")]
+    IfUnclosed {
+        /// Text after `if`.
+        condition: String,
+    },
+    /// An `{else}` or `{end}` has no `{if ...}` before it.
+    #[error("`{{{tag}}}` at byte {offset} has no `{{if ...}}` before it.")]
+    IfMissing {
+        /// `else` or `end`.
+        tag: &'static str,
+        /// Byte offset of the tag.
+        offset: usize,
+    },
+    /// An `{if ...}` has more than one `{else}`.
+    #[error("`{{if {condition}}}` has more than one `{{else}}`.")]
+    ElseRepeated {
+        /// Text after `if`.
+        condition: String,
+    },
+    /// A `{plural ...}` is not written as `{plural <variable>|<one>|<other>}`.
+    #[error(
+        "`{{plural {content}}}` is invalid. \
+        Write it as `{{plural their triggers|this trigger|these triggers}}`."
+    )]
+    PluralInvalid {
+        /// Text after `plural`.
+        content: String,
+    },
+}
@@ -0,0 +1,35 @@
+use crate::{Predicate, ProfilePlaceholder};
+
+/// Part of a [`Template`].
+///
+/// [`Template`]: crate::Template
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum TemplateNode {
+    /// Text that is written as is.
+    Text(String),
+    /// `{their name}`: filled in from the person's profile.
+    Variable(ProfilePlaceholder),
+    /// `{if <predicate>}...{else}...{end}`: written depending on the
+    /// situation.
+    ///
+    /// When the situation does not say whether the predicate is satisfied,
+    /// the `else` nodes are written.
+    Conditional {
+        /// Condition on the situation.
+        predicate: Predicate,
+        /// Nodes written when the predicate is satisfied.
+        nodes_then: Vec<TemplateNode>,
+        /// Nodes written otherwise.
+        nodes_else: Vec<TemplateNode>,
+    },
+    /// `{plural <variable>|<one>|<other>}`: `one` when the profile has exactly
+    /// one value for the variable, otherwise `other`.
+    Plural {
+        /// Variable whose values are counted.
+        profile_placeholder: ProfilePlaceholder,
+        /// Text for exactly one value.
+        one: String,
+        /// Text for any other number of values.
+        other: String,
+    },
+}
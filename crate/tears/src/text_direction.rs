@@ -0,0 +1,20 @@
+/// Direction that text in a [`Locale`] is written in.
+///
+/// [`Locale`]: crate::Locale
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Left to right, e.g. English.
+    Ltr,
+    /// Right to left, e.g. Arabic and Hebrew.
+    Rtl,
+}
+
+impl TextDirection {
+    /// Returns the value of the `dir` attribute in HTML for this direction.
+    pub fn attr_value(self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
@@ -3,11 +3,13 @@ use std::{
     str::FromStr,
 };
 
+use serde::{Deserialize, Serialize};
+
 /// Whether the receiving person trusts you.
 ///
 /// A good indicator whether the receiving person trusts you is if they initiate
 /// a conversation with you.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Trust {
     /// The receiving person does not trust you.
     Absent,
@@ -21,6 +23,14 @@ impl Trust {
         [Trust::Absent, Trust::Present].into_iter()
     }
 
+    /// Returns the name of this trust level, e.g. `"Absent"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Trust::Absent => "Absent",
+            Trust::Present => "Present",
+        }
+    }
+
     pub fn description(self) -> &'static str {
         match self {
             Trust::Absent => "The person has not initiated a conversation with you recently.",
@@ -29,14 +39,42 @@ impl Trust {
             }
         }
     }
+
+    /// Returns a one line description, shown at [`ReadingLevel::Glance`].
+    ///
+    /// [`ReadingLevel::Glance`]: crate::ReadingLevel::Glance
+    pub fn glance(self) -> &'static str {
+        match self {
+            Trust::Absent => "They have not reached out to you lately.",
+            Trust::Present => "They have reached out to you lately, by choice.",
+        }
+    }
+
+    /// Returns a longer explanation, shown after the description at
+    /// [`ReadingLevel::Extended`].
+    ///
+    /// [`ReadingLevel::Extended`]: crate::ReadingLevel::Extended
+    pub fn extended(self) -> &'static str {
+        match self {
+            Trust::Absent => {
+                "Trust shows in what the person does, not what they say. If they \
+                have not started a conversation with you recently, your presence \
+                may feel like pressure, even if you are close to them. Trust can \
+                grow again from small, reliable actions that ask nothing in return."
+            }
+            Trust::Present => {
+                "When the person starts a conversation with you without needing \
+                to, it shows that they feel safe with you. This trust is lost \
+                quickly if it is used to push advice or expectations on them, so \
+                treat it with care."
+            }
+        }
+    }
 }
 
 impl Display for Trust {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Trust::Absent => "Absent".fmt(f),
-            Trust::Present => "Present".fmt(f),
-        }
+        self.name().fmt(f)
     }
 }
 
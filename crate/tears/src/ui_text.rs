@@ -0,0 +1,476 @@
+use std::str::FromStr;
+
+/// Text in the user interface that is not part of the content.
+///
+/// Text may contain `{placeholders}`, which are replaced by the caller.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum UiText {
+    SkipToContent,
+    Github,
+    ThemeLabel,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    ThemeHighContrast,
+    ThemeLowStimulus,
+    LocaleLabel,
+    ReadingLevelLabel,
+    ReadingLevelGlance,
+    ReadingLevelStandard,
+    ReadingLevelExtended,
+    Disclaimer,
+    DisclaimerPersonal,
+    DisclaimerShort,
+    DisclaimerEager,
+    Close,
+    Clear,
+    PathNotFound,
+    TrustField,
+    TrustFieldDescription,
+    TrustHint,
+    MoodField,
+    MoodFieldDescription,
+    MoodHint,
+    MoodRangeMode,
+    ContextField,
+    ContextFieldDescription,
+    AgeGroupLabel,
+    RelationshipLabel,
+    SettingLabel,
+    /// Option for a context dimension that is not known.
+    ContextAny,
+    Indicators,
+    Symptoms,
+    Description,
+    Action,
+    /// Label for the suggestion at the upper bound of a mood range.
+    ///
+    /// Placeholders: `{mood}`.
+    UpperBound,
+    /// Screen reader announcement when the suggestion changes.
+    ///
+    /// Placeholders: `{action}`.
+    SuggestedAction,
+    SuggestionPlaceholder,
+    /// Note that the suggestion is specific to the selected context.
+    ///
+    /// Placeholders: `{context}`.
+    ContextMatched,
+    FeedbackQuestion,
+    FeedbackHelpful,
+    FeedbackNotHelpful,
+    FeedbackMadeItWorse,
+    FeedbackComment,
+    FeedbackPrivacy,
+    FeedbackSend,
+    FeedbackThanks,
+    FeedbackFailed,
+    /// Fallbacks for [`ProfilePlaceholder`]s that the profile has no value
+    /// for.
+    ///
+    /// [`ProfilePlaceholder`]: crate::ProfilePlaceholder
+    ProfileNameFallback,
+    ProfileComfortFallback,
+    ProfileTrustedPersonFallback,
+    ProfileTriggersFallback,
+    ProfileBackfiredFallback,
+    ProfileField,
+    ProfileFieldDescription,
+    ProfileNameLabel,
+    ProfileComfortsLabel,
+    ProfileTriggersLabel,
+    ProfileTrustedPeopleLabel,
+    ProfileBackfiredLabel,
+    /// Hint for profile fields that take one value per line.
+    ProfileListHint,
+    ProfilePrivacy,
+    /// Note of the receiving person's triggers.
+    ///
+    /// Placeholders: `{triggers}`.
+    ProfileAvoid,
+    /// Note of what backfired before.
+    ///
+    /// Placeholders: `{backfired}`.
+    ProfileBackfiredNote,
+    Principles,
+    PrinciplesDescription,
+    PrincipleExamples,
+    PrincipleAppliesIn,
+    /// Label for the principles that a suggestion applies.
+    SuggestionPrinciples,
+    AntiPatterns,
+    AntiPatternsDescription,
+    AntiPatternExample,
+    AntiPatternMoods,
+    AntiPatternReason,
+    AntiPatternInstead,
+    /// Label for the anti-patterns that backfire at the selected mood.
+    SuggestionAntiPatterns,
+    ActionCheckLabel,
+    ActionCheckPlaceholder,
+    ActionCheckAlternatives,
+    ActionCheckTrustUnknown,
+    VerdictRecommended,
+    VerdictRisky,
+    VerdictAvoid,
+    PhraseCheckLabel,
+    PhraseCheckDescription,
+    PhraseCheckPlaceholder,
+    PhraseCheckClear,
+    PhraseCheckTry,
+    WhatToSay,
+    SayingOpener,
+    SayingFollowUp,
+    SayingExit,
+    SayingDontSay,
+    WaitTimerStart,
+    WaitTimerWaiting,
+    WaitTimerStop,
+    WaitTimerDone,
+}
+
+impl UiText {
+    /// Returns an iterator over all variants of this `UiText` enum.
+    pub fn iter() -> impl Iterator<Item = UiText> {
+        [
+            UiText::SkipToContent,
+            UiText::Github,
+            UiText::ThemeLabel,
+            UiText::ThemeSystem,
+            UiText::ThemeLight,
+            UiText::ThemeDark,
+            UiText::ThemeHighContrast,
+            UiText::ThemeLowStimulus,
+            UiText::LocaleLabel,
+            UiText::ReadingLevelLabel,
+            UiText::ReadingLevelGlance,
+            UiText::ReadingLevelStandard,
+            UiText::ReadingLevelExtended,
+            UiText::Disclaimer,
+            UiText::DisclaimerPersonal,
+            UiText::DisclaimerShort,
+            UiText::DisclaimerEager,
+            UiText::Close,
+            UiText::Clear,
+            UiText::PathNotFound,
+            UiText::TrustField,
+            UiText::TrustFieldDescription,
+            UiText::TrustHint,
+            UiText::MoodField,
+            UiText::MoodFieldDescription,
+            UiText::MoodHint,
+            UiText::MoodRangeMode,
+            UiText::ContextField,
+            UiText::ContextFieldDescription,
+            UiText::AgeGroupLabel,
+            UiText::RelationshipLabel,
+            UiText::SettingLabel,
+            UiText::ContextAny,
+            UiText::Indicators,
+            UiText::Symptoms,
+            UiText::Description,
+            UiText::Action,
+            UiText::UpperBound,
+            UiText::SuggestedAction,
+            UiText::SuggestionPlaceholder,
+            UiText::ContextMatched,
+            UiText::FeedbackQuestion,
+            UiText::FeedbackHelpful,
+            UiText::FeedbackNotHelpful,
+            UiText::FeedbackMadeItWorse,
+            UiText::FeedbackComment,
+            UiText::FeedbackPrivacy,
+            UiText::FeedbackSend,
+            UiText::FeedbackThanks,
+            UiText::FeedbackFailed,
+            UiText::ProfileNameFallback,
+            UiText::ProfileComfortFallback,
+            UiText::ProfileTrustedPersonFallback,
+            UiText::ProfileTriggersFallback,
+            UiText::ProfileBackfiredFallback,
+            UiText::ProfileField,
+            UiText::ProfileFieldDescription,
+            UiText::ProfileNameLabel,
+            UiText::ProfileComfortsLabel,
+            UiText::ProfileTriggersLabel,
+            UiText::ProfileTrustedPeopleLabel,
+            UiText::ProfileBackfiredLabel,
+            UiText::ProfileListHint,
+            UiText::ProfilePrivacy,
+            UiText::ProfileAvoid,
+            UiText::ProfileBackfiredNote,
+            UiText::Principles,
+            UiText::PrinciplesDescription,
+            UiText::PrincipleExamples,
+            UiText::PrincipleAppliesIn,
+            UiText::SuggestionPrinciples,
+            UiText::AntiPatterns,
+            UiText::AntiPatternsDescription,
+            UiText::AntiPatternExample,
+            UiText::AntiPatternMoods,
+            UiText::AntiPatternReason,
+            UiText::AntiPatternInstead,
+            UiText::SuggestionAntiPatterns,
+            UiText::ActionCheckLabel,
+            UiText::ActionCheckPlaceholder,
+            UiText::ActionCheckAlternatives,
+            UiText::ActionCheckTrustUnknown,
+            UiText::VerdictRecommended,
+            UiText::VerdictRisky,
+            UiText::VerdictAvoid,
+            UiText::PhraseCheckLabel,
+            UiText::PhraseCheckDescription,
+            UiText::PhraseCheckPlaceholder,
+            UiText::PhraseCheckClear,
+            UiText::PhraseCheckTry,
+            UiText::WhatToSay,
+            UiText::SayingOpener,
+            UiText::SayingFollowUp,
+            UiText::SayingExit,
+            UiText::SayingDontSay,
+            UiText::WaitTimerStart,
+            UiText::WaitTimerWaiting,
+            UiText::WaitTimerStop,
+            UiText::WaitTimerDone,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the identifier of this text within content bundles.
+    pub fn id(self) -> &'static str {
+        match self {
+            UiText::SkipToContent => "skip_to_content",
+            UiText::Github => "github",
+            UiText::ThemeLabel => "theme_label",
+            UiText::ThemeSystem => "theme_system",
+            UiText::ThemeLight => "theme_light",
+            UiText::ThemeDark => "theme_dark",
+            UiText::ThemeHighContrast => "theme_high_contrast",
+            UiText::ThemeLowStimulus => "theme_low_stimulus",
+            UiText::LocaleLabel => "locale_label",
+            UiText::ReadingLevelLabel => "reading_level_label",
+            UiText::ReadingLevelGlance => "reading_level_glance",
+            UiText::ReadingLevelStandard => "reading_level_standard",
+            UiText::ReadingLevelExtended => "reading_level_extended",
+            UiText::Disclaimer => "disclaimer",
+            UiText::DisclaimerPersonal => "disclaimer_personal",
+            UiText::DisclaimerShort => "disclaimer_short",
+            UiText::DisclaimerEager => "disclaimer_eager",
+            UiText::Close => "close",
+            UiText::Clear => "clear",
+            UiText::PathNotFound => "path_not_found",
+            UiText::TrustField => "trust_field",
+            UiText::TrustFieldDescription => "trust_field_description",
+            UiText::TrustHint => "trust_hint",
+            UiText::MoodField => "mood_field",
+            UiText::MoodFieldDescription => "mood_field_description",
+            UiText::MoodHint => "mood_hint",
+            UiText::MoodRangeMode => "mood_range_mode",
+            UiText::ContextField => "context_field",
+            UiText::ContextFieldDescription => "context_field_description",
+            UiText::AgeGroupLabel => "age_group_label",
+            UiText::RelationshipLabel => "relationship_label",
+            UiText::SettingLabel => "setting_label",
+            UiText::ContextAny => "context_any",
+            UiText::Indicators => "indicators",
+            UiText::Symptoms => "symptoms",
+            UiText::Description => "description",
+            UiText::Action => "action",
+            UiText::UpperBound => "upper_bound",
+            UiText::SuggestedAction => "suggested_action",
+            UiText::SuggestionPlaceholder => "suggestion_placeholder",
+            UiText::ContextMatched => "context_matched",
+            UiText::FeedbackQuestion => "feedback_question",
+            UiText::FeedbackHelpful => "feedback_helpful",
+            UiText::FeedbackNotHelpful => "feedback_not_helpful",
+            UiText::FeedbackMadeItWorse => "feedback_made_it_worse",
+            UiText::FeedbackComment => "feedback_comment",
+            UiText::FeedbackPrivacy => "feedback_privacy",
+            UiText::FeedbackSend => "feedback_send",
+            UiText::FeedbackThanks => "feedback_thanks",
+            UiText::FeedbackFailed => "feedback_failed",
+            UiText::ProfileNameFallback => "profile_name_fallback",
+            UiText::ProfileComfortFallback => "profile_comfort_fallback",
+            UiText::ProfileTrustedPersonFallback => "profile_trusted_person_fallback",
+            UiText::ProfileTriggersFallback => "profile_triggers_fallback",
+            UiText::ProfileBackfiredFallback => "profile_backfired_fallback",
+            UiText::ProfileField => "profile_field",
+            UiText::ProfileFieldDescription => "profile_field_description",
+            UiText::ProfileNameLabel => "profile_name_label",
+            UiText::ProfileComfortsLabel => "profile_comforts_label",
+            UiText::ProfileTriggersLabel => "profile_triggers_label",
+            UiText::ProfileTrustedPeopleLabel => "profile_trusted_people_label",
+            UiText::ProfileBackfiredLabel => "profile_backfired_label",
+            UiText::ProfileListHint => "profile_list_hint",
+            UiText::ProfilePrivacy => "profile_privacy",
+            UiText::ProfileAvoid => "profile_avoid",
+            UiText::ProfileBackfiredNote => "profile_backfired_note",
+            UiText::Principles => "principles",
+            UiText::PrinciplesDescription => "principles_description",
+            UiText::PrincipleExamples => "principle_examples",
+            UiText::PrincipleAppliesIn => "principle_applies_in",
+            UiText::SuggestionPrinciples => "suggestion_principles",
+            UiText::AntiPatterns => "anti_patterns",
+            UiText::AntiPatternsDescription => "anti_patterns_description",
+            UiText::AntiPatternExample => "anti_pattern_example",
+            UiText::AntiPatternMoods => "anti_pattern_moods",
+            UiText::AntiPatternReason => "anti_pattern_reason",
+            UiText::AntiPatternInstead => "anti_pattern_instead",
+            UiText::SuggestionAntiPatterns => "suggestion_anti_patterns",
+            UiText::ActionCheckLabel => "action_check_label",
+            UiText::ActionCheckPlaceholder => "action_check_placeholder",
+            UiText::ActionCheckAlternatives => "action_check_alternatives",
+            UiText::ActionCheckTrustUnknown => "action_check_trust_unknown",
+            UiText::VerdictRecommended => "verdict_recommended",
+            UiText::VerdictRisky => "verdict_risky",
+            UiText::VerdictAvoid => "verdict_avoid",
+            UiText::PhraseCheckLabel => "phrase_check_label",
+            UiText::PhraseCheckDescription => "phrase_check_description",
+            UiText::PhraseCheckPlaceholder => "phrase_check_placeholder",
+            UiText::PhraseCheckClear => "phrase_check_clear",
+            UiText::PhraseCheckTry => "phrase_check_try",
+            UiText::WhatToSay => "what_to_say",
+            UiText::SayingOpener => "saying_opener",
+            UiText::SayingFollowUp => "saying_follow_up",
+            UiText::SayingExit => "saying_exit",
+            UiText::SayingDontSay => "saying_dont_say",
+            UiText::WaitTimerStart => "wait_timer_start",
+            UiText::WaitTimerWaiting => "wait_timer_waiting",
+            UiText::WaitTimerStop => "wait_timer_stop",
+            UiText::WaitTimerDone => "wait_timer_done",
+        }
+    }
+
+    /// Returns the English text.
+    pub fn text(self) -> &'static str {
+        match self {
+            UiText::SkipToContent => "Skip to content",
+            UiText::Github => "🐙 github",
+            UiText::ThemeLabel => "🎨 theme",
+            UiText::ThemeSystem => "System",
+            UiText::ThemeLight => "Light",
+            UiText::ThemeDark => "Dark",
+            UiText::ThemeHighContrast => "High contrast",
+            UiText::ThemeLowStimulus => "Low stimulus",
+            UiText::LocaleLabel => "🌐 language",
+            UiText::ReadingLevelLabel => "📖 detail",
+            UiText::ReadingLevelGlance => "At a glance",
+            UiText::ReadingLevelStandard => "Standard",
+            UiText::ReadingLevelExtended => "In depth",
+            UiText::Disclaimer => "ℹ️ disclaimer",
+            UiText::DisclaimerPersonal => {
+                "These are words from personal experience, not advice from a \
+                qualified professional."
+            }
+            UiText::DisclaimerShort => {
+                "The descriptions are intentionally short so they are easy to \
+                read, and each example is only one possibility out of \
+                innumerable unique situations."
+            }
+            UiText::DisclaimerEager => {
+                "These are written for people who are eager to help, to show \
+                that practical steps may not necessarily be beneficial -- the \
+                person may not have the capacity to receive it, or if they \
+                are, it may not be from you."
+            }
+            UiText::Close => "close",
+            UiText::Clear => "clear",
+            UiText::PathNotFound => "Path not found: ",
+            UiText::TrustField => "Trust",
+            UiText::TrustFieldDescription => "- whether the person trusts you",
+            UiText::TrustHint => "select a value, or press ",
+            UiText::MoodField => "Mood",
+            UiText::MoodFieldDescription => "- how the person feels",
+            UiText::MoodHint => "select a value, or press ",
+            UiText::MoodRangeMode => "not sure? select two moods as a range",
+            UiText::ContextField => "Context",
+            UiText::ContextFieldDescription => "- optional, for a more specific suggestion",
+            UiText::AgeGroupLabel => "Age",
+            UiText::RelationshipLabel => "Relationship",
+            UiText::SettingLabel => "Setting",
+            UiText::ContextAny => "not specified",
+            UiText::Indicators => "Indicators:",
+            UiText::Symptoms => "Symptoms:",
+            UiText::Description => "Description:",
+            UiText::Action => "Action:",
+            UiText::UpperBound => "If they are closer to {mood}:",
+            UiText::SuggestedAction => "Suggested action: {action}",
+            UiText::SuggestionPlaceholder => {
+                "Please select if the person trusts you in this moment, and the \
+                mood they are in."
+            }
+            UiText::ContextMatched => "Specific to: {context}",
+            UiText::FeedbackQuestion => "Did this help?",
+            UiText::FeedbackHelpful => "👍 helpful",
+            UiText::FeedbackNotHelpful => "🤷 not helpful",
+            UiText::FeedbackMadeItWorse => "👎 made it worse",
+            UiText::FeedbackComment => "Anything to add? (optional)",
+            UiText::FeedbackPrivacy => {
+                "Feedback is anonymous. Please leave out names and other \
+                personal details."
+            }
+            UiText::FeedbackSend => "send",
+            UiText::FeedbackThanks => "Thank you for the feedback.",
+            UiText::FeedbackFailed => "The feedback could not be sent. Please try again later.",
+            UiText::ProfileNameFallback => "the person",
+            UiText::ProfileComfortFallback => "a gift (e.g. chocolate)",
+            UiText::ProfileTrustedPersonFallback => "someone whom they already trust",
+            UiText::ProfileTriggersFallback => "anything that has upset them before",
+            UiText::ProfileBackfiredFallback => "anything that made it worse before",
+            UiText::ProfileField => "Person",
+            UiText::ProfileFieldDescription => "- optional, to tailor the suggestions to them",
+            UiText::ProfileNameLabel => "Name",
+            UiText::ProfileComfortsLabel => "Comforts, favourite first",
+            UiText::ProfileTriggersLabel => "Triggers",
+            UiText::ProfileTrustedPeopleLabel => "People they trust",
+            UiText::ProfileBackfiredLabel => "Things that backfired",
+            UiText::ProfileListHint => "one per line",
+            UiText::ProfilePrivacy => "This is only stored in this browser.",
+            UiText::ProfileAvoid => "Keep in mind: avoid {triggers}.",
+            UiText::ProfileBackfiredNote => "This made it worse before: {backfired}.",
+            UiText::Principles => "Principles",
+            UiText::PrinciplesDescription => {
+                "Ideas that come up again and again in the suggestions, and when each one applies."
+            }
+            UiText::PrincipleExamples => "For example:",
+            UiText::PrincipleAppliesIn => "Applies when:",
+            UiText::SuggestionPrinciples => "Principles:",
+            UiText::AntiPatterns => "Well-meant mistakes",
+            UiText::AntiPatternsDescription => "Common ways of helping that can make things worse, when they backfire, and what to do instead.",
+            UiText::AntiPatternExample => "Sounds like:",
+            UiText::AntiPatternMoods => "Backfires when they are:",
+            UiText::AntiPatternReason => "Why:",
+            UiText::AntiPatternInstead => "Instead:",
+            UiText::SuggestionAntiPatterns => "Avoid:",
+            UiText::ActionCheckLabel => "Is it okay to…",
+            UiText::ActionCheckPlaceholder => "choose an action",
+            UiText::ActionCheckAlternatives => "Safer alternatives:",
+            UiText::ActionCheckTrustUnknown => "The trust level isn't chosen, so this is the more cautious verdict.",
+            UiText::VerdictRecommended => "✅ Recommended",
+            UiText::VerdictRisky => "⚠️ Risky",
+            UiText::VerdictAvoid => "⛔ Avoid",
+            UiText::PhraseCheckLabel => "What do you want to say?",
+            UiText::PhraseCheckDescription => "Checked on this device, nothing is sent. Only English phrases are recognised.",
+            UiText::PhraseCheckPlaceholder => "Type what you intend to say…",
+            UiText::PhraseCheckClear => "Nothing risky found for this mood. Keep it short, and give them a way out.",
+            UiText::PhraseCheckTry => "Try:",
+            UiText::WhatToSay => "What to say",
+            UiText::SayingOpener => "To start:",
+            UiText::SayingFollowUp => "If they respond:",
+            UiText::SayingExit => "To leave gently:",
+            UiText::SayingDontSay => "Not this:",
+            UiText::WaitTimerStart => "Wait quietly for {duration}",
+            UiText::WaitTimerWaiting => "Waiting quietly, {remaining} left.",
+            UiText::WaitTimerStop => "Stop",
+            UiText::WaitTimerDone => "It's fine to speak again now, or to quietly leave.",
+        }
+    }
+}
+
+impl FromStr for UiText {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UiText::iter().find(|ui_text| ui_text.id() == s).ok_or(())
+    }
+}
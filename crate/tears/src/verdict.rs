@@ -0,0 +1,59 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::UiText;
+
+/// Whether an action is appropriate for the receiving person's state.
+///
+/// Verdicts are ordered from the safest to the least safe.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verdict {
+    /// The action is likely to help.
+    Recommended,
+    /// The action may help, but may also backfire.
+    Risky,
+    /// The action is likely to make things worse.
+    Avoid,
+}
+
+impl Verdict {
+    /// Returns an iterator over all variants of this `Verdict` enum.
+    pub fn iter() -> impl ExactSizeIterator<Item = Verdict> {
+        [Verdict::Recommended, Verdict::Risky, Verdict::Avoid].into_iter()
+    }
+
+    /// Returns the identifier of this verdict within content keys, e.g.
+    /// `"risky"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Verdict::Recommended => "recommended",
+            Verdict::Risky => "risky",
+            Verdict::Avoid => "avoid",
+        }
+    }
+
+    /// Returns the label for this verdict in the user interface.
+    pub fn ui_text(self) -> UiText {
+        match self {
+            Verdict::Recommended => UiText::VerdictRecommended,
+            Verdict::Risky => UiText::VerdictRisky,
+            Verdict::Avoid => UiText::VerdictAvoid,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id().fmt(f)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Verdict::iter().find(|verdict| verdict.id() == s).ok_or(())
+    }
+}
@@ -0,0 +1,12 @@
+/// Whether a part of a [`WordDiff`] was kept, removed, or inserted.
+///
+/// [`WordDiff`]: crate::WordDiff
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum WordChange {
+    /// The words are in both the old and new text.
+    Unchanged,
+    /// The words are only in the old text.
+    Removed,
+    /// The words are only in the new text.
+    Inserted,
+}
@@ -0,0 +1,51 @@
+use similar::{ChangeTag, TextDiff};
+
+use crate::{WordChange, WordDiffSegment};
+
+/// Word level differences between two texts.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct WordDiff {
+    /// Segments of the texts, in order.
+    ///
+    /// Adjacent segments always have different changes.
+    segments: Vec<WordDiffSegment>,
+}
+
+impl WordDiff {
+    /// Returns the word level differences from `old` to `new`.
+    pub fn new(old: &str, new: &str) -> Self {
+        let text_diff = TextDiff::from_words(old, new);
+
+        let mut segments = Vec::<WordDiffSegment>::new();
+        text_diff.iter_all_changes().for_each(|change| {
+            let word_change = match change.tag() {
+                ChangeTag::Equal => WordChange::Unchanged,
+                ChangeTag::Delete => WordChange::Removed,
+                ChangeTag::Insert => WordChange::Inserted,
+            };
+            match segments.last_mut() {
+                Some(segment) if segment.change == word_change => {
+                    segment.text.push_str(change.value());
+                }
+                _ => segments.push(WordDiffSegment {
+                    change: word_change,
+                    text: change.value().to_string(),
+                }),
+            }
+        });
+
+        Self { segments }
+    }
+
+    /// Returns the segments of the texts, in order.
+    pub fn segments(&self) -> &[WordDiffSegment] {
+        &self.segments
+    }
+
+    /// Returns whether the texts are the same.
+    pub fn is_unchanged(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|segment| segment.change == WordChange::Unchanged)
+    }
+}
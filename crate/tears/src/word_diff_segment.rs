@@ -0,0 +1,12 @@
+use crate::WordChange;
+
+/// A run of words in a [`WordDiff`] that were changed in the same way.
+///
+/// [`WordDiff`]: crate::WordDiff
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct WordDiffSegment {
+    /// Whether the words were kept, removed, or inserted.
+    pub change: WordChange,
+    /// The words, including the whitespace between them.
+    pub text: String,
+}
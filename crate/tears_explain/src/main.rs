@@ -0,0 +1,163 @@
+//! Explains which `tears` suggestion rule is used for a situation.
+
+use std::{error::Error, fs, process::ExitCode, str::FromStr};
+
+use tears::{
+    AgeGroup, CatalogFile, ContentBundle, Locale, Localizer, Mood, MoodRange, PersonProfile,
+    ReadingLevel, Relationship, RichText, Setting, Situation, SuggestionCatalog, Trust,
+};
+
+const USAGE: &str = "\
+Explains which `tears` suggestion rule is used for a situation, and why.
+
+Usage: tears_explain [--trust <trust>] [--mood <mood>] [--age-group <age_group>]
+                     [--relationship <relationship>] [--setting <setting>] [--all]
+                     [--suggestion] [--reading-level <reading_level>]
+                     [--catalog-file <file>]
+
+Options:
+  --trust <trust>                e.g. `Absent`.
+  --mood <mood>                  e.g. `Closed`.
+  --age-group <age_group>        e.g. `older_adult`.
+  --relationship <relationship>  e.g. `Colleague`.
+  --setting <setting>            e.g. `Work`.
+  --all                          Also list the rules that did not match.
+  --suggestion                   Also print the suggestion, as plain text.
+  --reading-level <reading_level>
+                                 How much of the suggestion to print: `glance`,
+                                 `standard` (default), or `extended`.
+  --catalog-file <file>          Catalog file to explain, with its rules.
+                                 Defaults to the built-in catalog.
+  -h, --help                     Print this help.
+";
+
+fn main() -> ExitCode {
+    let mut situation = Situation::default();
+    let mut all = false;
+    let mut suggestion = false;
+    let mut reading_level = ReadingLevel::default();
+    let mut catalog_file = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trust" => match args.next().and_then(|value| Trust::from_str(&value).ok()) {
+                Some(trust) => situation.trust = Some(trust),
+                None => return usage_error("`--trust` must be a trust level, e.g. `Absent`."),
+            },
+            "--mood" => match args.next().and_then(|value| Mood::from_str(&value).ok()) {
+                Some(mood) => situation.mood = Some(mood),
+                None => return usage_error("`--mood` must be a mood, e.g. `Closed`."),
+            },
+            "--age-group" => match args.next().and_then(|value| AgeGroup::from_id(&value)) {
+                Some(age_group) => situation.context.age_group = Some(age_group),
+                None => {
+                    return usage_error("`--age-group` must be an age group, e.g. `older_adult`.");
+                }
+            },
+            "--relationship" => {
+                match args
+                    .next()
+                    .and_then(|value| Relationship::from_str(&value).ok())
+                {
+                    Some(relationship) => situation.context.relationship = Some(relationship),
+                    None => {
+                        return usage_error(
+                            "`--relationship` must be a relationship, e.g. `Colleague`.",
+                        );
+                    }
+                }
+            }
+            "--setting" => match args.next().and_then(|value| Setting::from_str(&value).ok()) {
+                Some(setting) => situation.context.setting = Some(setting),
+                None => return usage_error("`--setting` must be a setting, e.g. `Work`."),
+            },
+            "--all" => all = true,
+            "--suggestion" => suggestion = true,
+            "--reading-level" => match args
+                .next()
+                .and_then(|value| ReadingLevel::from_str(&value).ok())
+            {
+                Some(reading_level_value) => reading_level = reading_level_value,
+                None => {
+                    return usage_error(
+                        "`--reading-level` must be `glance`, `standard`, or `extended`.",
+                    );
+                }
+            },
+            "--catalog-file" => match args.next() {
+                Some(catalog_file_arg) => catalog_file = Some(catalog_file_arg),
+                None => return usage_error("`--catalog-file` requires a file."),
+            },
+            "-h" | "--help" => {
+                print!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => return usage_error(&format!("Unknown argument: `{arg}`.")),
+        }
+    }
+
+    let suggestion_catalog = match catalog_file {
+        Some(catalog_file) => {
+            let catalog_file = match fs::read_to_string(&catalog_file)
+                .map_err(|error| Box::new(error) as Box<dyn Error>)
+                .and_then(|catalog_toml| Ok(CatalogFile::parse(&catalog_toml)?))
+            {
+                Ok(catalog_file) => catalog_file,
+                Err(error) => return report_error(&catalog_file, error.as_ref()),
+            };
+            SuggestionCatalog::from_catalog_file(&catalog_file)
+        }
+        None => SuggestionCatalog::builtin(),
+    };
+    let explanation = suggestion_catalog.explain(&situation);
+    if all {
+        print!("{explanation:#}");
+    } else {
+        print!("{explanation}");
+    }
+
+    if let Some(guidance) = suggestion
+        .then(|| {
+            suggestion_catalog.lookup_range_in_context(
+                situation.trust,
+                situation.mood.map(MoodRange::from),
+                situation.context,
+            )
+        })
+        .flatten()
+    {
+        let localizer = Localizer::with_bundle(ContentBundle::new(Locale::En));
+        let person_profile = PersonProfile::new();
+        let action =
+            localizer.personalize(guidance.suggestion.action(), &situation, &person_profile);
+        let description = guidance
+            .suggestion
+            .description_at(reading_level)
+            .into_iter()
+            .map(|text| {
+                RichText::parse(&localizer.personalize(text, &situation, &person_profile))
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        print!("\nSuggestion: {action}\n\n{description}\n");
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("error: {message}\n\n{USAGE}");
+    ExitCode::FAILURE
+}
+
+fn report_error(catalog_file: &str, error: &dyn Error) -> ExitCode {
+    eprintln!("error: cannot read the catalog file `{catalog_file}`: {error}");
+    let mut source = error.source();
+    while let Some(error_source) = source {
+        eprintln!("  caused by: {error_source}");
+        source = error_source.source();
+    }
+    ExitCode::FAILURE
+}
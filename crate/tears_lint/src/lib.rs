@@ -0,0 +1,14 @@
+//! Flags wording problems in the `tears` suggestion catalog.
+
+pub use crate::{
+    lint::Lint, lint_config::LintConfig, lint_error::LintError, lint_finding::LintFinding,
+    lint_report::LintReport, output_format::OutputFormat, text_stats::TextStats,
+};
+
+mod lint;
+mod lint_config;
+mod lint_error;
+mod lint_finding;
+mod lint_report;
+mod output_format;
+mod text_stats;
@@ -0,0 +1,41 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+/// Kind of problem that the linter flags.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lint {
+    /// Two cells have the same text.
+    Duplicate,
+    /// Two cells have descriptions that are almost the same.
+    NearDuplicate,
+    /// Doubled, trailing, or leading whitespace, tabs, or extra blank lines.
+    Whitespace,
+    /// A quotation mark that is not closed.
+    QuoteUnbalanced,
+    /// A sentence with more words than a stressed reader can easily follow.
+    SentenceLong,
+    /// Text whose reading grade is above the configured maximum.
+    ReadingGradeHigh,
+}
+
+impl Lint {
+    /// Returns the ID of this lint, e.g. `"near_duplicate"`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Lint::Duplicate => "duplicate",
+            Lint::NearDuplicate => "near_duplicate",
+            Lint::Whitespace => "whitespace",
+            Lint::QuoteUnbalanced => "quote_unbalanced",
+            Lint::SentenceLong => "sentence_long",
+            Lint::ReadingGradeHigh => "reading_grade_high",
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id().fmt(f)
+    }
+}
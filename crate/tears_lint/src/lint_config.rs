@@ -0,0 +1,22 @@
+/// Limits that the linter checks text against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LintConfig {
+    /// Highest Flesch-Kincaid grade that a description or extended text may
+    /// have.
+    pub grade_max: f64,
+    /// Most words that a sentence may have.
+    pub sentence_words_max: usize,
+    /// Similarity from `0.0` to `1.0` at or above which two descriptions are
+    /// near duplicates.
+    pub similarity_min: f64,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            grade_max: 8.0,
+            sentence_words_max: 25,
+            similarity_min: 0.8,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use crate::Lint;
+
+/// A problem in one cell of the catalog.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LintFinding {
+    /// Table of the cell in the catalog file, e.g. `absent.closed` or
+    /// `contexts.absent.closed.colleague+work`.
+    pub cell: String,
+    /// Field of the cell, e.g. `description`, or `None` for the whole cell.
+    pub field: Option<&'static str>,
+    /// Kind of problem.
+    pub lint: Lint,
+    /// What the problem is.
+    pub message: String,
+}
+
+impl Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(f, "{}.{field}", self.cell)?,
+            None => write!(f, "{}", self.cell)?,
+        }
+        write!(f, ": {}: {}", self.lint, self.message)
+    }
+}
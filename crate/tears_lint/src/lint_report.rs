@@ -0,0 +1,287 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+};
+
+use serde::Serialize;
+use similar::TextDiff;
+use tears::{
+    CatalogFile, ContentBundle, Locale, Localizer, PersonProfile, RichText, Situation, Suggestion,
+};
+
+use crate::{Lint, LintConfig, LintError, LintFinding, OutputFormat, TextStats};
+
+/// Number of words of a long sentence to show in its finding.
+const SENTENCE_PREVIEW_WORDS: usize = 8;
+
+/// Problems found in the suggestions of a catalog.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LintReport {
+    /// Every problem, in the order of the cells in the catalog.
+    pub findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    /// Lints the catalog file at `path`.
+    pub fn from_file(path: &Path, lint_config: &LintConfig) -> Result<Self, LintError> {
+        let catalog_toml = fs::read_to_string(path).map_err(|error| LintError::CatalogRead {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let catalog_file =
+            CatalogFile::parse(&catalog_toml).map_err(|error| LintError::CatalogParse {
+                path: path.to_path_buf(),
+                error: Box::new(error),
+            })?;
+
+        Ok(Self::new(&catalog_file, lint_config))
+    }
+
+    /// Lints the suggestions, rules, and age specific symptoms of a catalog.
+    ///
+    /// Whitespace and quotes are checked in the text as written. Sentences and
+    /// reading grades are checked in the text as shown, i.e. with templates
+    /// rendered for an unknown situation and without Markdown.
+    pub fn new(catalog_file: &CatalogFile, lint_config: &LintConfig) -> Self {
+        let localizer = Localizer::with_bundle(ContentBundle::new(Locale::En));
+
+        let cells = catalog_file
+            .iter()
+            .map(|(cell, suggestion)| (cell.to_string(), suggestion))
+            .chain(
+                catalog_file
+                    .rules()
+                    .iter()
+                    .map(|rule| (format!("rules.{}", rule.id), &rule.suggestion)),
+            )
+            .collect::<Vec<_>>();
+        let mut findings = Vec::new();
+        for (index, (cell, suggestion)) in cells.iter().enumerate() {
+            cells[..index]
+                .iter()
+                .for_each(|(cell_other, suggestion_other)| {
+                    if text_eq(suggestion, suggestion_other) {
+                        findings.push(LintFinding {
+                            cell: cell.clone(),
+                            field: None,
+                            lint: Lint::Duplicate,
+                            message: format!("Same text as `{cell_other}`."),
+                        });
+                        return;
+                    }
+
+                    let similarity = f64::from(
+                        TextDiff::from_words(
+                            suggestion_other.description(),
+                            suggestion.description(),
+                        )
+                        .ratio(),
+                    );
+                    if similarity >= lint_config.similarity_min {
+                        findings.push(LintFinding {
+                            cell: cell.clone(),
+                            field: Some("description"),
+                            lint: Lint::NearDuplicate,
+                            message: format!(
+                                "{:.0}% the same as the description of `{cell_other}`.",
+                                similarity * 100.0
+                            ),
+                        });
+                    }
+                });
+
+            [
+                ("action", Some(suggestion.action())),
+                ("description", Some(suggestion.description())),
+                ("glance", suggestion.glance()),
+                ("extended", suggestion.extended()),
+            ]
+            .into_iter()
+            .filter_map(|(field, text)| text.map(|text| (field, text)))
+            .for_each(|(field, text)| {
+                findings.extend(text_findings(cell, field, text, &localizer, lint_config));
+            });
+        }
+
+        catalog_file
+            .iter_age_symptoms()
+            .for_each(|(mood, age_group, symptoms)| {
+                let cell = format!("age_symptoms.{}", mood.name().to_lowercase());
+                findings.extend(text_findings(
+                    &cell,
+                    age_group.id(),
+                    symptoms,
+                    &localizer,
+                    lint_config,
+                ));
+            });
+
+        Self { findings }
+    }
+
+    /// Returns whether no problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// Renders the report in the given format.
+    pub fn render(&self, output_format: OutputFormat) -> Result<String, LintError> {
+        match output_format {
+            OutputFormat::Text => Ok(self.to_string()),
+            OutputFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|error| LintError::JsonSerialize { error }),
+        }
+    }
+}
+
+impl Display for LintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.findings
+            .iter()
+            .try_for_each(|finding| writeln!(f, "{finding}"))?;
+
+        match self.findings.len() {
+            0 => write!(f, "No problems found."),
+            1 => write!(f, "1 problem found."),
+            count => write!(f, "{count} problems found."),
+        }
+    }
+}
+
+/// Returns the problems in one text of a cell.
+///
+/// Reading grades are only checked for descriptions and extended explanations,
+/// as shorter texts have too few sentences for a meaningful grade.
+fn text_findings(
+    cell: &str,
+    field: &'static str,
+    text: &str,
+    localizer: &Localizer,
+    lint_config: &LintConfig,
+) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let mut finding_push = |lint: Lint, message: String| {
+        findings.push(LintFinding {
+            cell: cell.to_string(),
+            field: Some(field),
+            lint,
+            message,
+        })
+    };
+
+    whitespace_problems(text)
+        .into_iter()
+        .for_each(|message| finding_push(Lint::Whitespace, message));
+    quote_problems(text)
+        .into_iter()
+        .for_each(|message| finding_push(Lint::QuoteUnbalanced, message));
+
+    let text_shown =
+        RichText::parse(&localizer.personalize(text, &Situation::default(), &PersonProfile::new()))
+            .to_string();
+    TextStats::sentences(&text_shown)
+        .into_iter()
+        .for_each(|sentence| {
+            let words = TextStats::words(sentence).count();
+            if words > lint_config.sentence_words_max {
+                let preview = TextStats::words(sentence)
+                    .take(SENTENCE_PREVIEW_WORDS)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                finding_push(
+                    Lint::SentenceLong,
+                    format!(
+                        "Sentence has {words} words, more than {}: `{preview}…`",
+                        lint_config.sentence_words_max
+                    ),
+                );
+            }
+        });
+
+    if field == "description" || field == "extended" {
+        let reading_grade = TextStats::new(&text_shown).reading_grade();
+        if reading_grade > lint_config.grade_max {
+            finding_push(
+                Lint::ReadingGradeHigh,
+                format!(
+                    "Reading grade is {reading_grade:.1}, above {:.1}.",
+                    lint_config.grade_max
+                ),
+            );
+        }
+    }
+
+    findings
+}
+
+/// Returns whether two suggestions have the same text.
+///
+/// Principles and wait durations are not text, and are often shared by
+/// different suggestions.
+fn text_eq(suggestion: &Suggestion, suggestion_other: &Suggestion) -> bool {
+    suggestion.action() == suggestion_other.action()
+        && suggestion.description() == suggestion_other.description()
+        && suggestion.glance() == suggestion_other.glance()
+        && suggestion.extended() == suggestion_other.extended()
+}
+
+/// Returns a message for each whitespace problem in the text.
+fn whitespace_problems(text: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    if text.starts_with(char::is_whitespace) {
+        messages.push(String::from("Starts with whitespace."));
+    }
+    if text.ends_with(char::is_whitespace) {
+        messages.push(String::from("Ends with whitespace."));
+    }
+    if text.contains('\t') {
+        messages.push(String::from("Contains a tab."));
+    }
+    if text.contains("\n\n\n") {
+        messages.push(String::from("Has more than one blank line in a row."));
+    }
+
+    // The end of the last line is covered by "Ends with whitespace."
+    let line_count = text.lines().count();
+    text.lines().enumerate().for_each(|(index, line)| {
+        let line_trimmed = line.trim();
+        if line_trimmed.len() < line.trim_start().len() && index + 1 < line_count {
+            messages.push(format!("Line {} ends with whitespace.", index + 1));
+        }
+
+        let mut rest = line_trimmed;
+        while let Some(offset) = rest.find("  ") {
+            let spaces = rest[offset..].len() - rest[offset..].trim_start_matches(' ').len();
+            let before = rest[..offset].split_whitespace().next_back().unwrap_or("");
+            let after = rest[offset + spaces..]
+                .split_whitespace()
+                .next()
+                .unwrap_or("");
+            messages.push(format!(
+                "Double space in `{before}{}{after}`.",
+                " ".repeat(spaces)
+            ));
+            rest = &rest[offset + spaces..];
+        }
+    });
+
+    messages
+}
+
+/// Returns a message for each kind of quotation mark that is not closed.
+fn quote_problems(text: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    let straight = text.matches('"').count();
+    if !straight.is_multiple_of(2) {
+        messages.push(format!("Has {straight} `\"`, so one is not closed."));
+    }
+
+    let opening = text.matches('“').count();
+    let closing = text.matches('”').count();
+    if opening != closing {
+        messages.push(format!("Has {opening} `“` but {closing} `”`."));
+    }
+
+    messages
+}
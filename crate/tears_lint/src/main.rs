@@ -0,0 +1,114 @@
+use std::{error::Error, path::PathBuf, process::ExitCode, str::FromStr};
+
+use tears_lint::{LintConfig, LintReport, OutputFormat};
+
+const USAGE: &str = "\
+Flags wording problems in the `tears` suggestion catalog.
+
+Usage: tears_lint [--format <text|json>] [--catalog-file <file>]
+                  [--grade-max <grade>] [--sentence-words-max <words>]
+                  [--similarity-min <ratio>]
+
+Options:
+  --format <text|json>          Output format. Defaults to `text`.
+  --catalog-file <file>         Catalog file to lint. Defaults to the `tears`
+                                crate's `catalog/suggestions.toml`.
+  --grade-max <grade>           Highest reading grade for a description.
+                                Defaults to `8`.
+  --sentence-words-max <words>  Most words in a sentence. Defaults to `25`.
+  --similarity-min <ratio>      Similarity from `0` to `1` at which two
+                                descriptions are near duplicates. Defaults to
+                                `0.8`.
+  -h, --help                    Print this help.
+
+Exits with a failure status when any problem is found.
+";
+
+/// Default catalog file to lint.
+const CATALOG_FILE_DEFAULT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../tears/catalog/suggestions.toml"
+);
+
+fn main() -> ExitCode {
+    let mut output_format = OutputFormat::default();
+    let mut catalog_file = PathBuf::from(CATALOG_FILE_DEFAULT);
+    let mut lint_config = LintConfig::default();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                match args
+                    .next()
+                    .and_then(|format| OutputFormat::from_str(&format).ok())
+                {
+                    Some(output_format_arg) => output_format = output_format_arg,
+                    None => return usage_error("`--format` must be `text` or `json`."),
+                }
+            }
+            "--catalog-file" => match args.next() {
+                Some(catalog_file_arg) => catalog_file = PathBuf::from(catalog_file_arg),
+                None => return usage_error("`--catalog-file` requires a file."),
+            },
+            "--grade-max" => match args.next().and_then(|value| value.parse().ok()) {
+                Some(grade_max) => lint_config.grade_max = grade_max,
+                None => return usage_error("`--grade-max` must be a number, e.g. `8`."),
+            },
+            "--sentence-words-max" => match args.next().and_then(|value| value.parse().ok()) {
+                Some(sentence_words_max) => lint_config.sentence_words_max = sentence_words_max,
+                None => {
+                    return usage_error(
+                        "`--sentence-words-max` must be a whole number, e.g. `25`.",
+                    );
+                }
+            },
+            "--similarity-min" => match args
+                .next()
+                .and_then(|value| value.parse::<f64>().ok())
+                .filter(|similarity_min| (0.0..=1.0).contains(similarity_min))
+            {
+                Some(similarity_min) => lint_config.similarity_min = similarity_min,
+                None => {
+                    return usage_error("`--similarity-min` must be from `0` to `1`, e.g. `0.8`.");
+                }
+            },
+            "-h" | "--help" => {
+                print!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => return usage_error(&format!("Unknown argument: `{arg}`.")),
+        }
+    }
+
+    let lint_report = match LintReport::from_file(&catalog_file, &lint_config) {
+        Ok(lint_report) => lint_report,
+        Err(error) => return report_error(&error),
+    };
+    match lint_report.render(output_format) {
+        Ok(report) => {
+            println!("{report}");
+            if lint_report.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(error) => report_error(&error),
+    }
+}
+
+fn report_error(error: &dyn Error) -> ExitCode {
+    eprintln!("error: {error}");
+    let mut source = error.source();
+    while let Some(error_source) = source {
+        eprintln!("  caused by: {error_source}");
+        source = error_source.source();
+    }
+    ExitCode::FAILURE
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("error: {message}\n\n{USAGE}");
+    ExitCode::FAILURE
+}
@@ -0,0 +1,113 @@
+/// Abbreviations whose full stop does not end a sentence.
+const ABBREVIATIONS: [&str; 3] = ["e.g.", "i.e.", "vs."];
+
+/// Characters that may follow the end of a sentence, e.g. `."`.
+const SENTENCE_CLOSERS: [char; 4] = ['"', '”', '\'', ')'];
+
+/// Number of sentences, words, and syllables in plain text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextStats {
+    /// Number of sentences.
+    pub sentences: usize,
+    /// Number of words.
+    pub words: usize,
+    /// Number of syllables, estimated from the spelling.
+    pub syllables: usize,
+}
+
+impl TextStats {
+    /// Counts the sentences, words, and syllables in plain text.
+    pub fn new(text: &str) -> Self {
+        let words = Self::words(text).collect::<Vec<_>>();
+        Self {
+            sentences: Self::sentences(text).len(),
+            words: words.len(),
+            syllables: words.iter().map(|word| Self::syllables(word)).sum(),
+        }
+    }
+
+    /// Returns the Flesch-Kincaid grade level of the text, i.e. roughly the
+    /// number of years of schooling needed to read it.
+    ///
+    /// Text without words has a grade of `0.0`.
+    pub fn reading_grade(&self) -> f64 {
+        if self.words == 0 || self.sentences == 0 {
+            return 0.0;
+        }
+
+        let words_per_sentence = self.words as f64 / self.sentences as f64;
+        let syllables_per_word = self.syllables as f64 / self.words as f64;
+        0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59
+    }
+
+    /// Returns the sentences in plain text.
+    ///
+    /// Sentences end at a `.`, `!`, or `?` that is followed by whitespace, and
+    /// at the end of each line.
+    pub fn sentences(text: &str) -> Vec<&str> {
+        let mut sentences = Vec::new();
+        for line in text.lines() {
+            let mut start = 0;
+            let mut chars = line.char_indices().peekable();
+            while let Some((offset, c)) = chars.next() {
+                if !matches!(c, '.' | '!' | '?') {
+                    continue;
+                }
+                let mut end = offset + c.len_utf8();
+                while let Some((offset_closer, c_closer)) =
+                    chars.next_if(|(_, c_next)| SENTENCE_CLOSERS.contains(c_next))
+                {
+                    end = offset_closer + c_closer.len_utf8();
+                }
+
+                let is_followed_by_space = chars.peek().is_some_and(|(_, c)| c.is_whitespace());
+                let word_last = line[..end].rsplit(char::is_whitespace).next();
+                let is_abbreviation = word_last.is_some_and(|word_last| {
+                    ABBREVIATIONS
+                        .iter()
+                        .any(|abbreviation| word_last.trim_start_matches('(') == *abbreviation)
+                });
+                if is_followed_by_space && !is_abbreviation {
+                    sentences.push(line[start..end].trim());
+                    start = end;
+                }
+            }
+            sentences.push(line[start..].trim());
+        }
+        sentences.retain(|sentence| Self::words(sentence).next().is_some());
+
+        sentences
+    }
+
+    /// Returns the words in plain text, i.e. the whitespace separated parts
+    /// that contain a letter or digit.
+    pub fn words(text: &str) -> impl Iterator<Item = &str> {
+        text.split_whitespace()
+            .filter(|word| word.chars().any(char::is_alphanumeric))
+    }
+
+    /// Returns the number of syllables in an English word, estimated from the
+    /// groups of vowels in it.
+    pub fn syllables(word: &str) -> usize {
+        let word = word
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>();
+
+        let mut syllables = 0;
+        let mut is_vowel_previous = false;
+        for c in word.chars() {
+            let is_vowel = matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+            if is_vowel && !is_vowel_previous {
+                syllables += 1;
+            }
+            is_vowel_previous = is_vowel;
+        }
+        if syllables > 1 && word.ends_with('e') && !word.ends_with("le") {
+            syllables -= 1;
+        }
+
+        syllables.max(1)
+    }
+}
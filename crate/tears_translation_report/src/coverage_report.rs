@@ -0,0 +1,96 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+    path::Path,
+};
+
+use serde::Serialize;
+use tears::{CatalogFile, ContentBundle, Locale, SuggestionCatalog};
+
+use crate::{LocaleCoverage, OutputFormat, ReportError};
+
+/// Translation coverage of every locale other than English.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CoverageReport {
+    /// Coverage of each locale.
+    pub locales: Vec<LocaleCoverage>,
+}
+
+impl CoverageReport {
+    /// Compares the bundle files in `locales_dir` against the English content,
+    /// with the suggestions in `catalog_file`.
+    ///
+    /// Bundles are read from `<locales_dir>/<locale_code>.toml`. A locale
+    /// without a bundle file is reported as having no translations. Without a
+    /// `catalog_file`, the catalog built into `tears` is used.
+    pub fn from_dir(locales_dir: &Path, catalog_file: Option<&Path>) -> Result<Self, ReportError> {
+        let suggestion_catalog = match catalog_file {
+            Some(path) => {
+                let catalog_toml =
+                    fs::read_to_string(path).map_err(|error| ReportError::CatalogRead {
+                        path: path.to_path_buf(),
+                        error,
+                    })?;
+                let catalog_file = CatalogFile::parse(&catalog_toml).map_err(|error| {
+                    ReportError::CatalogParse {
+                        path: path.to_path_buf(),
+                        error: Box::new(error),
+                    }
+                })?;
+                SuggestionCatalog::from_catalog_file(&catalog_file)
+            }
+            None => SuggestionCatalog::builtin(),
+        };
+
+        let locales = Locale::iter()
+            .filter(|locale| *locale != Locale::En)
+            .map(|locale| {
+                let path = locales_dir.join(format!("{}.toml", locale.code()));
+                let bundle_toml = match fs::read_to_string(&path) {
+                    Ok(bundle_toml) => bundle_toml,
+                    Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+                    Err(error) => return Err(ReportError::BundleRead { path, error }),
+                };
+                let (content_bundle, keys_unknown) =
+                    ContentBundle::parse_with_unknown_keys(locale, &bundle_toml).map_err(
+                        |error| ReportError::BundleParse {
+                            path,
+                            error: Box::new(error),
+                        },
+                    )?;
+
+                Ok(LocaleCoverage::new(
+                    &content_bundle,
+                    keys_unknown,
+                    &suggestion_catalog,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { locales })
+    }
+
+    /// Renders the report in the given format.
+    pub fn render(&self, output_format: OutputFormat) -> Result<String, ReportError> {
+        match output_format {
+            OutputFormat::Text => Ok(self.to_string()),
+            OutputFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|error| ReportError::JsonSerialize { error }),
+        }
+    }
+}
+
+impl Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.locales
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, locale_coverage)| {
+                if index > 0 {
+                    writeln!(f)?;
+                }
+                locale_coverage.fmt(f)
+            })
+    }
+}
@@ -0,0 +1,13 @@
+//! Reports which `tears` content is missing, stale, or extra in each locale's
+//! content bundle.
+
+pub use crate::{
+    coverage_report::CoverageReport, locale_coverage::LocaleCoverage, output_format::OutputFormat,
+    report_error::ReportError, stale_key::StaleKey,
+};
+
+mod coverage_report;
+mod locale_coverage;
+mod output_format;
+mod report_error;
+mod stale_key;
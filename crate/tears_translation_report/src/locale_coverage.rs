@@ -0,0 +1,130 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+use tears::{ContentBundle, ContentFingerprint, ContentKey, SuggestionCatalog};
+
+use crate::StaleKey;
+
+/// Translation coverage of one locale's content bundle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LocaleCoverage {
+    /// Locale code, e.g. `es`.
+    pub locale: &'static str,
+    /// Name of the locale in its own language, e.g. `Español`.
+    pub locale_name: &'static str,
+    /// Number of keys that have English text.
+    pub keys_total: usize,
+    /// Number of those keys that have a translation, including stale ones.
+    pub keys_translated: usize,
+    /// Keys with English text that have no translation.
+    pub missing: Vec<String>,
+    /// Keys whose English text changed since they were translated.
+    pub stale: Vec<StaleKey>,
+    /// Keys in the bundle that have no English text, or are not known keys.
+    pub extra: Vec<String>,
+}
+
+impl LocaleCoverage {
+    /// Compares a content bundle against the English text.
+    ///
+    /// # Parameters
+    ///
+    /// * `content_bundle`: Translations for the locale.
+    /// * `keys_unknown`: Keys in the bundle file that are not `ContentKey`s.
+    /// * `suggestion_catalog`: Catalog that the suggestion text is taken from.
+    pub fn new(
+        content_bundle: &ContentBundle,
+        keys_unknown: Vec<String>,
+        suggestion_catalog: &SuggestionCatalog,
+    ) -> Self {
+        let locale = content_bundle.locale();
+
+        let mut keys_total = 0;
+        let mut keys_translated = 0;
+        let mut missing = Vec::new();
+        let mut stale = Vec::new();
+        ContentKey::iter()
+            .filter_map(|key| {
+                key.base_text(suggestion_catalog)
+                    .map(|base_text| (key, base_text))
+            })
+            .for_each(|(key, base_text)| {
+                keys_total += 1;
+
+                if content_bundle.get(key).is_none() {
+                    missing.push(key.to_string());
+                    return;
+                }
+                keys_translated += 1;
+
+                let fingerprint = content_bundle.fingerprint(key);
+                let fingerprint_expected = ContentFingerprint::new(base_text);
+                if fingerprint != Some(fingerprint_expected) {
+                    stale.push(StaleKey {
+                        key: key.to_string(),
+                        fingerprint: fingerprint.map(|fingerprint| fingerprint.to_string()),
+                        fingerprint_expected: fingerprint_expected.to_string(),
+                    });
+                }
+            });
+
+        let mut extra = content_bundle
+            .iter()
+            .filter(|(key, _text)| key.base_text(suggestion_catalog).is_none())
+            .map(|(key, _text)| key.to_string())
+            .chain(keys_unknown)
+            .collect::<Vec<_>>();
+        extra.sort();
+
+        Self {
+            locale: locale.code(),
+            locale_name: locale.name(),
+            keys_total,
+            keys_translated,
+            missing,
+            stale,
+            extra,
+        }
+    }
+}
+
+impl Display for LocaleCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            locale,
+            locale_name,
+            keys_total,
+            keys_translated,
+            missing,
+            stale,
+            extra,
+        } = self;
+
+        writeln!(
+            f,
+            "{locale} ({locale_name}): {keys_translated} / {keys_total} translated"
+        )?;
+
+        writeln!(f, "  missing ({}):", missing.len())?;
+        missing
+            .iter()
+            .try_for_each(|key| writeln!(f, "    {key}"))?;
+
+        writeln!(f, "  stale ({}):", stale.len())?;
+        stale.iter().try_for_each(|stale_key| {
+            let StaleKey {
+                key,
+                fingerprint,
+                fingerprint_expected,
+            } = stale_key;
+            let fingerprint = fingerprint.as_deref().unwrap_or("none");
+            writeln!(
+                f,
+                "    {key} (fingerprint: {fingerprint}, expected: {fingerprint_expected})"
+            )
+        })?;
+
+        writeln!(f, "  extra ({}):", extra.len())?;
+        extra.iter().try_for_each(|key| writeln!(f, "    {key}"))
+    }
+}
@@ -0,0 +1,82 @@
+use std::{error::Error, path::PathBuf, process::ExitCode, str::FromStr};
+
+use tears_translation_report::{CoverageReport, OutputFormat};
+
+const USAGE: &str = "\
+Reports translation coverage of the `tears` content bundles.
+
+Usage: tears_translation_report [--format <text|json>] [--locales-dir <dir>]
+                                [--catalog-file <file>]
+
+Options:
+  --format <text|json>   Output format. Defaults to `text`.
+  --locales-dir <dir>    Directory containing `<locale>.toml` bundles.
+                         Defaults to the `tears` crate's `locales` directory.
+  --catalog-file <file>  Catalog file whose suggestions are translated.
+                         Defaults to the file in `TEARS_CATALOG_FILE`, as
+                         served by the server, or the built in catalog.
+  -h, --help             Print this help.
+";
+
+/// Default directory to read content bundles from.
+const LOCALES_DIR_DEFAULT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../tears/locales");
+
+/// Environment variable with the catalog file that the server serves.
+const CATALOG_FILE_ENV: &str = "TEARS_CATALOG_FILE";
+
+fn main() -> ExitCode {
+    let mut output_format = OutputFormat::default();
+    let mut locales_dir = PathBuf::from(LOCALES_DIR_DEFAULT);
+    let mut catalog_file = std::env::var_os(CATALOG_FILE_ENV).map(PathBuf::from);
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                match args
+                    .next()
+                    .and_then(|format| OutputFormat::from_str(&format).ok())
+                {
+                    Some(output_format_arg) => output_format = output_format_arg,
+                    None => return usage_error("`--format` must be `text` or `json`."),
+                }
+            }
+            "--locales-dir" => match args.next() {
+                Some(locales_dir_arg) => locales_dir = PathBuf::from(locales_dir_arg),
+                None => return usage_error("`--locales-dir` requires a directory."),
+            },
+            "--catalog-file" => match args.next() {
+                Some(catalog_file_arg) => catalog_file = Some(PathBuf::from(catalog_file_arg)),
+                None => return usage_error("`--catalog-file` requires a file."),
+            },
+            "-h" | "--help" => {
+                print!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => return usage_error(&format!("Unknown argument: `{arg}`.")),
+        }
+    }
+
+    match CoverageReport::from_dir(&locales_dir, catalog_file.as_deref())
+        .and_then(|coverage_report| coverage_report.render(output_format))
+    {
+        Ok(report) => {
+            println!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            let mut source = error.source();
+            while let Some(error_source) = source {
+                eprintln!("  caused by: {error_source}");
+                source = error_source.source();
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("error: {message}\n\n{USAGE}");
+    ExitCode::FAILURE
+}
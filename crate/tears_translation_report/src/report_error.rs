@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+/// Errors when producing a [`CoverageReport`].
+///
+/// [`CoverageReport`]: crate::CoverageReport
+#[derive(Debug, thiserror::Error)]
+pub enum ReportError {
+    /// A content bundle file exists but could not be read.
+    #[error("Failed to read content bundle `{}`.", path.display())]
+    BundleRead {
+        /// Path to the bundle file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// A content bundle file is malformed.
+    #[error("Failed to parse content bundle `{}`.", path.display())]
+    BundleParse {
+        /// Path to the bundle file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: Box<tears::ContentBundleError>,
+    },
+    /// The catalog file could not be read.
+    #[error("Failed to read catalog file `{}`.", path.display())]
+    CatalogRead {
+        /// Path to the catalog file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// The catalog file is malformed.
+    #[error("Failed to parse catalog file `{}`.", path.display())]
+    CatalogParse {
+        /// Path to the catalog file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: Box<tears::CatalogFileError>,
+    },
+    /// The report could not be serialized as JSON.
+    #[error("Failed to serialize the report as JSON.")]
+    JsonSerialize {
+        /// The underlying error.
+        #[source]
+        error: serde_json::Error,
+    },
+}
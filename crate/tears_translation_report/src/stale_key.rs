@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+/// A translation whose English text has changed since it was translated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StaleKey {
+    /// The content key, e.g. `mood.closed.symptoms`.
+    pub key: String,
+    /// Fingerprint recorded in the bundle, if any.
+    pub fingerprint: Option<String>,
+    /// Fingerprint of the current English text.
+    ///
+    /// Once the translation is updated, this is the value to record in the
+    /// bundle.
+    pub fingerprint_expected: String,
+}
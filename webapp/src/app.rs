@@ -1,27 +1,76 @@
-use std::{collections::HashMap, str::FromStr, time::Duration};
+use std::{str::FromStr, time::Duration};
 
 use leptos::{
     component,
     either::Either,
     hydration::{AutoReload, HydrationScripts},
     prelude::{
-        event_target_value, signal, ClassAttribute, CollectView, ElementChild, Get,
-        GlobalAttributes, GlobalOnAttributes, IntoView, LeptosOptions, OnAttribute, PropAttribute,
-        RwSignal, Signal, Write,
+        document, event_target_checked, event_target_value, signal, AddAnyAttr, AriaAttributes,
+        ClassAttribute, CollectView, Effect, ElementChild, Get, GlobalAttributes, IntoAttribute,
+        IntoView, LeptosOptions, Memo, OnAttribute, PropAttribute, Read, RwSignal, Set, Signal,
+        Track, With, Write,
     },
     view,
 };
-use leptos_meta::{provide_meta_context, MetaTags, Stylesheet, Title};
+use leptos_meta::{provide_meta_context, Html, MetaTags, Stylesheet, Title};
 use leptos_router::{
     components::{Route, Router, Routes, RoutingProgress},
     StaticSegment,
 };
-use tears::{Mood, Suggestion, Trust};
+use tears::{
+    AgeGroup, AntiPattern, Guidance, HelperAction, Locale, Localizer, Mood, MoodRange,
+    PersonProfile, ProfilePlaceholder, ReadingLevel, Relationship, RichText, SayingKind, Setting,
+    Situation, SuggestionCatalog, SuggestionContext, Trust, UiText,
+};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+#[cfg(feature = "ssr")]
+pub use self::{
+    editor::{editor_auth, editor_path, CATALOG_FILE_ENV, EDITORS_ENV, EDITOR_ALLOW_HTTP_ENV},
+    forwarded::TRUSTED_PROXIES_ENV,
+};
+
+use self::{
+    action_check::ActionCheck,
+    anti_patterns::{anti_pattern_href, AntiPatternsPage},
+    character_drawing::CharacterDrawing,
+    editor::{EditorPage, FeedbackReportPage},
+    feedback::FeedbackWidget,
+    l10n::LocaleSwitcher,
+    phrase_checker::PhraseChecker,
+    principles::{principle_href, PrinciplesPage},
+    profile::ProfileInput,
+    reading_level::ReadingLevelSwitcher,
+    rich_text::RichTextView,
+    theme::{Theme, ThemeSwitcher},
+    wait_timer::WaitTimer,
+};
+
+mod action_check;
+mod anti_patterns;
+mod catalog;
+mod character_drawing;
+mod editor;
+mod feedback;
+#[cfg(feature = "ssr")]
+mod forwarded;
+mod keyboard_shortcuts;
+mod l10n;
+mod local_storage;
+mod phrase_checker;
+mod principles;
+mod profile;
+mod reading_level;
+mod rich_text;
+mod theme;
+mod wait_timer;
 
 pub fn shell(options: LeptosOptions) -> impl IntoView {
     view! {
         <!DOCTYPE html>
-        <html lang="en">
+        // `lang` and `dir` are set by `App` from the active locale.
+        <html>
             <head>
                 <meta charset="utf-8"/>
                 <meta name="viewport" content="width=device-width, initial-scale=1" />
@@ -40,9 +89,12 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
     }
 }
 
+// Classes use logical directions such as `end-*`, `ps-*`, and `me-*` instead
+// of `right-*`, `pl-*`, and `mr-*`, so the layout mirrors for right-to-left
+// locales.
 const PAGE_CLASSES: &str = "\
-    bg-slate-950 \
-    text-slate-100 \
+    bg-tears-page \
+    text-tears-fg \
     \
     min-h-dvh \
     w-dvw \
@@ -79,6 +131,13 @@ const NAV_SPACER_CLASSES: &str = "\
     grow \
 ";
 
+const NAV_LINK_CLASSES: &str = "\
+    flex \
+    items-center \
+    px-4 \
+    text-base \
+";
+
 const SKIP_TO_CONTENT_CLASSES: &str = "\
     opacity-0 \
     pointer-events-none \
@@ -96,15 +155,15 @@ const MAIN_CLASSES: &str = "\
 const DISCLAIMER_MESSAGE_CLASSES: &str = "\
     fixed \
     bottom-8 \
-    right-8 \
+    end-8 \
     \
-    bg-slate-700 \
-    text-slate-100 \
+    bg-tears-popup \
+    text-tears-fg \
     text-justify \
     \
     p-8 \
     border-2 \
-    border-slate-800 \
+    border-tears-border \
     rounded-lg \
     max-w-[768px] \
 ";
@@ -120,38 +179,38 @@ const HOMEPAGE_CLASSES: &str = "\
 ";
 
 const LINK_CLASSES: &str = "\
-    text-slate-300 \
-    hover:text-slate-100 \
+    text-tears-muted \
+    hover:text-tears-fg \
     \
     focus-visible:outline-none \
     rounded-lg \
     ring-offset-4 \
-    ring-offset-slate-950 \
+    ring-offset-tears-page \
     focus:ring-2 \
     active:ring-2 \
-    focus:ring-blue-500 \
-    active:ring-blue-500 \
+    focus:ring-tears-focus \
+    active:ring-tears-focus \
 ";
 
 const BUTTON_CROSS_CLASSES: &str = "\
-    text-slate-300 \
-    float-right \
+    text-tears-muted \
+    [float:inline-end] \
     \
     outline-none \
     rounded-lg \
     ring-offset-4 \
-    ring-offset-slate-700 \
+    ring-offset-tears-popup \
     focus:ring-2 \
     active:ring-2 \
-    focus:ring-blue-500 \
-    active:ring-blue-500 \
+    focus:ring-tears-focus \
+    active:ring-tears-focus \
 ";
 
 const BUTTON_CROSS_ICON_CLASSES: &str = "\
     inline-block \
     w-4 \
     h-4 \
-    mr-2 \
+    me-2 \
     bg-cross \
     bg-no-repeat \
     bg-center \
@@ -159,14 +218,16 @@ const BUTTON_CROSS_ICON_CLASSES: &str = "\
 ";
 
 const INPUTS_DIV_CLASSES: &str = "\
-    bg-slate-900 \
+    bg-tears-panel \
+    border-2 \
+    border-tears-outline \
     rounded-lg \
     w-2/5 \
     min-w-min \
     p-8 \
     first:pt-4 \
     divide-y \
-    divide-slate-700 \
+    divide-tears-divider \
 ";
 
 const INPUT_PANEL_CLASSES: &str = "\
@@ -178,19 +239,72 @@ const FIELD_CLASSES: &str = "\
 ";
 
 const FIELD_NAME_CLASSES: &str = "\
-    pr-2 \
+    pe-2 \
     font-bold \
     text-3xl \
 ";
 
 const FIELD_DESC_CLASSES: &str = "\
     italic \
-    text-slate-200 \
+    text-tears-subtle \
 ";
 
 const FIELD_HINT_CLASSES: &str = "\
     italic \
-    text-slate-300 \
+    text-tears-muted \
+";
+
+const MOOD_RANGE_MODE_CLASSES: &str = "\
+    flex \
+    items-center \
+    gap-2 \
+    pt-2 \
+    text-base \
+    italic \
+    text-tears-muted \
+";
+
+const MOOD_RANGE_MODE_INPUT_CLASSES: &str = "\
+    w-4 \
+    h-4 \
+    accent-tears-focus \
+";
+
+const MOOD_HEADING_CLASSES: &str = "\
+    pt-3 \
+    font-bold \
+    text-tears-subtle \
+";
+
+const CONTEXT_FIELDS_CLASSES: &str = "\
+    flex \
+    flex-wrap \
+    gap-x-8 \
+    gap-y-2 \
+    text-base \
+";
+
+const CONTEXT_LABEL_CLASSES: &str = "\
+    flex \
+    items-center \
+    gap-2 \
+    text-tears-subtle \
+";
+
+const CONTEXT_SELECT_CLASSES: &str = "\
+    bg-tears-control \
+    text-tears-control-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-panel \
+    focus:ring-2 \
+    focus:ring-tears-focus \
 ";
 
 const DESCRIPTION_LABEL_CLASSES: &str = "\
@@ -207,11 +321,11 @@ const RADIO_WRAPPER_CLASSES: &str = "\
     w-max \
     rounded-lg \
     ring-offset-4 \
-    ring-offset-slate-950 \
+    ring-offset-tears-panel \
     has-[:focus]:ring-2 \
     has-[:active]:ring-2 \
-    has-[:focus]:ring-blue-500 \
-    has-[:active]:ring-blue-500 \
+    has-[:focus]:ring-tears-focus \
+    has-[:active]:ring-tears-focus \
 ";
 
 const RADIO_INPUT_CLASSES: &str = "\
@@ -224,16 +338,16 @@ const RADIO_CLEAR_CLASSES: &str = "\
     py-0 \
     p-2 \
     leading-none \
-    text-slate-300 \
+    text-tears-muted \
     \
     outline-none \
     rounded-lg \
     ring-offset-4 \
-    ring-offset-slate-900 \
+    ring-offset-tears-panel \
     focus:ring-2 \
     active:ring-2 \
-    focus:ring-blue-500 \
-    active:ring-blue-500 \
+    focus:ring-tears-focus \
+    active:ring-tears-focus \
 ";
 
 const RADIO_LABEL_CLASSES: &str = "\
@@ -243,33 +357,58 @@ const RADIO_LABEL_CLASSES: &str = "\
     font-bold \
     text-center \
     \
-    bg-slate-300 \
-    hover:bg-slate-200 \
-    has-[:checked]:hover:bg-slate-200 \
-    has-[:active]:bg-slate-400 \
+    bg-tears-control \
+    hover:bg-tears-control-hover \
+    has-[:checked]:hover:bg-tears-control-hover \
+    has-[:active]:bg-tears-control-checked \
     \
     border \
-    has-[:checked]:bg-slate-400 \
-    text-slate-700 \
-    has-[:checked]:text-slate-900 \
+    border-tears-divider \
+    has-[:checked]:bg-tears-control-checked \
+    text-tears-control-fg \
+    has-[:checked]:text-tears-control-checked-fg \
     \
     first-of-type:rounded-s-lg \
     last-of-type:rounded-e-lg \
 ";
 
 const SUGGESTION_DIV_CLASSES: &str = "\
-    bg-slate-800 \
-    text-slate-100 \
+    bg-tears-card \
+    text-tears-fg \
     \
     w-2/5 \
     min-h-40 \
     p-4 \
+    border-2 \
+    border-tears-outline \
     rounded-lg \
     shadow-[inset_0_2px_4px_0_rgba(0,0,0,0.3)] \
     \
     overflow-scroll \
 ";
 
+const GUIDANCE_NOTE_CLASSES: &str = "\
+    py-3 \
+    italic \
+    text-tears-muted \
+";
+
+const WHAT_TO_SAY_CLASSES: &str = "\
+    py-3 \
+";
+
+const WHAT_TO_SAY_SUMMARY_CLASSES: &str = "\
+    cursor-pointer \
+    font-bold \
+    pb-3 \
+";
+
+const WHAT_TO_SAY_LIST_CLASSES: &str = "\
+    list-disc \
+    ps-8 \
+    pb-3 \
+";
+
 const SUGGESTION_DIV_PLACEHOLDER_CLASSES: &str = "\
     opacity-75 \
     italic \
@@ -285,9 +424,44 @@ pub fn App() -> impl IntoView {
 
     let (is_routing, set_is_routing) = signal(false);
 
+    // The stored theme, locale, and reading level are only available in the
+    // browser, so they are loaded after the page is hydrated.
+    let theme = RwSignal::new(None::<Theme>);
+    let locale = RwSignal::new(Locale::default());
+    let reading_level = RwSignal::new(ReadingLevel::default());
+    Effect::new(move |_| {
+        theme.set(Theme::load());
+        if let Some(locale_stored) = l10n::locale_load() {
+            locale.set(locale_stored);
+        }
+        if let Some(reading_level_stored) = reading_level::reading_level_load() {
+            reading_level.set(reading_level_stored);
+        }
+    });
+    let localizer = l10n::provide_localizer(locale);
+    reading_level::provide_reading_level(reading_level);
+    catalog::provide_suggestion_catalog(localizer);
+
+    let skip_to_content = move |_| {
+        let first_input = document()
+            .query_selector("#main input")
+            .ok()
+            .flatten()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+        if let Some(first_input) = first_input {
+            let _ = first_input.focus();
+        }
+    };
+
     view! {
         // sets the document title
         <Title text="tears • azriel.im"/>
+        <Html
+            {..}
+            lang=move || locale.get().code()
+            dir=move || locale.get().text_direction().attr_value()
+            data-theme=move || theme.get().map(Theme::attr_value)
+        />
 
         // content for this welcome page
         <Router set_is_routing>
@@ -298,24 +472,53 @@ pub fn App() -> impl IntoView {
                 <div class=SKIP_TO_CONTENT_CLASSES>
                     <a
                         href="#main"
-                        onclick=r#"document.querySelector("input").focus();"#
+                        on:click=skip_to_content
                         class=LINK_CLASSES
-                    >"Skip to content"</a>
+                    >{l10n::ui(localizer, UiText::SkipToContent)}</a>
                 </div>
                 <nav class=NAV_CLASSES>
                     <h1 class=H1_CLASSES>"💧 tears"</h1>
                     <div class=NAV_SPACER_CLASSES />
+                    <a href=site_href("principles") class=format!("{LINK_CLASSES} {NAV_LINK_CLASSES}")>
+                        {l10n::ui(localizer, UiText::Principles)}
+                    </a>
+                    <a href=site_href("anti-patterns") class=format!("{LINK_CLASSES} {NAV_LINK_CLASSES}")>
+                        {l10n::ui(localizer, UiText::AntiPatterns)}
+                    </a>
+                    <LocaleSwitcher locale />
+                    <ReadingLevelSwitcher reading_level />
+                    <ThemeSwitcher theme />
                     <a
                         href="https://github.com/azriel91/tears"
                         target="_blank"
                         class=LINK_CLASSES
                     >
-                        "🐙 github"
+                        {l10n::ui(localizer, UiText::Github)}
                     </a>
                 </nav>
                 <main id="main" class=MAIN_CLASSES>
                     <Routes fallback=RouterFallback>
                         <Route path=StaticSegment(site_prefix) view=HomePage />
+                        <Route
+                            path=(StaticSegment(site_prefix), StaticSegment("principles"))
+                            view=PrinciplesPage
+                        />
+                        <Route
+                            path=(StaticSegment(site_prefix), StaticSegment("anti-patterns"))
+                            view=AntiPatternsPage
+                        />
+                        <Route
+                            path=(StaticSegment(site_prefix), StaticSegment("editor"))
+                            view=EditorPage
+                        />
+                        <Route
+                            path=(
+                                StaticSegment(site_prefix),
+                                StaticSegment("editor"),
+                                StaticSegment("feedback"),
+                            )
+                            view=FeedbackReportPage
+                        />
                     </Routes>
                 </main>
                 <div class=PAGE_SPACER_CLASSES />
@@ -331,14 +534,16 @@ pub fn App() -> impl IntoView {
 fn RouterFallback() -> impl IntoView {
     let location = leptos_router::hooks::use_location();
     let pathname = move || location.pathname.get();
+    let localizer = l10n::use_localizer();
 
     view! {
-        <p>"Path not found: " {pathname}</p>
+        <p>{l10n::ui(localizer, UiText::PathNotFound)} {pathname}</p>
     }
 }
 
 #[component]
 fn Disclaimer() -> impl IntoView {
+    let localizer = l10n::use_localizer();
     let disclaimer_visibility = RwSignal::new(true);
     let disclaimer_set_visible = move |_| *disclaimer_visibility.write() = true;
     let disclaimer_set_invisible = move |_| *disclaimer_visibility.write() = false;
@@ -367,7 +572,7 @@ fn Disclaimer() -> impl IntoView {
                 class=disclaimer_link_classes
                 on:click=disclaimer_set_visible
             >
-                "ℹ️ disclaimer"
+                {l10n::ui(localizer, UiText::Disclaimer)}
             </button>
 
             <div class=disclaimer_message_classes>
@@ -376,25 +581,19 @@ fn Disclaimer() -> impl IntoView {
                     on:click=disclaimer_set_invisible
                 >
                     <span class=BUTTON_CROSS_ICON_CLASSES />
-                    "close"
+                    {l10n::ui(localizer, UiText::Close)}
                 </button>
 
-                <p class=DESCRIPTION_LABEL_CLASSES>"ℹ️ disclaimer"</p>
+                <p class=DESCRIPTION_LABEL_CLASSES>{l10n::ui(localizer, UiText::Disclaimer)}</p>
 
                 <p class=DESCRIPTION_CLASSES>
-                    "These are words from personal experience, not advice from \
-                    a qualified professional."
+                    {l10n::ui(localizer, UiText::DisclaimerPersonal)}
                 </p>
                 <p class=DESCRIPTION_CLASSES>
-                    "The descriptions are intentionally short so they are easy \
-                    to read, and each example is only one possibility out of \
-                    innumerable unique situations."
+                    {l10n::ui(localizer, UiText::DisclaimerShort)}
                 </p>
                 <p class=DESCRIPTION_CLASSES>
-                    "These are written for people who are eager to help, to \
-                    show that practical steps may not necessarily be \
-                    beneficial -- the person may not have the capacity to \
-                    receive it, or if they are, it may not be from you."
+                    {l10n::ui(localizer, UiText::DisclaimerEager)}
                 </p>
             </div>
         </div>
@@ -404,179 +603,52 @@ fn Disclaimer() -> impl IntoView {
 /// Renders the home page of your application.
 #[component]
 fn HomePage() -> impl IntoView {
-    let suggestions = suggestions_map();
+    let suggestion_catalog = catalog::use_suggestion_catalog();
 
     let trust = RwSignal::new(None::<Trust>);
-    let mood = RwSignal::new(None::<Mood>);
-    let suggestion = Signal::derive(move || {
-        let trust = trust.get();
-        let mood = mood.get();
+    let mood_range = RwSignal::new(None::<MoodRange>);
+    let context = RwSignal::new(SuggestionContext::default());
+    keyboard_shortcuts::register(trust, mood_range);
 
-        trust
-            .zip(mood)
-            .and_then(|(trust, mood)| suggestions.get(&(trust, mood)))
-            .cloned()
+    // The profile is only stored in the browser, so it is loaded after the
+    // page is hydrated.
+    let person_profile = RwSignal::new(PersonProfile::new());
+    Effect::new(move |_| {
+        if let Some(person_profile_stored) = profile::profile_load() {
+            person_profile.set(person_profile_stored);
+        }
+    });
+
+    let guidance = Signal::derive(move || {
+        suggestion_catalog.with(|suggestion_catalog| {
+            suggestion_catalog.lookup_range_in_context(trust.get(), mood_range.get(), context.get())
+        })
     });
 
     view! {
         <div class=HOMEPAGE_CLASSES>
-            <Inputs trust mood />
-            <SuggestionDiv suggestion />
+            <Inputs trust mood_range context person_profile suggestion_catalog />
+            <SuggestionDiv trust mood_range guidance person_profile with_feedback=true with_action_check=true with_phrase_check=true />
         </div>
     }
 }
 
-/// Default suggestions
-fn suggestions_map() -> HashMap<(Trust, Mood), Suggestion> {
-    let mut suggestions = HashMap::with_capacity(32);
-    suggestions.insert(
-        (Trust::Absent, Mood::_01_Anguished),
-        Suggestion {
-            action: "Stay away",
-            description: "As a \"stranger\", your presence pressurizes the person, \
-                and may aggravate them, even when your motive is pure.\n\
-                \n\
-                It may be best to find someone whom they already trust.",
-        },
-    );
-    suggestions.insert(
-        (Trust::Absent, Mood::_02_Closed),
-        Suggestion {
-            action: "Stay away",
-            description: "Leave a gift if you must (e.g. chocolate), but your \
-                presence pressurizes the person.\n\
-                \n\
-                If they accept the gift in your  absence, then that may be the \
-                beginning of trust.",
-        },
-    );
-    suggestions.insert(
-        (Trust::Absent, Mood::_03_Cautious),
-        Suggestion {
-            action: "Occasionally ask if they want something",
-            description: "If you are sure the person wants something (that \
-                isn't harmful), ask \"do you want ____\"?\n\
-                \n\
-                Make sure the conversation is paced such that they are able to \
-                handle it.\n\
-                \n\
-                Don't ask why, don't require an answer -- provide a way \"out\" \
-                (e.g. \"you don't have to answer\"). Asking such questions is \
-                perceived as \"justify yourself\", and may cause them to hate \
-                you (which they may not vocalize).",
-        },
-    );
-    suggestions.insert(
-        (Trust::Absent, Mood::_04_Unsettled),
-        Suggestion {
-            action: "Ask, \"would you like to say anything?\", then wait.",
-            description: "Just listen, don't problem solve -- you haven't established \
-                trust with the person to do so.\n\
-                \n\
-                At this stage, you may have some rational conversation, but \
-                nothing that would introduce too much emotional pressure.\n\
-                \n\
-                Be ready to leave them alone if that is what they want (they \
-                may not say it).",
-        },
-    );
-    suggestions.insert(
-        (Trust::Absent, Mood::_05_Calm),
-        Suggestion {
-            action: "Be calm / hopeful.",
-            description: "Find some gentle fun -- the person is ready to explore.\n\
-                \n\
-                Be ready to leave them alone if that is what they want (they \
-                may not say it).",
-        },
-    );
-    suggestions.insert(
-        (Trust::Absent, Mood::_06_Hopeful),
-        Suggestion {
-            action: "Enjoy yourselves.",
-            description: "Make new happy memories -- the person needs them.\n\
-                \n\
-                This is your chance to help them believe life can be good.",
-        },
-    );
-
-    suggestions.insert(
-        (Trust::Present, Mood::_01_Anguished),
-        Suggestion {
-            action: "Be fully present with them",
-            description: "Simply sit quietly with them and allow them to \
-                grieve.\n\
-                \n\
-                Any more than that may overwhelm the person.",
-        },
-    );
-    suggestions.insert(
-        (Trust::Present, Mood::_02_Closed),
-        Suggestion {
-            action: "Remain at a small distance",
-            description: "Leave a gift if you have one, to show that they are \
-                still someone you care for; but allow a little distance -- \
-                your presence may feel like pressure to the person in the \
-                moment.\n\
-                \n\
-                Distance allows them to settle, proximity allows them to feel \
-                cared for.",
-        },
-    );
-    suggestions.insert(
-        (Trust::Present, Mood::_03_Cautious),
-        Suggestion {
-            action: "Occasionally ask if they want something",
-            description: "If you are sure the person wants something (that \
-                isn't harmful), ask \"do you want ____\"?\n\
-                \n\
-                Make sure the conversation is paced such that they are able to \
-                handle it.\n\
-                \n\
-                Don't ask why, don't require an answer -- provide a way \"out\" \
-                (e.g. \"you don't have to answer\"). Asking such questions is \
-                perceived as \"justify yourself\", and may cause them to hate \
-                you (which they may not vocalize).",
-        },
-    );
-    suggestions.insert(
-        (Trust::Present, Mood::_04_Unsettled),
-        Suggestion {
-            action: "Ask, \"would you like to say anything?\", then wait.",
-            description: "Listen, and if it feels right you may ask, \"Would \
-                you like some help with it?\" (if you are able to help).\n\
-                \n\
-                At this stage, you may have some rational conversation, but \
-                nothing that would introduce too much emotional pressure.\n\
-            ",
-        },
-    );
-    suggestions.insert(
-        (Trust::Present, Mood::_05_Calm),
-        Suggestion {
-            action: "Be calm / hopeful.",
-            description: "Find some gentle fun -- the person is ready to explore.",
-        },
-    );
-    suggestions.insert(
-        (Trust::Present, Mood::_06_Hopeful),
-        Suggestion {
-            action: "Enjoy yourselves.",
-            description: "Make new happy memories -- the person needs them.\n\
-                \n\
-                Help them remember life can be good.",
-        },
-    );
-
-    suggestions
-}
-
 #[component]
-fn Inputs(trust: RwSignal<Option<Trust>>, mood: RwSignal<Option<Mood>>) -> impl IntoView {
+fn Inputs(
+    trust: RwSignal<Option<Trust>>,
+    mood_range: RwSignal<Option<MoodRange>>,
+    context: RwSignal<SuggestionContext>,
+    person_profile: RwSignal<PersonProfile>,
+    suggestion_catalog: Memo<SuggestionCatalog>,
+) -> impl IntoView {
+    let age_group = Signal::derive(move || context.get().age_group);
+
     view! {
         <div class=INPUTS_DIV_CLASSES>
             <TrustInput trust />
-            <MoodInput mood />
+            <MoodInput mood_range age_group suggestion_catalog />
+            <ContextInput context />
+            <ProfileInput person_profile />
         </div>
     }
 }
@@ -586,14 +658,24 @@ fn TrustInput(trust: RwSignal<Option<Trust>>) -> impl IntoView {
     let trust_on_input =
         move |ev| *trust.write() = Trust::from_str(event_target_value(&ev).as_str()).ok();
     let trust_clear = move |_| *trust.write() = None;
+    let localizer = l10n::use_localizer();
+    let reading_level = reading_level::use_reading_level();
 
     view! {
         <div class=INPUT_PANEL_CLASSES>
             <p class=FIELD_CLASSES>
-                <span class=FIELD_NAME_CLASSES>"Trust"</span>
-                <span class=FIELD_DESC_CLASSES>"- whether the person trusts you"</span>
+                <span id="trust_label" class=FIELD_NAME_CLASSES>
+                    {l10n::ui(localizer, UiText::TrustField)}
+                </span>
+                <span class=FIELD_DESC_CLASSES>
+                    {l10n::ui(localizer, UiText::TrustFieldDescription)}
+                </span>
             </p>
-            <div class=RADIO_WRAPPER_CLASSES>
+            <div
+                class=RADIO_WRAPPER_CLASSES
+                role="radiogroup"
+                aria-labelledby="trust_label"
+            >
                 {
                     Trust::iter()
                         .map(|trust_variant| {
@@ -609,6 +691,7 @@ fn TrustInput(trust: RwSignal<Option<Trust>>) -> impl IntoView {
                                         class=RADIO_INPUT_CLASSES
                                         name="trust_radio"
                                         id=trust_radio_id.clone()
+                                        aria-keyshortcuts=keyboard_shortcuts::trust_key(trust_variant)
                                         on:input=trust_on_input
                                         prop:value=move || trust_variant.to_string()
                                         prop:checked=move || {
@@ -618,7 +701,11 @@ fn TrustInput(trust: RwSignal<Option<Trust>>) -> impl IntoView {
                                         }
                                     />
                                     <br />
-                                    <span>{trust_variant.to_string()}</span>
+                                    <span>
+                                        {move || localizer.with(|localizer| {
+                                            localizer.trust_name(trust_variant).to_string()
+                                        })}
+                                    </span>
                                 </label>
                             }
                         })
@@ -627,27 +714,46 @@ fn TrustInput(trust: RwSignal<Option<Trust>>) -> impl IntoView {
             </div>
             { move || {
                 let trust = trust.get();
+                let localizer = localizer.read();
                 match trust {
                     Some(trust) => {
                         Either::Left(view! {
                             <button
                                 class=RADIO_CLEAR_CLASSES
+                                aria-keyshortcuts=keyboard_shortcuts::KEY_CLEAR
                                 on:click=trust_clear
                             >
                                 <span class=BUTTON_CROSS_ICON_CLASSES />
-                                "clear"
+                                {localizer.ui(UiText::Clear).to_string()}
                             </button>
 
-                            <p class=DESCRIPTION_CLASSES>
-                                <span class=DESCRIPTION_LABEL_CLASSES>"Indicators:"</span>
-                                <br />
-                                {trust.description()}
-                            </p>
+                            {
+                                localizer
+                                    .trust_description_at(trust, reading_level.get())
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(|(index, text)| {
+                                        let label = (index == 0).then(|| view! {
+                                            <span class=DESCRIPTION_LABEL_CLASSES>
+                                                {localizer.ui(UiText::Indicators).to_string()}
+                                            </span>
+                                            <br />
+                                        });
+                                        view! {
+                                            <p class=DESCRIPTION_CLASSES>
+                                                {label}
+                                                {text.to_string()}
+                                            </p>
+                                        }
+                                    })
+                                    .collect_view()
+                            }
                         })
                     }
                     None => Either::Right(view! {
                         <p class=FIELD_HINT_CLASSES>
-                            "select a value"
+                            {localizer.ui(UiText::TrustHint).to_string()}
+                            <kbd>"A"</kbd>" / "<kbd>"P"</kbd>
                         </p>
                         <p class=DESCRIPTION_CLASSES><br /></p>
                     })
@@ -658,18 +764,74 @@ fn TrustInput(trust: RwSignal<Option<Trust>>) -> impl IntoView {
 }
 
 #[component]
-fn MoodInput(mood: RwSignal<Option<Mood>>) -> impl IntoView {
-    let mood_on_input =
-        move |ev| *mood.write() = Mood::from_str(event_target_value(&ev).as_str()).ok();
-    let mood_clear = move |_| *mood.write() = None;
+fn MoodInput(
+    mood_range: RwSignal<Option<MoodRange>>,
+    age_group: Signal<Option<AgeGroup>>,
+    suggestion_catalog: Memo<SuggestionCatalog>,
+) -> impl IntoView {
+    // When the person's mood isn't clear, the user may select a range of moods.
+    let mood_range_mode = RwSignal::new(false);
+    let mood_on_input = move |ev| {
+        let Ok(mood) = Mood::from_str(event_target_value(&ev).as_str()) else {
+            return;
+        };
+        let mood_range_next = match mood_range.get() {
+            // Selecting a second mood extends the range to it.
+            Some(mood_range_current) if mood_range_mode.get() && mood_range_current.is_single() => {
+                MoodRange::new(mood_range_current.lower(), mood)
+            }
+            _ => MoodRange::from(mood),
+        };
+
+        // Always set the signal, so that clicking on an already checked
+        // checkbox doesn't leave it unchecked.
+        mood_range.set(Some(mood_range_next));
+    };
+    let mood_range_mode_on_input = move |ev| {
+        let mood_range_mode_next = event_target_checked(&ev);
+        mood_range_mode.set(mood_range_mode_next);
+
+        // Collapse to the lower bound, as that is the safer mood to act on.
+        if !mood_range_mode_next {
+            if let Some(mood_range_current) = mood_range.get() {
+                mood_range.set(Some(MoodRange::from(mood_range_current.lower())));
+            }
+        }
+    };
+    let mood_clear = move |_| *mood_range.write() = None;
+    let localizer = l10n::use_localizer();
+    let reading_level = reading_level::use_reading_level();
+
+    let mood_input_type = move || {
+        if mood_range_mode.get() {
+            "checkbox"
+        } else {
+            "radio"
+        }
+    };
+    let mood_input_group_role = move || {
+        if mood_range_mode.get() {
+            "group"
+        } else {
+            "radiogroup"
+        }
+    };
 
     view! {
         <div class=INPUT_PANEL_CLASSES>
             <p class=FIELD_CLASSES>
-                <span class=FIELD_NAME_CLASSES>"Mood"</span>
-                <span class=FIELD_DESC_CLASSES>"- how the person feels"</span>
+                <span id="mood_label" class=FIELD_NAME_CLASSES>
+                    {l10n::ui(localizer, UiText::MoodField)}
+                </span>
+                <span class=FIELD_DESC_CLASSES>
+                    {l10n::ui(localizer, UiText::MoodFieldDescription)}
+                </span>
             </p>
-            <div class=RADIO_WRAPPER_CLASSES>
+            <div
+                class=RADIO_WRAPPER_CLASSES
+                role=mood_input_group_role
+                aria-labelledby="mood_label"
+            >
                 {
                     Mood::iter()
                         .map(|mood_variant| {
@@ -682,15 +844,16 @@ fn MoodInput(mood: RwSignal<Option<Mood>>) -> impl IntoView {
                                     class=RADIO_LABEL_CLASSES
                                 >
                                     <input
-                                        type="radio"
+                                        type=mood_input_type
                                         class=RADIO_INPUT_CLASSES
                                         name="mood_radio"
                                         id=mood_radio_id.clone()
+                                        aria-keyshortcuts=keyboard_shortcuts::mood_key(mood_variant)
                                         on:input=mood_on_input
                                         prop:value=move || mood_variant.to_string()
                                         prop:checked=move || {
-                                            mood.get()
-                                                .map(|mood| mood == mood_variant)
+                                            mood_range.get()
+                                                .map(|mood_range| mood_range.contains(mood_variant))
                                                 .unwrap_or(false)
                                         }
                                     />
@@ -698,7 +861,9 @@ fn MoodInput(mood: RwSignal<Option<Mood>>) -> impl IntoView {
                                     <span>
                                         {rank.to_string()}
                                         <br />
-                                        {mood_variant.to_string()}
+                                        {move || localizer.with(|localizer| {
+                                            localizer.mood_name(mood_variant).to_string()
+                                        })}
                                     </span>
                                 </label>
                             }
@@ -706,35 +871,96 @@ fn MoodInput(mood: RwSignal<Option<Mood>>) -> impl IntoView {
                         .collect_view()
                 }
             </div>
+            <label class=MOOD_RANGE_MODE_CLASSES>
+                <input
+                    type="checkbox"
+                    class=MOOD_RANGE_MODE_INPUT_CLASSES
+                    on:input=mood_range_mode_on_input
+                    prop:checked=move || mood_range_mode.get()
+                />
+                {l10n::ui(localizer, UiText::MoodRangeMode)}
+            </label>
             { move || {
-                let mood = mood.get();
-                match mood {
-                    Some(mood) => {
+                let mood_range = mood_range.get();
+                let age_group = age_group.get();
+                let reading_level = reading_level.get();
+                let localizer = localizer.read();
+                let suggestion_catalog = suggestion_catalog.read();
+                match mood_range {
+                    Some(mood_range) => {
+                        let moods = if mood_range.is_single() {
+                            vec![mood_range.lower()]
+                        } else {
+                            vec![mood_range.lower(), mood_range.upper()]
+                        };
+                        let mood_details = moods
+                            .into_iter()
+                            .map(|mood| {
+                                let mood_heading = (!mood_range.is_single()).then(|| view! {
+                                    <p class=MOOD_HEADING_CLASSES>
+                                        {localizer.mood_name(mood).to_string()}
+                                    </p>
+                                });
+
+                                view! {
+                                    {mood_heading}
+                                    <CharacterDrawing mood trust=None />
+                                    <p class=DESCRIPTION_CLASSES>
+                                        <span class=DESCRIPTION_LABEL_CLASSES>
+                                            {localizer.ui(UiText::Symptoms).to_string()}
+                                        </span>
+                                        <br />
+                                        {
+                                            age_group
+                                                .and_then(|age_group| {
+                                                    suggestion_catalog.symptoms_for_age(mood, age_group)
+                                                })
+                                                .unwrap_or_else(|| localizer.mood_symptoms(mood))
+                                                .to_string()
+                                        }
+                                    </p>
+                                    {
+                                        localizer
+                                            .mood_description_at(mood, reading_level)
+                                            .into_iter()
+                                            .enumerate()
+                                            .map(|(index, text)| {
+                                                let label = (index == 0).then(|| view! {
+                                                    <span class=DESCRIPTION_LABEL_CLASSES>
+                                                        {localizer.ui(UiText::Description).to_string()}
+                                                    </span>
+                                                    <br />
+                                                });
+                                                view! {
+                                                    <p class=DESCRIPTION_CLASSES>
+                                                        {label}
+                                                        {text.to_string()}
+                                                    </p>
+                                                }
+                                            })
+                                            .collect_view()
+                                    }
+                                }
+                            })
+                            .collect_view();
+
                         Either::Left(view! {
                             <button
                                 class=RADIO_CLEAR_CLASSES
+                                aria-keyshortcuts=keyboard_shortcuts::KEY_CLEAR
                                 on:click=mood_clear
                             >
                                 <span class=BUTTON_CROSS_ICON_CLASSES />
-                                "clear"
+                                {localizer.ui(UiText::Clear).to_string()}
                             </button>
 
-                            <p class=DESCRIPTION_CLASSES>
-                                <span class=DESCRIPTION_LABEL_CLASSES>"Symptoms:"</span>
-                                <br />
-                                {mood.symptoms()}
-                            </p>
-                            <p class=DESCRIPTION_CLASSES>
-                                <span class=DESCRIPTION_LABEL_CLASSES>"Description:"</span>
-                                <br />
-                                {mood.summary()}
-                            </p>
-                            <p class=DESCRIPTION_CLASSES>{mood.description()}</p>
+                            {mood_details}
                         })
                     }
                     None => Either::Right(view! {
                         <p class=FIELD_HINT_CLASSES>
-                            "select a value"
+                            {localizer.ui(UiText::MoodHint).to_string()}
+                            <kbd>"1"</kbd>" – "<kbd>"6"</kbd>
                         </p>
                         <p class=DESCRIPTION_CLASSES><br /></p>
                         <p class=DESCRIPTION_CLASSES><br /></p>
@@ -745,45 +971,480 @@ fn MoodInput(mood: RwSignal<Option<Mood>>) -> impl IntoView {
     }
 }
 
+/// Optional details about the situation, which narrow down the suggestion when
+/// the catalog has one specific to them.
+#[component]
+fn ContextInput(context: RwSignal<SuggestionContext>) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let age_group_on_change = move |ev| {
+        context.write().age_group = AgeGroup::from_id(event_target_value(&ev).as_str());
+    };
+    let relationship_on_change = move |ev| {
+        context.write().relationship =
+            Relationship::from_str(event_target_value(&ev).as_str()).ok();
+    };
+    let setting_on_change = move |ev| {
+        context.write().setting = Setting::from_str(event_target_value(&ev).as_str()).ok();
+    };
+
+    view! {
+        <div class=INPUT_PANEL_CLASSES>
+            <p class=FIELD_CLASSES>
+                <span class=FIELD_NAME_CLASSES>
+                    {l10n::ui(localizer, UiText::ContextField)}
+                </span>
+                <span class=FIELD_DESC_CLASSES>
+                    {l10n::ui(localizer, UiText::ContextFieldDescription)}
+                </span>
+            </p>
+            <div class=CONTEXT_FIELDS_CLASSES>
+                <label class=CONTEXT_LABEL_CLASSES>
+                    {l10n::ui(localizer, UiText::AgeGroupLabel)}
+                    <select class=CONTEXT_SELECT_CLASSES on:change=age_group_on_change>
+                        <option
+                            value=""
+                            prop:selected=move || context.get().age_group.is_none()
+                        >
+                            {l10n::ui(localizer, UiText::ContextAny)}
+                        </option>
+                        {
+                            AgeGroup::iter()
+                                .map(|age_group_variant| view! {
+                                    <option
+                                        value=age_group_variant.id()
+                                        prop:selected=move || {
+                                            context.get().age_group == Some(age_group_variant)
+                                        }
+                                    >
+                                        {move || localizer.with(|localizer| {
+                                            localizer.age_group_name(age_group_variant).to_string()
+                                        })}
+                                    </option>
+                                })
+                                .collect_view()
+                        }
+                    </select>
+                </label>
+                <label class=CONTEXT_LABEL_CLASSES>
+                    {l10n::ui(localizer, UiText::RelationshipLabel)}
+                    <select class=CONTEXT_SELECT_CLASSES on:change=relationship_on_change>
+                        <option
+                            value=""
+                            prop:selected=move || context.get().relationship.is_none()
+                        >
+                            {l10n::ui(localizer, UiText::ContextAny)}
+                        </option>
+                        {
+                            Relationship::iter()
+                                .map(|relationship_variant| view! {
+                                    <option
+                                        value=relationship_variant.name()
+                                        prop:selected=move || {
+                                            context.get().relationship == Some(relationship_variant)
+                                        }
+                                    >
+                                        {move || localizer.with(|localizer| {
+                                            localizer.relationship_name(relationship_variant).to_string()
+                                        })}
+                                    </option>
+                                })
+                                .collect_view()
+                        }
+                    </select>
+                </label>
+                <label class=CONTEXT_LABEL_CLASSES>
+                    {l10n::ui(localizer, UiText::SettingLabel)}
+                    <select class=CONTEXT_SELECT_CLASSES on:change=setting_on_change>
+                        <option
+                            value=""
+                            prop:selected=move || context.get().setting.is_none()
+                        >
+                            {l10n::ui(localizer, UiText::ContextAny)}
+                        </option>
+                        {
+                            Setting::iter()
+                                .map(|setting_variant| view! {
+                                    <option
+                                        value=setting_variant.name()
+                                        prop:selected=move || {
+                                            context.get().setting == Some(setting_variant)
+                                        }
+                                    >
+                                        {move || localizer.with(|localizer| {
+                                            localizer.setting_name(setting_variant).to_string()
+                                        })}
+                                    </option>
+                                })
+                                .collect_view()
+                        }
+                    </select>
+                </label>
+            </div>
+        </div>
+    }
+}
+
 #[component]
-fn SuggestionDiv(suggestion: Signal<Option<Suggestion>>) -> impl IntoView {
+fn SuggestionDiv(
+    trust: RwSignal<Option<Trust>>,
+    mood_range: RwSignal<Option<MoodRange>>,
+    guidance: Signal<Option<Guidance>>,
+    /// Profile of the person, to fill in to the suggestion.
+    #[prop(optional)]
+    person_profile: Option<RwSignal<PersonProfile>>,
+    /// Whether to ask if the suggestion helped, when the trust and mood are
+    /// known.
+    #[prop(optional)]
+    with_feedback: bool,
+    /// Whether to let the helper check an action they have in mind, when the
+    /// mood is known.
+    #[prop(optional)]
+    with_action_check: bool,
+    /// Whether to let the helper check what they intend to say, when the mood
+    /// is known.
+    #[prop(optional)]
+    with_phrase_check: bool,
+) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    // Kept outside the suggestion, so that the picked action and the typed
+    // phrase stay while the trust level and mood range change.
+    let helper_action = RwSignal::new(None::<HelperAction>);
+    let phrase = RwSignal::new(String::new());
+    let reading_level = reading_level::use_reading_level();
     let placeholder_classes = move || {
-        if suggestion.get().is_some() {
+        if guidance.get().is_some() {
             SUGGESTION_DIV_PLACEHOLDER_CLASSES
         } else {
             "hidden"
         }
     };
-    view! {
-        <div class=SUGGESTION_DIV_CLASSES>
-            {move || {
-                match suggestion.get() {
-                    Some(suggestion) => {
-                        Either::Left(view! {
-                            <div>
-                                <p class=DESCRIPTION_CLASSES>
-                                    <span class=DESCRIPTION_LABEL_CLASSES>"Action:"</span>
-                                    <br />
-                                    {suggestion.action().to_string()}
-                                </p>
-                                {
-                                    suggestion.description()
-                                        .split("\n\n")
-                                        .map(|line| view! { <p class=DESCRIPTION_CLASSES>{line.to_string()}</p> })
-                                        .collect_view()
-                                }
-                            </div>
+    // Screen readers announce changes to this, so that selecting a trust or
+    // mood level reads out the suggested action.
+    let suggestion_announcement = move || {
+        guidance.get().map(|guidance| {
+            localizer.with(|localizer| {
+                let situation = Situation::new(
+                    trust.get(),
+                    mood_range.get().map(|mood_range| mood_range.lower()),
+                    guidance.context(),
+                );
+                let action = personalize(
+                    localizer,
+                    guidance.suggestion().action(),
+                    &situation,
+                    person_profile,
+                );
+                localizer
+                    .ui(UiText::SuggestedAction)
+                    .replace("{action}", &action)
+            })
+        })
+    };
+
+    // The suggestion is shown in parts, so that the widgets between them that
+    // keep state, e.g. a running wait timer or a half written comment, are
+    // only rebuilt when the trust level or mood range changes, and not when
+    // the locale, reading level, or profile does.
+    let has_guidance = Memo::new(move |_| guidance.with(Option::is_some));
+    let wait = Memo::new(move |_| {
+        guidance.with(|guidance| {
+            guidance
+                .as_ref()
+                .and_then(|guidance| guidance.suggestion().wait())
+        })
+    });
+
+    let suggestion_main = move || {
+        let localizer = localizer.read();
+        let reading_level = reading_level.get();
+        guidance.get().map(|guidance| {
+            let suggestion = guidance.suggestion();
+            let situation = Situation::new(
+                trust.get(),
+                mood_range.get().map(|mood_range| mood_range.lower()),
+                guidance.context(),
+            );
+            let character_drawing = mood_range.get().map(|mood_range| {
+                view! { <CharacterDrawing mood=mood_range.lower() trust=trust.get() /> }
+            });
+
+            view! {
+                {character_drawing}
+                {
+                    localizer.guidance_note(guidance.basis()).map(|note| view! {
+                        <p class=GUIDANCE_NOTE_CLASSES>{note.to_string()}</p>
+                    })
+                }
+                {
+                    (!guidance.context().is_empty()).then(|| {
+                        let context = guidance.context();
+                        let context_matched = localizer
+                            .ui(UiText::ContextMatched)
+                            .replace("{context}", &l10n::context_names(&localizer, context));
+
+                        view! { <p class=GUIDANCE_NOTE_CLASSES>{context_matched}</p> }
+                    })
+                }
+                <p class=DESCRIPTION_CLASSES>
+                    <span class=DESCRIPTION_LABEL_CLASSES>
+                        {localizer.ui(UiText::Action).to_string()}
+                    </span>
+                    <br />
+                    {personalize(&localizer, suggestion.action(), &situation, person_profile)}
+                </p>
+                {
+                    suggestion
+                        .description_at(reading_level)
+                        .into_iter()
+                        .map(|text| view! {
+                            <RichTextView rich_text=RichText::parse(
+                                &personalize(&localizer, text, &situation, person_profile),
+                            ) />
+                        })
+                        .collect_view()
+                }
+            }
+        })
+    };
+    let wait_timer = move || {
+        trust.track();
+        mood_range.track();
+        wait.get().map(|wait| view! { <WaitTimer wait /> })
+    };
+    let suggestion_links = move || {
+        let localizer = localizer.read();
+        guidance.get().map(|guidance| {
+            let suggestion = guidance.suggestion();
+            let principles = (!suggestion.principles().is_empty()).then(|| {
+                let principle_links = suggestion
+                    .principles()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, principle)| {
+                        view! {
+                            {(index > 0).then_some(", ")}
+                            <a class=LINK_CLASSES href=principle_href(*principle)>
+                                {localizer.principle_name(*principle).to_string()}
+                            </a>
+                        }
+                    })
+                    .collect_view();
+
+                view! {
+                    <p class=GUIDANCE_NOTE_CLASSES>
+                        {localizer.ui(UiText::SuggestionPrinciples).to_string()}
+                        " "
+                        {principle_links}
+                    </p>
+                }
+            });
+            let anti_patterns = mood_range
+                .get()
+                .map(|mood_range| AntiPattern::for_mood_range(mood_range).collect::<Vec<_>>())
+                .filter(|anti_patterns| !anti_patterns.is_empty())
+                .map(|anti_patterns| {
+                    let anti_pattern_links = anti_patterns
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, anti_pattern)| {
+                            view! {
+                                {(index > 0).then_some(", ")}
+                                <a class=LINK_CLASSES href=anti_pattern_href(anti_pattern)>
+                                    {localizer.anti_pattern_name(anti_pattern).to_string()}
+                                </a>
+                            }
                         })
+                        .collect_view();
+
+                    view! {
+                        <p class=GUIDANCE_NOTE_CLASSES>
+                            {localizer.ui(UiText::SuggestionAntiPatterns).to_string()}
+                            " "
+                            {anti_pattern_links}
+                        </p>
                     }
-                    None => {
-                        Either::Right(view! {
-                            <span class=placeholder_classes>
-                                "Please select if the person trusts you in this moment, and the mood they are in."
-                            </span>
+                });
+
+            view! {
+                {principles}
+                {anti_patterns}
+            }
+        })
+    };
+    // Only the sayings within follow the locale, so that the section stays
+    // open while the locale changes.
+    let what_to_say = move || {
+        trust
+            .get()
+            .zip(mood_range.get())
+            .map(|(trust, mood_range)| {
+                let sayings = move || {
+                    let localizer = localizer.read();
+                    SayingKind::iter()
+                        .map(|saying_kind| {
+                            let saying_items = localizer
+                                .sayings(trust, mood_range.lower(), saying_kind)
+                                .into_iter()
+                                .map(|saying| view! { <li>{saying.to_string()}</li> })
+                                .collect_view();
+
+                            view! {
+                                <span class=DESCRIPTION_LABEL_CLASSES>
+                                    {localizer.ui(saying_kind.ui_text()).to_string()}
+                                </span>
+                                <ul class=WHAT_TO_SAY_LIST_CLASSES>{saying_items}</ul>
+                            }
                         })
+                        .collect_view()
+                };
+
+                view! {
+                    <details class=WHAT_TO_SAY_CLASSES>
+                        <summary class=WHAT_TO_SAY_SUMMARY_CLASSES>
+                            {l10n::ui(localizer, UiText::WhatToSay)}
+                        </summary>
+                        {sayings}
+                    </details>
+                }
+            })
+    };
+    let suggestion_notes = move || {
+        let localizer = localizer.read();
+        let reading_level = reading_level.get();
+        guidance.get().map(|guidance| {
+            let situation_upper = Situation::new(
+                trust.get(),
+                mood_range.get().map(|mood_range| mood_range.upper()),
+                guidance.context(),
+            );
+            let upper_bound = mood_range.get().zip(guidance.upper_bound().cloned()).map(
+                |(mood_range, upper_bound)| view! {
+                    <p class=DESCRIPTION_CLASSES>
+                        <span class=DESCRIPTION_LABEL_CLASSES>
+                            {
+                                localizer
+                                    .ui(UiText::UpperBound)
+                                    .replace("{mood}", localizer.mood_name(mood_range.upper()))
+                            }
+                        </span>
+                        <br />
+                        {personalize(&localizer, upper_bound.action(), &situation_upper, person_profile)}
+                    </p>
+                    {
+                        upper_bound
+                            .description_at(reading_level)
+                            .into_iter()
+                            .map(|text| view! {
+                                <RichTextView rich_text=RichText::parse(
+                                    &personalize(&localizer, text, &situation_upper, person_profile),
+                                ) />
+                            })
+                            .collect_view()
                     }
+                },
+            );
+            let profile_notes = person_profile.map(|person_profile| {
+                person_profile.with(|person_profile| {
+                    [
+                        (ProfilePlaceholder::Triggers, UiText::ProfileAvoid, "{triggers}"),
+                        (ProfilePlaceholder::Backfired, UiText::ProfileBackfiredNote, "{backfired}"),
+                    ]
+                    .into_iter()
+                    .filter_map(|(profile_placeholder, note, placeholder)| {
+                        person_profile.value(profile_placeholder).map(|value| {
+                            let note = localizer.ui(note).replace(placeholder, &value);
+                            view! { <p class=GUIDANCE_NOTE_CLASSES>{note}</p> }
+                        })
+                    })
+                    .collect_view()
+                })
+            });
+
+            view! {
+                {upper_bound}
+                {profile_notes}
+            }
+        })
+    };
+    let action_check = move || {
+        mood_range
+            .get()
+            .filter(|_| with_action_check)
+            .map(|mood_range| {
+                view! {
+                    <ActionCheck trust=trust.get() mood_range helper_action />
+                }
+            })
+    };
+    let phrase_checker = move || {
+        mood_range
+            .get()
+            .filter(|_| with_phrase_check)
+            .map(|mood_range| view! { <PhraseChecker mood_range phrase /> })
+    };
+    let feedback = move || {
+        trust
+            .get()
+            .zip(mood_range.get())
+            .filter(|_| with_feedback)
+            .map(|(trust, mood_range)| {
+                view! {
+                    <FeedbackWidget trust mood=mood_range.lower() />
+                }
+            })
+    };
+
+    view! {
+        <div class=SUGGESTION_DIV_CLASSES>
+            <p class="sr-only" role="status" aria-live="polite">
+                {suggestion_announcement}
+            </p>
+            {move || {
+                if has_guidance.get() {
+                    Either::Left(view! {
+                        <div>
+                            {suggestion_main}
+                            {wait_timer}
+                            {suggestion_links}
+                            {what_to_say}
+                            {suggestion_notes}
+                            {action_check}
+                            {phrase_checker}
+                            {feedback}
+                        </div>
+                    })
+                } else {
+                    Either::Right(view! {
+                        <span class=placeholder_classes>
+                            {l10n::ui(localizer, UiText::SuggestionPlaceholder)}
+                        </span>
+                    })
                 }
             }}
         </div>
     }
 }
+
+/// Returns the suggestion text rendered for the situation, with the profile
+/// filled in, or the fallback text when there is no profile.
+fn personalize(
+    localizer: &Localizer,
+    text: &str,
+    situation: &Situation,
+    person_profile: Option<RwSignal<PersonProfile>>,
+) -> String {
+    match person_profile {
+        Some(person_profile) => person_profile
+            .with(|person_profile| localizer.personalize(text, situation, person_profile)),
+        None => localizer.personalize(text, situation, &PersonProfile::new()),
+    }
+}
+
+/// Returns the link to a page of this site, e.g. `"principles"`, under the
+/// `SITE_PREFIX` that the site is built with.
+fn site_href(path: &str) -> String {
+    match option_env!("SITE_PREFIX") {
+        Some(site_prefix) if !site_prefix.is_empty() => format!("/{site_prefix}/{path}"),
+        _ => format!("/{path}"),
+    }
+}
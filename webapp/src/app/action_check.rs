@@ -0,0 +1,130 @@
+use leptos::{
+    component,
+    prelude::{
+        event_target_value, ClassAttribute, CollectView, ElementChild, Get, IntoView, OnAttribute,
+        PropAttribute, Read, RwSignal, Set,
+    },
+    view,
+};
+use tears::{HelperAction, MoodRange, Trust, UiText};
+
+use super::{
+    l10n, CONTEXT_LABEL_CLASSES, CONTEXT_SELECT_CLASSES, DESCRIPTION_CLASSES,
+    DESCRIPTION_LABEL_CLASSES, GUIDANCE_NOTE_CLASSES,
+};
+
+const ACTION_CHECK_CLASSES: &str = "\
+    mt-3 \
+    pt-3 \
+    border-t \
+    border-tears-divider \
+";
+
+const ACTION_CHECK_VERDICT_CLASSES: &str = "\
+    block \
+    pt-3 \
+    font-bold \
+";
+
+const ACTION_CHECK_ALTERNATIVES_CLASSES: &str = "\
+    list-disc \
+    ps-8 \
+";
+
+/// Lets the helper pick an action they have in mind, and shows whether it is
+/// appropriate for the trust level and mood range, with safer alternatives.
+#[component]
+pub fn ActionCheck(
+    trust: Option<Trust>,
+    mood_range: MoodRange,
+    /// The action that the helper picked, kept by the caller so that it
+    /// survives changes to the trust level and mood range.
+    helper_action: RwSignal<Option<HelperAction>>,
+) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let helper_action_on_change = move |ev| {
+        helper_action.set(HelperAction::from_id(event_target_value(&ev).as_str()));
+    };
+
+    view! {
+        <div class=ACTION_CHECK_CLASSES>
+            <label class=CONTEXT_LABEL_CLASSES>
+                {l10n::ui(localizer, UiText::ActionCheckLabel)}
+                <select class=CONTEXT_SELECT_CLASSES on:change=helper_action_on_change>
+                    <option value="" prop:selected=move || helper_action.get().is_none()>
+                        {l10n::ui(localizer, UiText::ActionCheckPlaceholder)}
+                    </option>
+                    {
+                        HelperAction::iter()
+                            .map(|helper_action_variant| view! {
+                                <option
+                                    value=helper_action_variant.id()
+                                    prop:selected=move || {
+                                        helper_action.get() == Some(helper_action_variant)
+                                    }
+                                >
+                                    {move || {
+                                        localizer
+                                            .read()
+                                            .helper_action_name(helper_action_variant)
+                                            .to_string()
+                                    }}
+                                </option>
+                            })
+                            .collect_view()
+                    }
+                </select>
+            </label>
+            {move || {
+                let localizer = localizer.read();
+                helper_action.get().map(|helper_action| {
+                    let verdict = helper_action.verdict_cautious(trust, mood_range);
+                    let alternatives = helper_action.alternatives(trust, mood_range);
+                    let alternatives = (!alternatives.is_empty()).then(|| {
+                        let alternatives = alternatives
+                            .into_iter()
+                            .map(|alternative| {
+                                let verdict_alternative =
+                                    alternative.verdict_cautious(trust, mood_range);
+                                view! {
+                                    <li>
+                                        {localizer.helper_action_name(alternative).to_string()}
+                                        " ("
+                                        {localizer.ui(verdict_alternative.ui_text()).to_string()}
+                                        ")"
+                                    </li>
+                                }
+                            })
+                            .collect_view();
+
+                        view! {
+                            <div class=DESCRIPTION_CLASSES>
+                                <span class=DESCRIPTION_LABEL_CLASSES>
+                                    {localizer.ui(UiText::ActionCheckAlternatives).to_string()}
+                                </span>
+                                <ul class=ACTION_CHECK_ALTERNATIVES_CLASSES>{alternatives}</ul>
+                            </div>
+                        }
+                    });
+
+                    view! {
+                        <span class=ACTION_CHECK_VERDICT_CLASSES>
+                            {localizer.ui(verdict.ui_text()).to_string()}
+                        </span>
+                        <p class=DESCRIPTION_CLASSES>
+                            {localizer.helper_action_reason(helper_action, verdict).map(str::to_string)}
+                        </p>
+                        {
+                            trust.is_none().then(|| view! {
+                                <p class=GUIDANCE_NOTE_CLASSES>
+                                    {localizer.ui(UiText::ActionCheckTrustUnknown).to_string()}
+                                </p>
+                            })
+                        }
+                        {alternatives}
+                    }
+                })
+            }}
+        </div>
+    }
+}
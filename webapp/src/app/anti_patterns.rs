@@ -0,0 +1,118 @@
+use leptos::{
+    component,
+    prelude::{ClassAttribute, CollectView, ElementChild, GlobalAttributes, IntoView, Read},
+    view,
+};
+use tears::{AntiPattern, UiText};
+
+use super::{l10n, site_href, DESCRIPTION_CLASSES, FIELD_DESC_CLASSES, FIELD_NAME_CLASSES};
+
+const ANTI_PATTERNS_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-8 \
+";
+
+const ANTI_PATTERN_CLASSES: &str = "\
+    bg-tears-panel \
+    border-2 \
+    border-tears-outline \
+    rounded-lg \
+    p-8 \
+";
+
+const ANTI_PATTERN_NAME_CLASSES: &str = "\
+    font-bold \
+    text-3xl \
+";
+
+const ANTI_PATTERN_LABEL_CLASSES: &str = "\
+    font-bold \
+";
+
+const ANTI_PATTERN_EXAMPLE_CLASSES: &str = "\
+    py-3 \
+    italic \
+    text-tears-muted \
+";
+
+/// Returns the link to an anti-pattern on the anti-patterns page.
+pub fn anti_pattern_href(anti_pattern: AntiPattern) -> String {
+    format!(
+        "{}#{}",
+        site_href("anti-patterns"),
+        anti_pattern_anchor(anti_pattern)
+    )
+}
+
+/// Returns the element ID of an anti-pattern on the anti-patterns page.
+fn anti_pattern_anchor(anti_pattern: AntiPattern) -> String {
+    format!("anti-pattern-{}", anti_pattern.id())
+}
+
+/// Lists every anti-pattern, with the moods where it backfires, why, and what
+/// to do instead.
+#[component]
+pub fn AntiPatternsPage() -> impl IntoView {
+    let localizer = l10n::use_localizer();
+
+    view! {
+        <div class=ANTI_PATTERNS_CLASSES>
+            <div>
+                <h2 class=FIELD_NAME_CLASSES>{l10n::ui(localizer, UiText::AntiPatterns)}</h2>
+                <span class=FIELD_DESC_CLASSES>
+                    {l10n::ui(localizer, UiText::AntiPatternsDescription)}
+                </span>
+            </div>
+            {
+                AntiPattern::iter()
+                    .map(|anti_pattern| view! {
+                        <section class=ANTI_PATTERN_CLASSES id=anti_pattern_anchor(anti_pattern)>
+                            {move || {
+                                let localizer = localizer.read();
+                                let moods = anti_pattern
+                                    .moods()
+                                    .iter()
+                                    .map(|mood| localizer.mood_name(*mood))
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
+
+                                view! {
+                                    <h3 class=ANTI_PATTERN_NAME_CLASSES>
+                                        {localizer.anti_pattern_name(anti_pattern).to_string()}
+                                    </h3>
+                                    <p class=ANTI_PATTERN_EXAMPLE_CLASSES>
+                                        {localizer.ui(UiText::AntiPatternExample).to_string()}
+                                        " "
+                                        {localizer.anti_pattern_example(anti_pattern).to_string()}
+                                    </p>
+                                    <p class=DESCRIPTION_CLASSES>
+                                        <span class=ANTI_PATTERN_LABEL_CLASSES>
+                                            {localizer.ui(UiText::AntiPatternMoods).to_string()}
+                                        </span>
+                                        " "
+                                        {moods}
+                                    </p>
+                                    <p class=DESCRIPTION_CLASSES>
+                                        <span class=ANTI_PATTERN_LABEL_CLASSES>
+                                            {localizer.ui(UiText::AntiPatternReason).to_string()}
+                                        </span>
+                                        " "
+                                        {localizer.anti_pattern_reason(anti_pattern).to_string()}
+                                    </p>
+                                    <p class=DESCRIPTION_CLASSES>
+                                        <span class=ANTI_PATTERN_LABEL_CLASSES>
+                                            {localizer.ui(UiText::AntiPatternInstead).to_string()}
+                                        </span>
+                                        " "
+                                        {localizer.anti_pattern_instead(anti_pattern).to_string()}
+                                    </p>
+                                }
+                            }}
+                        </section>
+                    })
+                    .collect_view()
+            }
+        </div>
+    }
+}
@@ -0,0 +1,62 @@
+use leptos::{
+    logging,
+    prelude::{expect_context, provide_context, Memo, SharedValue, With},
+};
+use tears::{CatalogFile, Localizer, SuggestionCatalog};
+
+/// Returns a memo of the suggestion catalog in the localizer's locale, and
+/// provides it as context to the components within the calling component.
+///
+/// The server reads the catalog file each time a page is rendered, so saved
+/// changes are shown straight away, and the page in the browser uses the
+/// same catalog. The catalog built into `tears` is used when there is no
+/// catalog file, or it is invalid.
+pub fn provide_suggestion_catalog(localizer: Memo<Localizer>) -> Memo<SuggestionCatalog> {
+    let catalog_toml = SharedValue::new(catalog_toml_load).into_inner();
+    let suggestion_catalog_base = catalog_toml
+        .and_then(|catalog_toml| {
+            CatalogFile::parse(&catalog_toml)
+                .map_err(|error| logging::error!("{error}"))
+                .ok()
+        })
+        .map(|catalog_file| SuggestionCatalog::from_catalog_file(&catalog_file))
+        .unwrap_or_else(SuggestionCatalog::builtin);
+    let suggestion_catalog = Memo::new(move |_| {
+        localizer.with(|localizer| localizer.suggestion_catalog(&suggestion_catalog_base))
+    });
+    provide_context(suggestion_catalog);
+
+    suggestion_catalog
+}
+
+/// Returns the suggestion catalog provided by [`provide_suggestion_catalog`].
+pub fn use_suggestion_catalog() -> Memo<SuggestionCatalog> {
+    expect_context::<Memo<SuggestionCatalog>>()
+}
+
+/// Returns the catalog file that the server serves, if any.
+#[cfg(feature = "ssr")]
+fn catalog_toml_load() -> Option<String> {
+    use super::editor::{catalog_store, CatalogStoreError};
+
+    let catalog_toml = catalog_store::load().and_then(|catalog_file| {
+        catalog_file
+            .to_toml()
+            .map_err(|error| CatalogStoreError::Update { error })
+    });
+    match catalog_toml {
+        Ok(catalog_toml) => Some(catalog_toml),
+        Err(CatalogStoreError::PathUnset) => None,
+        Err(error) => {
+            logging::error!("{}", super::editor::error_message(&error));
+            None
+        }
+    }
+}
+
+/// Returns the catalog file that the server serves, which static builds do
+/// not have.
+#[cfg(not(feature = "ssr"))]
+fn catalog_toml_load() -> Option<String> {
+    None
+}
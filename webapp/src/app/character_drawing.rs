@@ -0,0 +1,177 @@
+use leptos::{
+    component,
+    prelude::{ClassAttribute, CustomAttribute, ElementChild, IntoView, With},
+    view,
+};
+use tears::{Mood, Trust};
+
+use super::l10n;
+
+const CHARACTER_DRAWING_CLASSES: &str = "\
+    block \
+    w-48 \
+    h-36 \
+    py-2 \
+    text-tears-muted \
+";
+
+/// Strokes that make up the drawing of the person for a given mood.
+///
+/// All coordinates are within the `0 0 160 112` view box, with the ground at
+/// `y = 106`.
+struct Pose {
+    /// Centre of the head, `(cx, cy)`.
+    head: (u8, u8),
+    body: &'static str,
+    arms: &'static str,
+    legs: &'static str,
+    eyes: &'static str,
+    /// Eyebrows, if the mood is shown through them.
+    brows: &'static str,
+    mouth: &'static str,
+    /// Tear drops, if any.
+    tears: &'static str,
+}
+
+impl Pose {
+    fn new(mood: Mood) -> Self {
+        match mood {
+            // Curled up on the ground, head down, eyes shut.
+            Mood::_01_Anguished => Pose {
+                head: (46, 62),
+                body: "M40 72 Q30 86 38 104",
+                arms: "M40 78 Q52 86 58 86",
+                legs: "M38 104 L62 104 M38 104 L56 84 L62 104",
+                eyes: "M40 64 Q42.5 66 45 64 M48 64 Q50.5 66 53 64",
+                brows: "",
+                mouth: "M42 70 Q46.5 67 51 70",
+                tears: "M41 68 L41 71 M52 68 L52 71",
+            },
+            // Slouched, arms limp, blank stare.
+            Mood::_02_Closed => Pose {
+                head: (50, 31),
+                body: "M50 43 Q52 58 50 74",
+                arms: "M50 48 L43 74 M50 48 L57 74",
+                legs: "M50 74 L42 104 M50 74 L58 104",
+                eyes: "M45 30 L45 30 M55 30 L55 30",
+                brows: "",
+                mouth: "M46 36 L54 36",
+                tears: "",
+            },
+            // Arms crossed, eyes narrowed and glancing aside.
+            Mood::_03_Cautious => Pose {
+                head: (50, 28),
+                body: "M50 40 L50 74",
+                arms: "M50 46 L40 56 L58 58 M50 46 L60 56 L42 58",
+                legs: "M50 74 L42 104 M50 74 L58 104",
+                eyes: "M46 26 L46 26 M56 26 L56 26",
+                brows: "M42 24 L48 24 M52 24 L58 24",
+                mouth: "M47 33 L53 33",
+                tears: "",
+            },
+            // One hand raised, asking for justification.
+            Mood::_04_Unsettled => Pose {
+                head: (50, 28),
+                body: "M50 40 L50 74",
+                arms: "M50 46 L62 52 L68 42 M50 46 L42 70",
+                legs: "M50 74 L42 104 M50 74 L58 104",
+                eyes: "M45 26 L45 26 M55 26 L55 26",
+                brows: "M42 21 L48 20 M52 19 L58 21",
+                mouth: "M45 34 Q47.5 32 50 34 Q52.5 36 55 34",
+                tears: "",
+            },
+            // Upright and relaxed, with a small, conscious smile.
+            Mood::_05_Calm => Pose {
+                head: (50, 28),
+                body: "M50 40 L50 74",
+                arms: "M50 46 L42 70 M50 46 L58 70",
+                legs: "M50 74 L42 104 M50 74 L58 104",
+                eyes: "M45 26 L45 26 M55 26 L55 26",
+                brows: "",
+                mouth: "M45 33 Q50 35 55 33",
+                tears: "",
+            },
+            // Arms open, smiling without thinking about it.
+            Mood::_06_Hopeful => Pose {
+                head: (50, 27),
+                body: "M50 39 L50 74",
+                arms: "M50 46 L36 38 M50 46 L64 38",
+                legs: "M50 74 L42 104 M50 74 L58 104",
+                eyes: "M42 26 Q45 23 48 26 M52 26 Q55 23 58 26",
+                brows: "",
+                mouth: "M44 31 Q50 38 56 31",
+                tears: "",
+            },
+        }
+    }
+}
+
+/// Returns the `transform` for the helper figure, which stands closer to the
+/// person when they trust the helper.
+fn helper_transform(trust: Trust) -> &'static str {
+    match trust {
+        Trust::Absent => "translate(138 32) scale(0.7)",
+        Trust::Present => "translate(92 0)",
+    }
+}
+
+/// Drawing of a person in the given mood, with the helper standing nearby
+/// when the trust level is known.
+///
+/// The mood's localized symptoms are used as the drawing's alternative text.
+#[component]
+pub fn CharacterDrawing(mood: Mood, trust: Option<Trust>) -> impl IntoView {
+    let Pose {
+        head: (head_cx, head_cy),
+        body,
+        arms,
+        legs,
+        eyes,
+        brows,
+        mouth,
+        tears,
+    } = Pose::new(mood);
+
+    let localizer = l10n::use_localizer();
+    let aria_label = move || localizer.with(|localizer| localizer.mood_symptoms(mood).to_string());
+
+    let helper = trust.map(|trust| {
+        let helper_opacity = match trust {
+            Trust::Absent => "0.5",
+            Trust::Present => "1",
+        };
+
+        view! {
+            <g transform=helper_transform(trust) opacity=helper_opacity>
+                <circle cx="0" cy="38" r="10" />
+                <path d="M0 48 L0 78 M0 78 L-7 104 M0 78 L7 104 M0 54 L-8 74 M0 54 L8 74" />
+                <path d="M-4 36 L-4 36 M4 36 L4 36 M-3 42 L3 42" />
+            </g>
+        }
+    });
+
+    view! {
+        <svg
+            class=CHARACTER_DRAWING_CLASSES
+            viewBox="0 0 160 112"
+            role="img"
+            aria-label=aria_label
+            fill="none"
+            stroke="currentColor"
+            stroke-width="2.5"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+        >
+            <path d="M8 106 L152 106" opacity="0.4" />
+            <circle cx=head_cx.to_string() cy=head_cy.to_string() r="12" />
+            <path d=body />
+            <path d=arms />
+            <path d=legs />
+            <path d=eyes />
+            <path d=brows />
+            <path d=mouth />
+            <path d=tears class="stroke-tears-accent" />
+            {helper}
+        </svg>
+    }
+}
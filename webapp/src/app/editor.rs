@@ -0,0 +1,521 @@
+use std::borrow::Cow;
+
+use leptos::{
+    component,
+    either::Either,
+    prelude::{
+        event_target_checked, event_target_value, ClassAttribute, CollectView, Effect,
+        ElementChild, Get, GetUntracked, GlobalAttributes, IntoView, Memo, OnAttribute,
+        PropAttribute, Read, Resource, RwSignal, ServerAction, ServerFnError, Set, Signal, Suspend,
+        Suspense, Write,
+    },
+    server, view,
+};
+use tears::{
+    CatalogCell, CatalogRevision, Guidance, GuidanceBasis, MoodRange, Principle, Suggestion,
+};
+
+use super::{
+    site_href, SuggestionDiv, FIELD_DESC_CLASSES, FIELD_NAME_CLASSES, HOMEPAGE_CLASSES,
+    LINK_CLASSES,
+};
+
+#[cfg(feature = "ssr")]
+pub use self::auth::{editor_auth, editor_path, EDITORS_ENV, EDITOR_ALLOW_HTTP_ENV};
+#[cfg(feature = "ssr")]
+pub use self::catalog_store::{CatalogStoreError, CATALOG_FILE_ENV};
+
+#[cfg(feature = "ssr")]
+use self::auth::EditorName;
+pub use self::feedback_report::FeedbackReportPage;
+
+use self::revision_history::RevisionHistory;
+
+#[cfg(feature = "ssr")]
+mod auth;
+#[cfg(feature = "ssr")]
+pub(super) mod catalog_store;
+mod feedback_report;
+mod revision_history;
+
+const EDITOR_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-8 \
+";
+
+const EDITOR_CELL_CLASSES: &str = "\
+    bg-tears-panel \
+    border-2 \
+    border-tears-outline \
+    rounded-lg \
+    p-8 \
+";
+
+const EDITOR_FORM_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-2 \
+    w-2/5 \
+    min-w-min \
+";
+
+const EDITOR_LABEL_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-1 \
+    text-tears-subtle \
+";
+
+const EDITOR_INPUT_CLASSES: &str = "\
+    bg-tears-control \
+    text-tears-control-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-panel \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+const EDITOR_PRINCIPLES_CLASSES: &str = "\
+    flex \
+    flex-wrap \
+    gap-x-4 \
+    text-tears-subtle \
+";
+
+const EDITOR_PRINCIPLE_CLASSES: &str = "\
+    flex \
+    items-center \
+    gap-1 \
+";
+
+const EDITOR_ERRORS_CLASSES: &str = "\
+    list-disc \
+    ps-6 \
+    text-tears-fg \
+    font-bold \
+";
+
+const EDITOR_SAVE_CLASSES: &str = "\
+    self-start \
+    bg-tears-control \
+    text-tears-control-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-4 \
+    py-1 \
+    \
+    disabled:opacity-50 \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-panel \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+const EDITOR_STATUS_CLASSES: &str = "\
+    italic \
+    text-tears-muted \
+";
+
+/// Returns every suggestion in the catalog file.
+#[server(prefix = "/api/editor", endpoint = "catalog")]
+pub async fn editor_catalog() -> Result<Vec<(CatalogCell, Suggestion)>, ServerFnError> {
+    let catalog_file = catalog_store::load().map_err(server_fn_error)?;
+
+    Ok(catalog_file
+        .iter()
+        .map(|(cell, suggestion)| (cell, suggestion.clone()))
+        .collect())
+}
+
+/// Saves a suggestion to the catalog file, and returns it.
+///
+/// The change is recorded in the history with the signed in editor as the
+/// author.
+#[server(prefix = "/api/editor", endpoint = "save")]
+pub async fn editor_save(
+    cell: CatalogCell,
+    suggestion: Suggestion,
+    message: String,
+) -> Result<Suggestion, ServerFnError> {
+    let editor_name = editor_name().await?;
+    catalog_store::save(cell, suggestion.clone(), &editor_name, &message)
+        .map_err(server_fn_error)?;
+
+    Ok(suggestion)
+}
+
+/// Returns the revisions of a suggestion, newest first.
+#[server(prefix = "/api/editor", endpoint = "history")]
+pub async fn editor_history(cell: CatalogCell) -> Result<Vec<CatalogRevision>, ServerFnError> {
+    let catalog_history = catalog_store::load_history().map_err(server_fn_error)?;
+
+    Ok(catalog_history.iter_cell(cell).rev().cloned().collect())
+}
+
+/// Restores the suggestion that a revision changed to what it was after that
+/// revision, or before it if `is_previous`, and returns it.
+#[server(prefix = "/api/editor", endpoint = "restore")]
+pub async fn editor_restore(
+    revision_id: u64,
+    is_previous: bool,
+    message: String,
+) -> Result<Suggestion, ServerFnError> {
+    let editor_name = editor_name().await?;
+    catalog_store::restore(revision_id, is_previous, &editor_name, &message)
+        .map_err(server_fn_error)
+}
+
+/// Returns the name of the signed in editor.
+#[cfg(feature = "ssr")]
+async fn editor_name() -> Result<String, ServerFnError> {
+    let axum::Extension(EditorName(editor_name)) =
+        leptos_axum::extract::<axum::Extension<EditorName>>().await?;
+    Ok(editor_name)
+}
+
+/// Returns a `ServerFnError` with the error and each of its sources, so the
+/// editor shows why a request failed.
+#[cfg(feature = "ssr")]
+pub(super) fn server_fn_error(error: impl std::error::Error) -> ServerFnError {
+    ServerFnError::new(error_message(&error))
+}
+
+/// Returns the error and each of its sources, separated by spaces.
+pub(super) fn error_message(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(error_source) = source {
+        message.push(' ');
+        message.push_str(&error_source.to_string());
+        source = error_source.source();
+    }
+    message
+}
+
+/// Returns the wait of a suggestion as text for editing, which is empty when
+/// there is no wait.
+fn wait_seconds_text(suggestion: &Suggestion) -> String {
+    suggestion
+        .wait_seconds
+        .map(|wait_seconds| wait_seconds.to_string())
+        .unwrap_or_default()
+}
+
+/// Parses the wait being edited, which is `None` when left empty.
+///
+/// Returns a message for the editor when it is not a whole number of seconds.
+fn wait_seconds_parse(wait_seconds_text: &str) -> Result<Option<u32>, String> {
+    let wait_seconds_text = wait_seconds_text.trim();
+    if wait_seconds_text.is_empty() {
+        return Ok(None);
+    }
+    wait_seconds_text.parse().map(Some).map_err(|_| {
+        format!("The wait `{wait_seconds_text}` must be a whole number of seconds, or left out.")
+    })
+}
+
+/// Returns the heading of a cell in the editor, e.g. `"Absent · Closed"`.
+fn cell_heading(cell: CatalogCell) -> String {
+    match cell {
+        CatalogCell::Suggestion(trust, mood) => format!("{} · {}", trust.name(), mood.name()),
+        CatalogCell::ContextSuggestion(trust, mood, context) => {
+            format!("{} · {} · {context}", trust.name(), mood.name())
+        }
+        CatalogCell::TrustSuggestion(trust) => format!("{} · mood unknown", trust.name()),
+    }
+}
+
+/// Lists every suggestion in the catalog for editing.
+///
+/// This is only usable when served by the `ssr` build, which provides the
+/// server functions and requires editors to sign in.
+#[component]
+pub fn EditorPage() -> impl IntoView {
+    // Static builds have no server to load from or save to.
+    if cfg!(feature = "csr") {
+        return Either::Left(view! {
+            <p class=EDITOR_STATUS_CLASSES>
+                "The editor is only available when served by the `tears` server."
+            </p>
+        });
+    }
+
+    let cells = Resource::new(|| (), |()| editor_catalog());
+    let feedback_href = site_href("editor/feedback");
+
+    Either::Right(view! {
+        <div class=EDITOR_CLASSES>
+            <div>
+                <h2 class=FIELD_NAME_CLASSES>"Suggestion editor"</h2>
+                <span class=FIELD_DESC_CLASSES>
+                    "Changes are saved to the catalog file on the server, and are shown to readers straight away. "
+                    <a class=LINK_CLASSES href=feedback_href>
+                        "See what readers thought of each suggestion."
+                    </a>
+                </span>
+            </div>
+            <Suspense fallback=|| view! { <p class=EDITOR_STATUS_CLASSES>"Loading the catalog…"</p> }>
+                {move || Suspend::new(async move {
+                    match cells.await {
+                        Ok(cells) => Either::Left(
+                            cells
+                                .into_iter()
+                                .map(|(cell, suggestion)| view! { <EditorCell cell suggestion /> })
+                                .collect_view(),
+                        ),
+                        Err(error) => Either::Right(view! {
+                            <p class=EDITOR_STATUS_CLASSES>{error.to_string()}</p>
+                        }),
+                    }
+                })}
+            </Suspense>
+        </div>
+    })
+}
+
+/// Edits one suggestion, with a preview.
+#[component]
+fn EditorCell(cell: CatalogCell, suggestion: Suggestion) -> impl IntoView {
+    let action = RwSignal::new(suggestion.action().to_string());
+    let description = RwSignal::new(suggestion.description().to_string());
+    let glance = RwSignal::new(suggestion.glance().unwrap_or_default().to_string());
+    let extended = RwSignal::new(suggestion.extended().unwrap_or_default().to_string());
+    let principles = RwSignal::new(suggestion.principles().to_vec());
+    let wait_seconds = RwSignal::new(wait_seconds_text(&suggestion));
+    // Optional texts that are left empty are not saved.
+    let text_optional = |text: String| (!text.trim().is_empty()).then_some(Cow::Owned(text));
+    let draft = Memo::new(move |_| Suggestion {
+        action: Cow::Owned(action.get()),
+        description: Cow::Owned(description.get()),
+        glance: text_optional(glance.get()),
+        extended: text_optional(extended.get()),
+        principles: principles.get(),
+        wait_seconds: wait_seconds_parse(&wait_seconds.read()).unwrap_or_default(),
+    });
+    let errors = Memo::new(move |_| draft.read().validate());
+    // The draft leaves out a wait that doesn't parse, so this is shown and
+    // blocks saving separately.
+    let wait_seconds_error = Memo::new(move |_| wait_seconds_parse(&wait_seconds.read()).err());
+
+    let message = RwSignal::new(String::new());
+    let saved = RwSignal::new(suggestion);
+    // Incremented whenever a revision is recorded, to reload the history.
+    let history_version = RwSignal::new(0u32);
+
+    let save = ServerAction::<EditorSave>::new();
+    let restore = ServerAction::<EditorRestore>::new();
+    Effect::new(move |_| {
+        if let Some(Ok(suggestion_saved)) = save.value().get() {
+            saved.set(suggestion_saved);
+            message.set(String::new());
+            *history_version.write() += 1;
+        }
+    });
+    Effect::new(move |_| {
+        if let Some(Ok(suggestion_restored)) = restore.value().get() {
+            action.set(suggestion_restored.action().to_string());
+            description.set(suggestion_restored.description().to_string());
+            glance.set(suggestion_restored.glance().unwrap_or_default().to_string());
+            extended.set(
+                suggestion_restored
+                    .extended()
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            principles.set(suggestion_restored.principles().to_vec());
+            wait_seconds.set(wait_seconds_text(&suggestion_restored));
+            saved.set(suggestion_restored);
+            message.set(String::new());
+            *history_version.write() += 1;
+        }
+    });
+
+    let is_pending = move || save.pending().get() || restore.pending().get();
+    let is_save_disabled = move || {
+        !errors.read().is_empty()
+            || wait_seconds_error.read().is_some()
+            || *draft.read() == *saved.read()
+            || is_pending()
+    };
+    let status = move || {
+        if is_pending() {
+            return String::from("Saving…");
+        }
+        let error = [save.value().get(), restore.value().get()]
+            .into_iter()
+            .flatten()
+            .find_map(Result::err);
+        match error {
+            Some(error) => error.to_string(),
+            None if *draft.read() != *saved.read() => String::from("Unsaved changes."),
+            None if history_version.get() > 0 => String::from("Saved."),
+            None => String::new(),
+        }
+    };
+    let on_submit = move |event: leptos::ev::SubmitEvent| {
+        event.prevent_default();
+        if wait_seconds_error.get_untracked().is_some() {
+            return;
+        }
+        save.dispatch(EditorSave {
+            cell,
+            suggestion: draft.get_untracked(),
+            message: message.get_untracked(),
+        });
+    };
+
+    // The preview is shown as if the cell's trust level, mood, and context
+    // were selected.
+    let preview_trust = RwSignal::new(Some(cell.trust()));
+    let preview_mood_range = RwSignal::new(cell.mood().map(MoodRange::from));
+    let preview_guidance = Signal::derive(move || {
+        Some(Guidance {
+            suggestion: draft.get(),
+            basis: match cell.mood() {
+                Some(_) => GuidanceBasis::TrustAndMood,
+                None => GuidanceBasis::TrustOnly,
+            },
+            upper_bound: None,
+            context: cell.context(),
+        })
+    });
+
+    let description_initial = description.get_untracked();
+    let extended_initial = extended.get_untracked();
+
+    view! {
+        <section class=EDITOR_CELL_CLASSES>
+            <h3 class=FIELD_NAME_CLASSES>{cell_heading(cell)}</h3>
+            <div class=HOMEPAGE_CLASSES>
+                <form class=EDITOR_FORM_CLASSES on:submit=on_submit>
+                    <label class=EDITOR_LABEL_CLASSES>
+                        "Action"
+                        <input
+                            type="text"
+                            class=EDITOR_INPUT_CLASSES
+                            prop:value=move || action.get()
+                            on:input=move |event| action.set(event_target_value(&event))
+                        />
+                    </label>
+                    <label class=EDITOR_LABEL_CLASSES>
+                        "Description"
+                        <textarea
+                            rows="10"
+                            class=EDITOR_INPUT_CLASSES
+                            prop:value=move || description.get()
+                            on:input=move |event| description.set(event_target_value(&event))
+                        >
+                            {description_initial}
+                        </textarea>
+                    </label>
+                    <label class=EDITOR_LABEL_CLASSES>
+                        "At a glance (optional, one line)"
+                        <input
+                            type="text"
+                            class=EDITOR_INPUT_CLASSES
+                            prop:value=move || glance.get()
+                            on:input=move |event| glance.set(event_target_value(&event))
+                        />
+                    </label>
+                    <label class=EDITOR_LABEL_CLASSES>
+                        "In depth (optional)"
+                        <textarea
+                            rows="6"
+                            class=EDITOR_INPUT_CLASSES
+                            prop:value=move || extended.get()
+                            on:input=move |event| extended.set(event_target_value(&event))
+                        >
+                            {extended_initial}
+                        </textarea>
+                    </label>
+                    <label class=EDITOR_LABEL_CLASSES>
+                        "Wait, in seconds (optional)"
+                        <input
+                            type="text"
+                            inputmode="numeric"
+                            class=EDITOR_INPUT_CLASSES
+                            prop:value=move || wait_seconds.get()
+                            on:input=move |event| wait_seconds.set(event_target_value(&event))
+                        />
+                    </label>
+                    <fieldset class=EDITOR_PRINCIPLES_CLASSES>
+                        <legend>"Principles, in order of importance"</legend>
+                        {
+                            Principle::iter()
+                                .map(|principle| {
+                                    // Newly checked principles are added at the end.
+                                    let principle_on_input = move |event| {
+                                        if event_target_checked(&event) {
+                                            principles.write().push(principle);
+                                        } else {
+                                            principles.write().retain(|principle_checked| *principle_checked != principle);
+                                        }
+                                    };
+                                    view! {
+                                        <label class=EDITOR_PRINCIPLE_CLASSES>
+                                            <input
+                                                type="checkbox"
+                                                prop:checked=move || principles.read().contains(&principle)
+                                                on:input=principle_on_input
+                                            />
+                                            {principle.name()}
+                                        </label>
+                                    }
+                                })
+                                .collect_view()
+                        }
+                    </fieldset>
+                    <ul class=EDITOR_ERRORS_CLASSES role="alert">
+                        {move || {
+                            wait_seconds_error
+                                .get()
+                                .map(|wait_seconds_error| view! { <li>{wait_seconds_error}</li> })
+                        }}
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|error| view! { <li>{error_message(&error)}</li> })
+                                .collect_view()
+                        }}
+                    </ul>
+                    <label class=EDITOR_LABEL_CLASSES>
+                        "What changed, and why (optional)"
+                        <input
+                            type="text"
+                            class=EDITOR_INPUT_CLASSES
+                            prop:value=move || message.get()
+                            on:input=move |event| message.set(event_target_value(&event))
+                        />
+                    </label>
+                    <button type="submit" class=EDITOR_SAVE_CLASSES disabled=is_save_disabled>
+                        "Save"
+                    </button>
+                    <p class=EDITOR_STATUS_CLASSES role="status">{status}</p>
+                </form>
+                <SuggestionDiv
+                    trust=preview_trust
+                    mood_range=preview_mood_range
+                    guidance=preview_guidance
+                />
+            </div>
+            <RevisionHistory
+                cell
+                history_version
+                saved
+                message
+                restore
+            />
+        </section>
+    }
+}
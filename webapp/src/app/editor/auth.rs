@@ -0,0 +1,156 @@
+use std::net::SocketAddr;
+
+use axum::{
+    extract::{ConnectInfo, Request},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::{prelude::BASE64_STANDARD, Engine};
+
+use crate::app::forwarded::{self, TRUSTED_PROXIES_ENV};
+
+/// Environment variable listing who may use the editor, as comma separated
+/// `name:password` pairs.
+///
+/// The editor is disabled when this is not set.
+///
+/// Credentials are sent with HTTP basic authentication, which does not
+/// encrypt them, so the editor is only served over HTTPS, through a reverse
+/// proxy in [`TRUSTED_PROXIES_ENV`].
+pub const EDITORS_ENV: &str = "TEARS_EDITORS";
+
+/// Environment variable that allows the editor over plain HTTP when set, e.g.
+/// while developing on `127.0.0.1`.
+pub const EDITOR_ALLOW_HTTP_ENV: &str = "TEARS_EDITOR_ALLOW_HTTP";
+
+/// Number of bytes that credentials are compared over, at least.
+///
+/// Credentials up to this length take the same time to compare, so the time
+/// does not reveal their length.
+const CREDENTIAL_COMPARE_LENGTH: usize = 128;
+
+/// Path prefix of the editor's server functions.
+///
+/// This must match the `prefix` of the server functions in `editor.rs`.
+const EDITOR_API_PREFIX: &str = "/api/editor";
+
+/// Name of the editor that made a request.
+///
+/// Inserted into the request extensions by [`editor_auth`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EditorName(pub String);
+
+/// Requires HTTPS and HTTP basic authentication for the editor page and its
+/// server functions.
+///
+/// Requests over plain HTTP are rejected before their credentials are
+/// checked, unless [`EDITOR_ALLOW_HTTP_ENV`] is set. Other requests are passed
+/// through unchanged.
+pub async fn editor_auth(mut request: Request, next: Next) -> Response {
+    if !is_editor_path(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let is_https = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .is_some_and(|ConnectInfo(socket_addr)| {
+            forwarded::is_https(socket_addr.ip(), request.headers())
+        });
+    if !is_https && std::env::var_os(EDITOR_ALLOW_HTTP_ENV).is_none() {
+        let message = format!(
+            "The editor is only served over HTTPS, through a proxy in `{TRUSTED_PROXIES_ENV}`. \
+             Set `{EDITOR_ALLOW_HTTP_ENV}` to allow HTTP, e.g. while developing."
+        );
+        return (StatusCode::FORBIDDEN, message).into_response();
+    }
+
+    let editors = match std::env::var(EDITORS_ENV) {
+        Ok(editors) if !editors.trim().is_empty() => editors,
+        _ => {
+            let message = format!("The editor is disabled. Set `{EDITORS_ENV}` to enable it.");
+            return (StatusCode::FORBIDDEN, message).into_response();
+        }
+    };
+
+    let editor_name = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .and_then(|authorization| authorization.strip_prefix("Basic "))
+        .and_then(|credentials| BASE64_STANDARD.decode(credentials).ok())
+        .and_then(|credentials| String::from_utf8(credentials).ok())
+        .and_then(|credentials| authenticate(&editors, &credentials));
+
+    match editor_name {
+        Some(editor_name) => {
+            request.extensions_mut().insert(EditorName(editor_name));
+            next.run(request).await
+        }
+        None => (
+            StatusCode::UNAUTHORIZED,
+            [(
+                header::WWW_AUTHENTICATE,
+                "Basic realm=\"tears editor\", charset=\"UTF-8\"",
+            )],
+            "Sign in to use the editor.",
+        )
+            .into_response(),
+    }
+}
+
+/// Returns the path of the editor page.
+pub fn editor_path() -> String {
+    let site_prefix = option_env!("SITE_PREFIX").unwrap_or("");
+    let site_prefix = site_prefix.trim_matches('/');
+    if site_prefix.is_empty() {
+        String::from("/editor")
+    } else {
+        format!("/{site_prefix}/editor")
+    }
+}
+
+/// Returns whether the path is the editor page or one of its server
+/// functions.
+fn is_editor_path(path: &str) -> bool {
+    let editor_path = editor_path();
+    path.trim_end_matches('/') == editor_path
+        || path.starts_with(&format!("{editor_path}/"))
+        || path.starts_with(&format!("{EDITOR_API_PREFIX}/"))
+}
+
+/// Returns the editor's name if the `name:password` credentials match one of
+/// the `editors`.
+///
+/// Every editor's name and password is compared, so the time taken does not
+/// reveal which names exist.
+fn authenticate(editors: &str, credentials: &str) -> Option<String> {
+    let (name, password) = credentials.split_once(':')?;
+    editors
+        .split(',')
+        .filter_map(|editor| editor.trim().split_once(':'))
+        .fold(
+            None,
+            |editor_name_matched, (editor_name, editor_password)| {
+                let is_match = constant_time_eq(editor_name.as_bytes(), name.as_bytes())
+                    & constant_time_eq(editor_password.as_bytes(), password.as_bytes());
+                editor_name_matched.or_else(|| is_match.then(|| editor_name.to_string()))
+            },
+        )
+}
+
+/// Compares the bytes without returning early, so the time taken does not
+/// reveal how much of a password is correct, or how long it is.
+///
+/// Both are compared over the same number of bytes, at least
+/// [`CREDENTIAL_COMPARE_LENGTH`], with the shorter one padded.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let length = a.len().max(b.len()).max(CREDENTIAL_COMPARE_LENGTH);
+    let difference = (0..length).fold(a.len() ^ b.len(), |difference, index| {
+        let a = a.get(index).copied().unwrap_or(0);
+        let b = b.get(index).copied().unwrap_or(0);
+        difference | usize::from(a ^ b)
+    });
+    std::hint::black_box(difference) == 0
+}
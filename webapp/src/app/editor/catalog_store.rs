@@ -0,0 +1,307 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use tears::{
+    CatalogCell, CatalogFile, CatalogFileError, CatalogHistory, CatalogHistoryError,
+    CatalogRevision, Suggestion,
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// Environment variable with the path of the catalog file to serve and edit.
+///
+/// When this is not set, the catalog built into `tears` is served, and the
+/// editor cannot save changes.
+pub const CATALOG_FILE_ENV: &str = "TEARS_CATALOG_FILE";
+
+/// Held while the catalog and history files are read and written, so that
+/// concurrent saves do not overwrite each other.
+static CATALOG_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Comment written at the top of a history file.
+const HISTORY_FILE_HEADER: &str = "\
+# Revisions of the catalog file, written by the editor in the `tears` server.
+#
+# New revisions are appended to the end of this file.
+
+";
+
+/// Errors when reading or writing the catalog file or its history.
+#[derive(Debug, thiserror::Error)]
+pub enum CatalogStoreError {
+    /// There is no catalog file to edit.
+    #[error("Set `{CATALOG_FILE_ENV}` to the catalog file to edit.")]
+    PathUnset,
+    /// The catalog file could not be read.
+    #[error("Failed to read the catalog file `{}`.", path.display())]
+    Read {
+        /// Path to the catalog file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// The catalog file is invalid.
+    #[error("The catalog file `{}` is invalid.", path.display())]
+    Parse {
+        /// Path to the catalog file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: CatalogFileError,
+    },
+    /// The change would make the catalog invalid.
+    #[error("The change was not saved.")]
+    Update {
+        /// The underlying error.
+        #[source]
+        error: CatalogFileError,
+    },
+    /// The catalog file could not be written.
+    #[error("Failed to write the catalog file `{}`.", path.display())]
+    Write {
+        /// Path to the catalog file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// The history file exists but could not be read.
+    #[error("Failed to read the history file `{}`.", path.display())]
+    HistoryRead {
+        /// Path to the history file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// The history file is invalid.
+    #[error("The history file `{}` is invalid.", path.display())]
+    HistoryParse {
+        /// Path to the history file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: CatalogHistoryError,
+    },
+    /// The revision could not be recorded.
+    #[error("The change was saved, but failed to be recorded in `{}`.", path.display())]
+    HistoryWrite {
+        /// Path to the history file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The revision to restore is not in the history.
+    #[error("Revision {revision_id} is not in the history.")]
+    RevisionUnknown {
+        /// ID of the revision.
+        revision_id: u64,
+    },
+}
+
+/// Returns the path of the catalog file to serve and edit.
+pub fn catalog_file_path() -> Result<PathBuf, CatalogStoreError> {
+    std::env::var_os(CATALOG_FILE_ENV)
+        .filter(|catalog_file_path| !catalog_file_path.is_empty())
+        .map(PathBuf::from)
+        .ok_or(CatalogStoreError::PathUnset)
+}
+
+/// Returns the path of the history file for a catalog file.
+///
+/// This is next to the catalog file, e.g. `suggestions.history.toml` for
+/// `suggestions.toml`.
+pub fn history_file_path(catalog_file_path: &Path) -> PathBuf {
+    catalog_file_path.with_extension("history.toml")
+}
+
+/// Reads the catalog file.
+pub fn load() -> Result<CatalogFile, CatalogStoreError> {
+    let _lock = CATALOG_FILE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    read(&catalog_file_path()?)
+}
+
+/// Reads the history of the catalog file.
+pub fn load_history() -> Result<CatalogHistory, CatalogStoreError> {
+    let _lock = CATALOG_FILE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    read_history(&history_file_path(&catalog_file_path()?))
+}
+
+/// Replaces a suggestion in the catalog file, and records the change in the
+/// history.
+///
+/// Nothing is written if the suggestion is unchanged.
+pub fn save(
+    cell: CatalogCell,
+    suggestion: Suggestion,
+    author: &str,
+    message: &str,
+) -> Result<(), CatalogStoreError> {
+    let _lock = CATALOG_FILE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    change(cell, suggestion, author, message, None)
+}
+
+/// Sets the suggestion that a revision changed back to what it was after that
+/// revision, or before it if `is_previous`, and records this as a new
+/// revision.
+///
+/// Returns the restored suggestion.
+pub fn restore(
+    revision_id: u64,
+    is_previous: bool,
+    author: &str,
+    message: &str,
+) -> Result<Suggestion, CatalogStoreError> {
+    let _lock = CATALOG_FILE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let catalog_history = read_history(&history_file_path(&catalog_file_path()?))?;
+    let revision = catalog_history
+        .get(revision_id)
+        .cloned()
+        .ok_or(CatalogStoreError::RevisionUnknown { revision_id })?;
+
+    let cell = revision.cell();
+    let suggestion = if is_previous {
+        revision.suggestion_previous
+    } else {
+        revision.suggestion
+    };
+
+    change(cell, suggestion.clone(), author, message, Some(revision_id))?;
+
+    Ok(suggestion)
+}
+
+/// Writes the suggestion to the catalog file, and appends a revision to the
+/// history file.
+///
+/// The caller must hold [`CATALOG_FILE_LOCK`].
+fn change(
+    cell: CatalogCell,
+    suggestion: Suggestion,
+    author: &str,
+    message: &str,
+    restored_from: Option<u64>,
+) -> Result<(), CatalogStoreError> {
+    let path = catalog_file_path()?;
+    let history_path = history_file_path(&path);
+
+    let mut catalog_file = read(&path)?;
+    let catalog_history = read_history(&history_path)?;
+    let suggestion_previous = match catalog_file.get(cell) {
+        Some(suggestion_previous) if *suggestion_previous == suggestion => return Ok(()),
+        Some(suggestion_previous) => suggestion_previous.clone(),
+        None => {
+            return Err(CatalogStoreError::Update {
+                error: CatalogFileError::SuggestionMissing { cell },
+            })
+        }
+    };
+
+    catalog_file
+        .set(cell, suggestion.clone())
+        .map_err(|error| CatalogStoreError::Update { error })?;
+    let catalog_toml = catalog_file
+        .to_toml()
+        .map_err(|error| CatalogStoreError::Update { error })?;
+    write(&path, &catalog_toml)?;
+
+    let revision = CatalogRevision {
+        id: catalog_history.id_next(),
+        author: author.to_string(),
+        timestamp: OffsetDateTime::now_utc()
+            .format(&Rfc3339)
+            .map_err(|error| CatalogStoreError::HistoryWrite {
+                path: history_path.clone(),
+                error: Box::new(error),
+            })?,
+        message: message.trim().to_string(),
+        trust: cell.trust(),
+        mood: cell.mood(),
+        context: cell.context(),
+        restored_from,
+        suggestion_previous,
+        suggestion,
+    };
+    append_revision(&history_path, &revision)
+}
+
+fn read(path: &Path) -> Result<CatalogFile, CatalogStoreError> {
+    let catalog_toml = fs::read_to_string(path).map_err(|error| CatalogStoreError::Read {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    CatalogFile::parse(&catalog_toml).map_err(|error| CatalogStoreError::Parse {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Reads the history file, which does not exist until the first change.
+fn read_history(path: &Path) -> Result<CatalogHistory, CatalogStoreError> {
+    let history_toml = match fs::read_to_string(path) {
+        Ok(history_toml) => history_toml,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(CatalogHistory::new()),
+        Err(error) => {
+            return Err(CatalogStoreError::HistoryRead {
+                path: path.to_path_buf(),
+                error,
+            })
+        }
+    };
+    CatalogHistory::parse(&history_toml).map_err(|error| CatalogStoreError::HistoryParse {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Writes to a temporary file and renames it over the catalog file, so the
+/// catalog file is never left half written.
+fn write(path: &Path, catalog_toml: &str) -> Result<(), CatalogStoreError> {
+    let path_tmp = path.with_extension("toml.tmp");
+    fs::write(&path_tmp, catalog_toml)
+        .and_then(|()| fs::rename(&path_tmp, path))
+        .map_err(|error| CatalogStoreError::Write {
+            path: path.to_path_buf(),
+            error,
+        })
+}
+
+/// Appends the revision to the history file, creating it if needed.
+fn append_revision(path: &Path, revision: &CatalogRevision) -> Result<(), CatalogStoreError> {
+    let history_write_error =
+        |error: Box<dyn std::error::Error + Send + Sync>| CatalogStoreError::HistoryWrite {
+            path: path.to_path_buf(),
+            error,
+        };
+
+    let revision_toml = revision
+        .to_toml()
+        .map_err(|error| history_write_error(Box::new(error)))?;
+    let is_first_revision = revision.id == 1;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut history_file| {
+            if is_first_revision {
+                history_file.write_all(HISTORY_FILE_HEADER.as_bytes())?;
+            } else {
+                history_file.write_all(b"\n")?;
+            }
+            history_file.write_all(revision_toml.as_bytes())
+        })
+        .map_err(|error| history_write_error(Box::new(error)))
+}
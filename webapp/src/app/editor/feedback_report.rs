@@ -0,0 +1,132 @@
+use leptos::{
+    component,
+    either::Either,
+    prelude::{ClassAttribute, CollectView, ElementChild, IntoView, Resource, Suspend, Suspense},
+    view,
+};
+use tears::{FeedbackRating, FeedbackSummary};
+
+use super::{EDITOR_CELL_CLASSES, EDITOR_CLASSES, EDITOR_STATUS_CLASSES};
+use crate::app::{feedback::feedback_report, FIELD_DESC_CLASSES, FIELD_NAME_CLASSES};
+
+const REPORT_TABLE_CLASSES: &str = "\
+    w-full \
+    text-start \
+    border-collapse \
+";
+
+const REPORT_HEADER_CLASSES: &str = "\
+    text-start \
+    text-tears-subtle \
+    border-b \
+    border-tears-divider \
+    py-2 \
+    pe-4 \
+";
+
+const REPORT_CELL_CLASSES: &str = "\
+    border-b \
+    border-tears-divider \
+    py-2 \
+    pe-4 \
+    align-top \
+";
+
+const REPORT_COMMENTS_CLASSES: &str = "\
+    list-disc \
+    ps-6 \
+    whitespace-pre-wrap \
+";
+
+/// Shows how often the suggestion for each trust and mood level was rated
+/// helpful, not helpful, or as making it worse, with the comments left.
+#[component]
+pub fn FeedbackReportPage() -> impl IntoView {
+    // Static builds have no server to load from.
+    if cfg!(feature = "csr") {
+        return Either::Left(view! {
+            <p class=EDITOR_STATUS_CLASSES>
+                "The feedback report is only available when served by the `tears` server."
+            </p>
+        });
+    }
+
+    let feedback_summaries = Resource::new(|| (), |()| feedback_report());
+
+    Either::Right(view! {
+        <div class=EDITOR_CLASSES>
+            <div>
+                <h2 class=FIELD_NAME_CLASSES>"Feedback report"</h2>
+                <span class=FIELD_DESC_CLASSES>
+                    "Ratings and comments left under each suggestion. Feedback is anonymous."
+                </span>
+            </div>
+            <Suspense fallback=|| view! { <p class=EDITOR_STATUS_CLASSES>"Loading the feedback…"</p> }>
+                {move || Suspend::new(async move {
+                    match feedback_summaries.await {
+                        Ok(feedback_summaries) => Either::Left(view! {
+                            <section class=EDITOR_CELL_CLASSES>
+                                <table class=REPORT_TABLE_CLASSES>
+                                    <thead>
+                                        <tr>
+                                            <th class=REPORT_HEADER_CLASSES>"Trust"</th>
+                                            <th class=REPORT_HEADER_CLASSES>"Mood"</th>
+                                            {FeedbackRating::iter()
+                                                .map(|feedback_rating| view! {
+                                                    <th class=REPORT_HEADER_CLASSES>
+                                                        {feedback_rating.ui_text().text()}
+                                                    </th>
+                                                })
+                                                .collect_view()}
+                                            <th class=REPORT_HEADER_CLASSES>"Total"</th>
+                                            <th class=REPORT_HEADER_CLASSES>"Comments"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {feedback_summaries
+                                            .into_iter()
+                                            .map(|feedback_summary| view! { <FeedbackSummaryRow feedback_summary /> })
+                                            .collect_view()}
+                                    </tbody>
+                                </table>
+                            </section>
+                        }),
+                        Err(error) => Either::Right(view! {
+                            <p class=EDITOR_STATUS_CLASSES>{error.to_string()}</p>
+                        }),
+                    }
+                })}
+            </Suspense>
+        </div>
+    })
+}
+
+/// Shows the feedback for one trust and mood level.
+#[component]
+fn FeedbackSummaryRow(feedback_summary: FeedbackSummary) -> impl IntoView {
+    let counts = FeedbackRating::iter()
+        .map(|feedback_rating| feedback_summary.count(feedback_rating))
+        .collect::<Vec<_>>();
+    let total = feedback_summary.total();
+
+    view! {
+        <tr>
+            <td class=REPORT_CELL_CLASSES>{feedback_summary.trust.name()}</td>
+            <td class=REPORT_CELL_CLASSES>{feedback_summary.mood.name()}</td>
+            {counts
+                .into_iter()
+                .map(|count| view! { <td class=REPORT_CELL_CLASSES>{count}</td> })
+                .collect_view()}
+            <td class=REPORT_CELL_CLASSES>{total}</td>
+            <td class=REPORT_CELL_CLASSES>
+                <ul class=REPORT_COMMENTS_CLASSES>
+                    {feedback_summary
+                        .comments
+                        .into_iter()
+                        .map(|comment| view! { <li>{comment}</li> })
+                        .collect_view()}
+                </ul>
+            </td>
+        </tr>
+    }
+}
@@ -0,0 +1,254 @@
+use leptos::{
+    component,
+    either::Either,
+    prelude::{
+        AriaAttributes, ClassAttribute, CollectView, ElementChild, Get, GetUntracked, IntoView,
+        OnAttribute, Read, Resource, RwSignal, ServerAction, Suspend, Suspense, Update,
+    },
+    view,
+};
+use tears::{CatalogCell, CatalogRevision, Suggestion, WordChange, WordDiff};
+
+use super::{
+    editor_history, EditorRestore, EDITOR_LABEL_CLASSES, EDITOR_SAVE_CLASSES, EDITOR_STATUS_CLASSES,
+};
+
+const HISTORY_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-4 \
+    mt-8 \
+";
+
+const HISTORY_LIST_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-4 \
+";
+
+const REVISION_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-2 \
+    border-t \
+    border-tears-divider \
+    pt-4 \
+";
+
+const REVISION_META_CLASSES: &str = "\
+    text-tears-subtle \
+";
+
+const REVISION_DIFF_CLASSES: &str = "\
+    whitespace-pre-wrap \
+    text-tears-fg \
+";
+
+const REVISION_ACTIONS_CLASSES: &str = "\
+    flex \
+    flex-wrap \
+    gap-2 \
+";
+
+const DIFF_INSERTED_CLASSES: &str = "\
+    underline \
+    decoration-tears-accent \
+    decoration-2 \
+    underline-offset-4 \
+";
+
+const DIFF_REMOVED_CLASSES: &str = "\
+    text-tears-muted \
+    line-through \
+";
+
+/// Lists the revisions of one suggestion, with what each changed and a button
+/// to restore it.
+///
+/// The history is only loaded once shown, and reloaded whenever
+/// `history_version` changes.
+#[component]
+pub fn RevisionHistory(
+    cell: CatalogCell,
+    history_version: RwSignal<u32>,
+    saved: RwSignal<Suggestion>,
+    message: RwSignal<String>,
+    restore: ServerAction<EditorRestore>,
+) -> impl IntoView {
+    let is_shown = RwSignal::new(false);
+    let revisions = Resource::new(
+        move || (is_shown.get(), history_version.get()),
+        move |(is_shown, _)| async move {
+            if is_shown {
+                editor_history(cell).await
+            } else {
+                Ok(Vec::new())
+            }
+        },
+    );
+
+    let toggle_text = move || {
+        if is_shown.get() {
+            "Hide history"
+        } else {
+            "Show history"
+        }
+    };
+
+    view! {
+        <div class=HISTORY_CLASSES>
+            <button
+                type="button"
+                class=EDITOR_SAVE_CLASSES
+                aria-expanded=move || is_shown.get().to_string()
+                on:click=move |_| is_shown.update(|is_shown| *is_shown = !*is_shown)
+            >
+                {toggle_text}
+            </button>
+            {move || {
+                is_shown
+                    .get()
+                    .then(|| {
+                        view! {
+                            <Suspense fallback=|| {
+                                view! { <p class=EDITOR_STATUS_CLASSES>"Loading the history…"</p> }
+                            }>
+                                {move || Suspend::new(async move {
+                                    match revisions.await {
+                                        Ok(revisions) if revisions.is_empty() => Either::Left(view! {
+                                            <p class=EDITOR_STATUS_CLASSES>
+                                                "No changes have been saved yet."
+                                            </p>
+                                        }),
+                                        Ok(revisions) => Either::Right(Either::Left(view! {
+                                            <ol class=HISTORY_LIST_CLASSES>
+                                                {revisions
+                                                    .into_iter()
+                                                    .map(|revision| {
+                                                        view! { <RevisionItem revision saved message restore /> }
+                                                    })
+                                                    .collect_view()}
+                                            </ol>
+                                        })),
+                                        Err(error) => Either::Right(Either::Right(view! {
+                                            <p class=EDITOR_STATUS_CLASSES>{error.to_string()}</p>
+                                        })),
+                                    }
+                                })}
+                            </Suspense>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+/// Shows who made a revision and when, why, and what it changed.
+#[component]
+fn RevisionItem(
+    revision: CatalogRevision,
+    saved: RwSignal<Suggestion>,
+    message: RwSignal<String>,
+    restore: ServerAction<EditorRestore>,
+) -> impl IntoView {
+    let revision_id = revision.id;
+    let is_restore_disabled = move |suggestion: Suggestion| {
+        move || *saved.read() == suggestion || restore.pending().get()
+    };
+    let on_restore = move |is_previous: bool| {
+        move |_| {
+            let message_text = message.get_untracked();
+            let message_text = if !message_text.trim().is_empty() {
+                message_text
+            } else if is_previous {
+                format!("Restore the version before revision #{revision_id}.")
+            } else {
+                format!("Restore revision #{revision_id}.")
+            };
+            restore.dispatch(EditorRestore {
+                revision_id,
+                is_previous,
+                message: message_text,
+            });
+        }
+    };
+
+    let restored_from = revision
+        .restored_from
+        .map(|restored_from| format!(" · restored from #{restored_from}"));
+    let message_text = if revision.message.is_empty() {
+        String::from("(no message)")
+    } else {
+        revision.message.clone()
+    };
+
+    view! {
+        <li class=REVISION_CLASSES>
+            <p class=REVISION_META_CLASSES>
+                "#"{revision.id}" · "{revision.author.clone()}" · "
+                <time datetime=revision.timestamp.clone()>{revision.timestamp.clone()}</time>
+                {restored_from}
+            </p>
+            <p>{message_text}</p>
+            <span class=EDITOR_LABEL_CLASSES>"Action"</span>
+            <WordDiffView word_diff=revision.action_diff() />
+            <span class=EDITOR_LABEL_CLASSES>"Description"</span>
+            <WordDiffView word_diff=revision.description_diff() />
+            {revision.glance_diff().map(|word_diff| view! {
+                <span class=EDITOR_LABEL_CLASSES>"At a glance"</span>
+                <WordDiffView word_diff />
+            })}
+            {revision.extended_diff().map(|word_diff| view! {
+                <span class=EDITOR_LABEL_CLASSES>"In depth"</span>
+                <WordDiffView word_diff />
+            })}
+            <div class=REVISION_ACTIONS_CLASSES>
+                <button
+                    type="button"
+                    class=EDITOR_SAVE_CLASSES
+                    disabled=is_restore_disabled(revision.suggestion.clone())
+                    on:click=on_restore(false)
+                >
+                    "Restore this version"
+                </button>
+                <button
+                    type="button"
+                    class=EDITOR_SAVE_CLASSES
+                    disabled=is_restore_disabled(revision.suggestion_previous.clone())
+                    on:click=on_restore(true)
+                >
+                    "Restore the version before"
+                </button>
+            </div>
+        </li>
+    }
+}
+
+/// Shows the words that a revision removed and inserted.
+#[component]
+fn WordDiffView(word_diff: WordDiff) -> impl IntoView {
+    if word_diff.is_unchanged() {
+        return Either::Left(view! { <p class=EDITOR_STATUS_CLASSES>"Unchanged."</p> });
+    }
+
+    Either::Right(view! {
+        <p class=REVISION_DIFF_CLASSES>
+            {word_diff
+                .segments()
+                .iter()
+                .map(|segment| {
+                    let text = segment.text.clone();
+                    match segment.change {
+                        WordChange::Unchanged => Either::Left(view! { <span>{text}</span> }),
+                        WordChange::Inserted => Either::Right(Either::Left(view! {
+                            <ins class=DIFF_INSERTED_CLASSES>{text}</ins>
+                        })),
+                        WordChange::Removed => Either::Right(Either::Right(view! {
+                            <del class=DIFF_REMOVED_CLASSES>{text}</del>
+                        })),
+                    }
+                })
+                .collect_view()}
+        </p>
+    })
+}
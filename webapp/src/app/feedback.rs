@@ -0,0 +1,213 @@
+use leptos::{
+    component,
+    either::Either,
+    prelude::{
+        event_target_value, AriaAttributes, ClassAttribute, CollectView, ElementChild, Get,
+        GetUntracked, GlobalAttributes, IntoView, OnAttribute, PropAttribute, RwSignal,
+        ServerAction, ServerFnError, Set,
+    },
+    server, view,
+};
+use tears::{FeedbackRating, FeedbackSummary, Mood, Trust, UiText};
+
+use super::l10n;
+
+#[cfg(feature = "ssr")]
+mod feedback_store;
+#[cfg(feature = "ssr")]
+mod feedback_throttle;
+
+/// Maximum number of characters in a feedback comment.
+const COMMENT_LENGTH_MAX: usize = 1000;
+
+const FEEDBACK_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-2 \
+    mt-3 \
+    pt-3 \
+    border-t \
+    border-tears-divider \
+";
+
+const FEEDBACK_QUESTION_CLASSES: &str = "\
+    font-bold \
+";
+
+const FEEDBACK_RATINGS_CLASSES: &str = "\
+    flex \
+    flex-wrap \
+    gap-2 \
+";
+
+const FEEDBACK_BUTTON_CLASSES: &str = "\
+    self-start \
+    bg-tears-control \
+    text-tears-control-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-3 \
+    py-1 \
+    \
+    aria-pressed:border-tears-accent \
+    aria-pressed:font-bold \
+    disabled:opacity-50 \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-card \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+const FEEDBACK_LABEL_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-1 \
+";
+
+const FEEDBACK_COMMENT_CLASSES: &str = "\
+    bg-tears-control \
+    text-tears-control-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-card \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+const FEEDBACK_NOTE_CLASSES: &str = "\
+    italic \
+    text-tears-muted \
+";
+
+/// Stores a rating of the suggestion for a trust and mood level, with an
+/// optional comment.
+#[server(prefix = "/api/feedback", endpoint = "submit")]
+pub async fn feedback_submit(
+    trust: Trust,
+    mood: Mood,
+    rating: FeedbackRating,
+    comment: String,
+) -> Result<(), ServerFnError> {
+    use axum::{extract::ConnectInfo, http::HeaderMap};
+    use std::net::SocketAddr;
+
+    let (ConnectInfo(socket_addr), headers) =
+        leptos_axum::extract::<(ConnectInfo<SocketAddr>, HeaderMap)>().await?;
+    let client_addr = super::forwarded::client_addr(socket_addr.ip(), &headers);
+    feedback_throttle::submission_record(client_addr).map_err(super::editor::server_fn_error)?;
+
+    tokio::task::spawn_blocking(move || feedback_store::record(trust, mood, rating, &comment))
+        .await
+        .map_err(super::editor::server_fn_error)?
+        .map_err(super::editor::server_fn_error)
+}
+
+/// Returns the feedback for each trust and mood level, added up.
+///
+/// This is under the editor prefix, so only editors can read it.
+#[server(prefix = "/api/editor", endpoint = "feedback")]
+pub async fn feedback_report() -> Result<Vec<FeedbackSummary>, ServerFnError> {
+    tokio::task::spawn_blocking(feedback_store::report)
+        .await
+        .map_err(super::editor::server_fn_error)?
+        .map_err(super::editor::server_fn_error)
+}
+
+/// Asks whether the suggestion for a trust and mood level helped.
+///
+/// Only the rating and comment are sent, for the [`FeedbackReportPage`].
+///
+/// [`FeedbackReportPage`]: super::editor::FeedbackReportPage
+#[component]
+pub fn FeedbackWidget(trust: Trust, mood: Mood) -> impl IntoView {
+    // Static builds have no server to send feedback to.
+    if cfg!(feature = "csr") {
+        return Either::Left(());
+    }
+
+    let localizer = l10n::use_localizer();
+    let rating = RwSignal::new(None::<FeedbackRating>);
+    let comment = RwSignal::new(String::new());
+    let submit = ServerAction::<FeedbackSubmit>::new();
+
+    let is_send_disabled = move || rating.get().is_none() || submit.pending().get();
+    let on_submit = move |event: leptos::ev::SubmitEvent| {
+        event.prevent_default();
+        if let Some(rating) = rating.get_untracked() {
+            submit.dispatch(FeedbackSubmit {
+                trust,
+                mood,
+                rating,
+                comment: comment.get_untracked(),
+            });
+        }
+    };
+
+    Either::Right(move || match submit.value().get() {
+        Some(Ok(())) => Either::Left(view! {
+            <p class=format!("{FEEDBACK_CLASSES} {FEEDBACK_NOTE_CLASSES}") role="status">
+                {l10n::ui(localizer, UiText::FeedbackThanks)}
+            </p>
+        }),
+        result => {
+            let is_failed = matches!(result, Some(Err(_)));
+            Either::Right(view! {
+                <form class=FEEDBACK_CLASSES on:submit=on_submit>
+                    <span class=FEEDBACK_QUESTION_CLASSES>
+                        {l10n::ui(localizer, UiText::FeedbackQuestion)}
+                    </span>
+                    <div class=FEEDBACK_RATINGS_CLASSES role="group">
+                        {FeedbackRating::iter()
+                            .map(|feedback_rating| {
+                                view! {
+                                    <button
+                                        type="button"
+                                        class=FEEDBACK_BUTTON_CLASSES
+                                        aria-pressed=move || {
+                                            (rating.get() == Some(feedback_rating)).to_string()
+                                        }
+                                        on:click=move |_| rating.set(Some(feedback_rating))
+                                    >
+                                        {l10n::ui(localizer, feedback_rating.ui_text())}
+                                    </button>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                    <label class=FEEDBACK_LABEL_CLASSES>
+                        {l10n::ui(localizer, UiText::FeedbackComment)}
+                        <textarea
+                            rows="2"
+                            maxlength=COMMENT_LENGTH_MAX.to_string()
+                            class=FEEDBACK_COMMENT_CLASSES
+                            prop:value=move || comment.get()
+                            on:input=move |event| comment.set(event_target_value(&event))
+                        />
+                    </label>
+                    <span class=FEEDBACK_NOTE_CLASSES>
+                        {l10n::ui(localizer, UiText::FeedbackPrivacy)}
+                    </span>
+                    <button type="submit" class=FEEDBACK_BUTTON_CLASSES disabled=is_send_disabled>
+                        {l10n::ui(localizer, UiText::FeedbackSend)}
+                    </button>
+                    {is_failed
+                        .then(|| {
+                            view! {
+                                <p class=FEEDBACK_NOTE_CLASSES role="alert">
+                                    {l10n::ui(localizer, UiText::FeedbackFailed)}
+                                </p>
+                            }
+                        })}
+                </form>
+            })
+        }
+    })
+}
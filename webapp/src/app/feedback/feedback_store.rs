@@ -0,0 +1,233 @@
+use std::{fs, path::PathBuf, str::FromStr, sync::Mutex};
+
+use rusqlite::{params, Connection};
+use tears::{FeedbackRating, FeedbackSummary, Mood, Trust};
+
+/// Environment variable with the path of the SQLite database to store
+/// feedback in.
+pub const FEEDBACK_DB_ENV: &str = "TEARS_FEEDBACK_DB";
+
+/// Database to store feedback in when [`FEEDBACK_DB_ENV`] is not set,
+/// relative to the user's data directory, i.e. `$XDG_DATA_HOME` or
+/// `~/.local/share`.
+const FEEDBACK_DB_DEFAULT: &str = "tears/feedback.sqlite3";
+
+/// Maximum number of feedback entries to store.
+///
+/// Feedback is anonymous, so this stops the database from filling the disk.
+const FEEDBACK_COUNT_MAX: u64 = 100_000;
+
+/// Connection to the feedback database, opened on first use.
+static CONNECTION: Mutex<Option<Connection>> = Mutex::new(None);
+
+/// Creates the feedback table if it does not exist.
+///
+/// Only what is needed for the report is stored -- there is no time, address,
+/// or anything else that could identify who sent the feedback.
+const SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS feedback (
+    id INTEGER PRIMARY KEY,
+    trust TEXT NOT NULL,
+    mood TEXT NOT NULL,
+    rating TEXT NOT NULL,
+    comment TEXT NOT NULL
+);
+";
+
+/// Errors when storing or reporting feedback.
+#[derive(Debug, thiserror::Error)]
+pub enum FeedbackStoreError {
+    /// There is no data directory to store the feedback database in.
+    #[error("Set `{FEEDBACK_DB_ENV}`, `XDG_DATA_HOME`, or `HOME` to store feedback.")]
+    PathUnknown,
+    /// The directory of the feedback database could not be created.
+    #[error("Failed to create the directory `{}` for the feedback database.", path.display())]
+    DirCreate {
+        /// Path to the directory.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// The feedback database could not be opened.
+    #[error("Failed to open the feedback database `{}`.", path.display())]
+    Open {
+        /// Path to the feedback database.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        error: rusqlite::Error,
+    },
+    /// The feedback could not be stored.
+    #[error("Failed to store the feedback.")]
+    Insert {
+        /// The underlying error.
+        #[source]
+        error: rusqlite::Error,
+    },
+    /// The feedback could not be read for the report.
+    #[error("Failed to read the feedback.")]
+    Query {
+        /// The underlying error.
+        #[source]
+        error: rusqlite::Error,
+    },
+    /// The comment is longer than allowed.
+    #[error("The comment must be at most {comment_length_max} characters.")]
+    CommentTooLong {
+        /// Maximum number of characters in a comment.
+        comment_length_max: usize,
+    },
+    /// The feedback database holds as much feedback as allowed.
+    #[error("Thank you, but no more feedback is being accepted for now.")]
+    Full,
+}
+
+/// Returns the path of the feedback database, if there is anywhere to store
+/// it.
+pub fn feedback_db_path() -> Option<PathBuf> {
+    if let Some(feedback_db_path) = std::env::var_os(FEEDBACK_DB_ENV) {
+        return Some(PathBuf::from(feedback_db_path));
+    }
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|data_dir| data_dir.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .map(|data_dir| data_dir.join(FEEDBACK_DB_DEFAULT))
+}
+
+/// Stores a rating of the suggestion for a trust and mood level.
+pub fn record(
+    trust: Trust,
+    mood: Mood,
+    feedback_rating: FeedbackRating,
+    comment: &str,
+) -> Result<(), FeedbackStoreError> {
+    let comment = comment.trim();
+    if comment.chars().count() > super::COMMENT_LENGTH_MAX {
+        return Err(FeedbackStoreError::CommentTooLong {
+            comment_length_max: super::COMMENT_LENGTH_MAX,
+        });
+    }
+
+    with_connection(|connection| {
+        let feedback_count = connection
+            .query_row("SELECT COUNT(*) FROM feedback", [], |row| {
+                row.get::<_, u64>(0)
+            })
+            .map_err(|error| FeedbackStoreError::Query { error })?;
+        if feedback_count >= FEEDBACK_COUNT_MAX {
+            return Err(FeedbackStoreError::Full);
+        }
+
+        connection
+            .execute(
+                "INSERT INTO feedback (trust, mood, rating, comment) VALUES (?1, ?2, ?3, ?4)",
+                params![trust.name(), mood.name(), feedback_rating.name(), comment],
+            )
+            .map(|_row_count| ())
+            .map_err(|error| FeedbackStoreError::Insert { error })
+    })
+}
+
+/// Returns the feedback for each trust and mood level, added up.
+pub fn report() -> Result<Vec<FeedbackSummary>, FeedbackStoreError> {
+    with_connection(report_query)
+}
+
+fn report_query(connection: &Connection) -> Result<Vec<FeedbackSummary>, FeedbackStoreError> {
+    let mut feedback_summaries = Trust::iter()
+        .flat_map(|trust| Mood::iter().map(move |mood| FeedbackSummary::new(trust, mood)))
+        .collect::<Vec<_>>();
+    let query_error = |error| FeedbackStoreError::Query { error };
+
+    let mut statement = connection
+        .prepare("SELECT trust, mood, rating, COUNT(*) FROM feedback GROUP BY trust, mood, rating")
+        .map_err(query_error)?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, u64>(3)?,
+            ))
+        })
+        .map_err(query_error)?;
+    for row in rows {
+        let (trust, mood, feedback_rating, count) = row.map_err(query_error)?;
+        // Rows for levels or ratings that have since been renamed are left out.
+        let Ok(feedback_rating) = FeedbackRating::from_str(&feedback_rating) else {
+            continue;
+        };
+        if let Some(feedback_summary) = feedback_summary_mut(&mut feedback_summaries, &trust, &mood)
+        {
+            feedback_summary.add(feedback_rating, count);
+        }
+    }
+
+    let mut statement = connection
+        .prepare("SELECT trust, mood, comment FROM feedback WHERE comment != '' ORDER BY id")
+        .map_err(query_error)?;
+    let rows = statement
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(query_error)?;
+    for row in rows {
+        let (trust, mood, comment) = row.map_err(query_error)?;
+        if let Some(feedback_summary) = feedback_summary_mut(&mut feedback_summaries, &trust, &mood)
+        {
+            feedback_summary.comments.push(comment);
+        }
+    }
+
+    Ok(feedback_summaries)
+}
+
+/// Runs `f` with the connection to the feedback database, opening it on first
+/// use.
+///
+/// This blocks, so call it from a blocking task.
+fn with_connection<T>(
+    f: impl FnOnce(&Connection) -> Result<T, FeedbackStoreError>,
+) -> Result<T, FeedbackStoreError> {
+    let mut connection = CONNECTION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let connection = match &mut *connection {
+        Some(connection) => connection,
+        connection @ None => connection.insert(open()?),
+    };
+    f(connection)
+}
+
+/// Opens the feedback database, creating it if needed.
+fn open() -> Result<Connection, FeedbackStoreError> {
+    let path = feedback_db_path().ok_or(FeedbackStoreError::PathUnknown)?;
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|error| FeedbackStoreError::DirCreate {
+            path: dir.to_path_buf(),
+            error,
+        })?;
+    }
+    Connection::open(&path)
+        .and_then(|connection| connection.execute_batch(SCHEMA).map(|()| connection))
+        .map_err(|error| FeedbackStoreError::Open { path, error })
+}
+
+fn feedback_summary_mut<'summaries>(
+    feedback_summaries: &'summaries mut [FeedbackSummary],
+    trust: &str,
+    mood: &str,
+) -> Option<&'summaries mut FeedbackSummary> {
+    let trust = Trust::from_str(trust).ok()?;
+    let mood = Mood::from_str(mood).ok()?;
+    feedback_summaries
+        .iter_mut()
+        .find(|feedback_summary| feedback_summary.trust == trust && feedback_summary.mood == mood)
+}
@@ -0,0 +1,53 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Number of times feedback may be sent from one address within
+/// [`SUBMISSION_WINDOW`].
+const SUBMISSIONS_MAX: u32 = 10;
+
+/// How long the submissions from an address are counted for.
+const SUBMISSION_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// When each address's window started, and how much feedback it has sent
+/// within it.
+///
+/// This is only kept in memory, so addresses are never stored with feedback.
+static SUBMISSIONS: Mutex<Option<HashMap<IpAddr, (Instant, u32)>>> = Mutex::new(None);
+
+/// Feedback was sent too often from one address.
+#[derive(Debug, thiserror::Error)]
+#[error("Thank you, but we've had a lot of feedback from you. Please try again later.")]
+pub struct FeedbackThrottled;
+
+/// Records a submission from the client's address, failing if it has sent
+/// feedback too often.
+///
+/// Behind a reverse proxy, this is the address that the proxy forwarded the
+/// request for, see [`forwarded::client_addr`].
+///
+/// [`forwarded::client_addr`]: crate::app::forwarded::client_addr
+pub fn submission_record(ip_addr: IpAddr) -> Result<(), FeedbackThrottled> {
+    let now = Instant::now();
+    let mut submissions = SUBMISSIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let submissions = submissions.get_or_insert_with(HashMap::new);
+
+    // Addresses whose window has passed are forgotten, which also starts a
+    // new window for this address if its own has passed.
+    submissions.retain(|_ip_addr, (window_start, _count)| {
+        now.duration_since(*window_start) < SUBMISSION_WINDOW
+    });
+
+    let (_window_start, count) = submissions.entry(ip_addr).or_insert((now, 0));
+    if *count >= SUBMISSIONS_MAX {
+        return Err(FeedbackThrottled);
+    }
+    *count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Reads what a reverse proxy says about a request.
+//!
+//! The server only serves HTTP, so it is served over HTTPS by a reverse proxy
+//! that terminates TLS. Anyone can send the headers that proxies add, so they
+//! are only trusted on requests from the proxies in [`TRUSTED_PROXIES_ENV`].
+
+use std::net::IpAddr;
+
+use axum::http::HeaderMap;
+
+/// Environment variable listing the addresses of the reverse proxies in front
+/// of the server, comma separated, e.g. `127.0.0.1`.
+pub const TRUSTED_PROXIES_ENV: &str = "TEARS_TRUSTED_PROXIES";
+
+/// Header in which proxies list the addresses that a request was forwarded
+/// for, each appending the address it received the request from.
+const FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Header in which a proxy says whether the client used `http` or `https`.
+const FORWARDED_PROTO: &str = "x-forwarded-proto";
+
+/// Returns the address of the client that made the request.
+///
+/// `peer_addr` is the address the request came from. When that is a trusted
+/// proxy, the client is the last address in `X-Forwarded-For` that is not a
+/// trusted proxy, as the addresses before it may have been made up by the
+/// client.
+pub fn client_addr(peer_addr: IpAddr, headers: &HeaderMap) -> IpAddr {
+    let trusted_proxies = trusted_proxies();
+    if !trusted_proxies.contains(&peer_addr) {
+        return peer_addr;
+    }
+
+    let forwarded_for = headers
+        .get_all(FORWARDED_FOR)
+        .iter()
+        .filter_map(|forwarded_for| forwarded_for.to_str().ok())
+        .flat_map(|forwarded_for| forwarded_for.split(','))
+        .collect::<Vec<_>>();
+    forwarded_for
+        .into_iter()
+        .rev()
+        .map(|ip_addr| ip_addr.trim().parse::<IpAddr>())
+        .find(|ip_addr| {
+            ip_addr
+                .as_ref()
+                .map_or(true, |ip_addr| !trusted_proxies.contains(ip_addr))
+        })
+        .and_then(Result::ok)
+        .unwrap_or(peer_addr)
+}
+
+/// Returns whether the request reached a trusted proxy over HTTPS.
+///
+/// `peer_addr` is the address the request came from, which is the proxy's
+/// when there is one.
+pub fn is_https(peer_addr: IpAddr, headers: &HeaderMap) -> bool {
+    trusted_proxies().contains(&peer_addr)
+        && headers
+            .get(FORWARDED_PROTO)
+            .and_then(|proto| proto.to_str().ok())
+            // The first proxy that the client connected to is listed first.
+            .and_then(|proto| proto.split(',').next())
+            .is_some_and(|proto| proto.trim().eq_ignore_ascii_case("https"))
+}
+
+/// Returns the addresses of the proxies in [`TRUSTED_PROXIES_ENV`].
+fn trusted_proxies() -> Vec<IpAddr> {
+    std::env::var(TRUSTED_PROXIES_ENV)
+        .map(|trusted_proxies| {
+            trusted_proxies
+                .split(',')
+                .filter_map(|trusted_proxy| trusted_proxy.trim().parse::<IpAddr>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,100 @@
+use leptos::{
+    ev,
+    prelude::{on_cleanup, window_event_listener, RwSignal, Set},
+};
+use tears::{Mood, MoodRange, Trust};
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, HtmlInputElement, KeyboardEvent};
+
+/// Key that clears both the trust and mood selections.
+pub const KEY_CLEAR: &str = "Escape";
+
+/// `type`s of `<input>` elements that accept typed text.
+///
+/// Other inputs, e.g. radio buttons and checkboxes, leave the shortcuts
+/// enabled.
+const INPUT_TYPES_TYPING: &[&str] = &[
+    "text",
+    "search",
+    "email",
+    "url",
+    "tel",
+    "password",
+    "number",
+    "date",
+    "datetime-local",
+    "month",
+    "week",
+    "time",
+];
+
+/// Returns the key that selects the given trust level.
+pub fn trust_key(trust: Trust) -> &'static str {
+    match trust {
+        Trust::Absent => "A",
+        Trust::Present => "P",
+    }
+}
+
+/// Returns the key that selects the given mood, which is its rank.
+pub fn mood_key(mood: Mood) -> String {
+    mood.rank().to_string()
+}
+
+/// Registers the page wide keyboard shortcuts for the trust and mood inputs.
+///
+/// * `1` to `6` select the `Mood` with that rank, replacing any mood range.
+/// * `A` / `P` select `Trust::Absent` / `Trust::Present`.
+/// * `Esc` clears both selections.
+///
+/// Key presses are ignored while the user is typing into a text field, or
+/// when a modifier key is held, so that browser shortcuts still work.
+pub fn register(trust: RwSignal<Option<Trust>>, mood_range: RwSignal<Option<MoodRange>>) {
+    let handle = window_event_listener(ev::keydown, move |ev: KeyboardEvent| {
+        if ev.ctrl_key() || ev.alt_key() || ev.meta_key() || is_typing_target(&ev) {
+            return;
+        }
+
+        let key = ev.key();
+        let trust_next = Trust::iter().find(|trust| key.eq_ignore_ascii_case(trust_key(*trust)));
+        let mood_next = Mood::iter().find(|mood| key == mood_key(*mood));
+
+        if let Some(trust_next) = trust_next {
+            trust.set(Some(trust_next));
+        } else if let Some(mood_next) = mood_next {
+            mood_range.set(Some(MoodRange::from(mood_next)));
+        } else if key == KEY_CLEAR {
+            trust.set(None);
+            mood_range.set(None);
+        } else {
+            return;
+        }
+
+        ev.prevent_default();
+    });
+
+    on_cleanup(move || handle.remove());
+}
+
+/// Returns whether the key press is for an element that accepts text.
+fn is_typing_target(ev: &KeyboardEvent) -> bool {
+    let Some(element) = ev
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+    else {
+        return false;
+    };
+
+    if element.is_content_editable() {
+        return true;
+    }
+
+    match element.tag_name().as_str() {
+        "TEXTAREA" | "SELECT" => true,
+        "INPUT" => element
+            .dyn_into::<HtmlInputElement>()
+            .map(|input| INPUT_TYPES_TYPING.contains(&input.type_().as_str()))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use leptos::{
+    component, logging,
+    prelude::{
+        event_target_value, expect_context, provide_context, ClassAttribute, CollectView,
+        ElementChild, Get, GlobalAttributes, IntoView, Memo, OnAttribute, PropAttribute, RwSignal,
+        Set, With,
+    },
+    view,
+};
+use tears::{ContentBundle, Locale, Localizer, SuggestionContext, UiText};
+
+use super::local_storage;
+
+/// Key in `localStorage` that the chosen locale is stored under.
+const LOCALE_STORAGE_KEY: &str = "tears.locale";
+
+const LOCALE_SWITCHER_CLASSES: &str = "\
+    flex \
+    items-center \
+    gap-2 \
+    px-4 \
+    text-base \
+    text-tears-muted \
+";
+
+const LOCALE_SELECT_CLASSES: &str = "\
+    bg-tears-panel \
+    text-tears-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-page \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+/// Returns a memo of the `Localizer` for the given locale, and provides it as
+/// context to the components within the calling component.
+pub fn provide_localizer(locale: RwSignal<Locale>) -> Memo<Localizer> {
+    let localizer = Memo::new(move |_| {
+        let locale = locale.get();
+        Localizer::new(locale).unwrap_or_else(|error| {
+            logging::error!("{error}");
+            Localizer::with_bundle(ContentBundle::new(locale))
+        })
+    });
+    provide_context(localizer);
+
+    localizer
+}
+
+/// Returns the `Localizer` provided by `App`.
+pub fn use_localizer() -> Memo<Localizer> {
+    expect_context::<Memo<Localizer>>()
+}
+
+/// Returns a closure that renders the given UI text in the active locale.
+pub fn ui(
+    localizer: Memo<Localizer>,
+    ui_text: UiText,
+) -> impl Fn() -> String + Copy + Send + Sync + 'static {
+    move || localizer.with(|localizer| localizer.ui(ui_text).to_string())
+}
+
+/// Returns the known dimensions of the context in the active locale, joined by
+/// commas, e.g. `"Child, Home"`.
+pub fn context_names(localizer: &Localizer, context: SuggestionContext) -> String {
+    context
+        .age_group
+        .map(|age_group| localizer.age_group_name(age_group))
+        .into_iter()
+        .chain(
+            context
+                .relationship
+                .map(|relationship| localizer.relationship_name(relationship)),
+        )
+        .chain(
+            context
+                .setting
+                .map(|setting| localizer.setting_name(setting)),
+        )
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the locale stored from a previous visit, if any.
+///
+/// This must only be called in the browser.
+pub fn locale_load() -> Option<Locale> {
+    local_storage::get(LOCALE_STORAGE_KEY).and_then(|locale| Locale::from_str(&locale).ok())
+}
+
+/// Stores the chosen locale.
+///
+/// This must only be called in the browser.
+pub fn locale_store(locale: Locale) {
+    local_storage::set(LOCALE_STORAGE_KEY, Some(locale.code()));
+}
+
+/// Drop down to choose the language, which is remembered across visits.
+#[component]
+pub fn LocaleSwitcher(locale: RwSignal<Locale>) -> impl IntoView {
+    let localizer = use_localizer();
+    let locale_on_change = move |ev| {
+        if let Ok(locale_next) = Locale::from_str(event_target_value(&ev).as_str()) {
+            locale.set(locale_next);
+            locale_store(locale_next);
+        }
+    };
+
+    view! {
+        <label class=LOCALE_SWITCHER_CLASSES>
+            {ui(localizer, UiText::LocaleLabel)}
+            <select
+                class=LOCALE_SELECT_CLASSES
+                on:change=locale_on_change
+            >
+                {
+                    Locale::iter()
+                        .map(|locale_variant| view! {
+                            <option
+                                value=locale_variant.code()
+                                lang=locale_variant.code()
+                                dir=locale_variant.text_direction().attr_value()
+                                prop:selected=move || locale.get() == locale_variant
+                            >
+                                {locale_variant.name()}
+                            </option>
+                        })
+                        .collect_view()
+                }
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,28 @@
+//! Remembers the user's preferences across visits.
+//!
+//! These functions must only be called in the browser, e.g. in effects or event
+//! handlers. Failing to read or write a preference is not worth interrupting
+//! the user for, so errors are ignored.
+
+use web_sys::Storage;
+
+fn storage() -> Option<Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+/// Returns the stored value for the given key, if any.
+pub fn get(key: &str) -> Option<String> {
+    storage().and_then(|storage| storage.get_item(key).ok().flatten())
+}
+
+/// Stores the value for the given key, or removes it if `value` is `None`.
+pub fn set(key: &str, value: Option<&str>) {
+    let Some(storage) = storage() else {
+        return;
+    };
+
+    let _ = match value {
+        Some(value) => storage.set_item(key, value),
+        None => storage.remove_item(key),
+    };
+}
@@ -0,0 +1,129 @@
+use leptos::{
+    component,
+    either::Either,
+    prelude::{
+        event_target_value, ClassAttribute, CollectView, ElementChild, Get, IntoView, OnAttribute,
+        PropAttribute, Read, RwSignal, Set,
+    },
+    view,
+};
+use tears::{MoodRange, PhraseCheck, UiText};
+
+use super::{
+    anti_pattern_href, l10n, DESCRIPTION_CLASSES, DESCRIPTION_LABEL_CLASSES, FIELD_HINT_CLASSES,
+    GUIDANCE_NOTE_CLASSES, LINK_CLASSES,
+};
+
+const PHRASE_CHECKER_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-2 \
+    mt-3 \
+    pt-3 \
+    border-t \
+    border-tears-divider \
+";
+
+const PHRASE_CHECKER_LABEL_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-2 \
+";
+
+const PHRASE_CHECKER_INPUT_CLASSES: &str = "\
+    bg-tears-control \
+    text-tears-control-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-card \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+const PHRASE_CHECKER_PHRASE_CLASSES: &str = "\
+    font-bold \
+";
+
+/// Lets the helper type what they intend to say, and flags risky phrases for
+/// the mood range, with gentler ways to say them.
+///
+/// The check runs in the browser, so nothing that is typed leaves the device.
+#[component]
+pub fn PhraseChecker(
+    mood_range: MoodRange,
+    /// What the helper intends to say, kept by the caller so that it survives
+    /// changes to the trust level and mood range.
+    phrase: RwSignal<String>,
+) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+
+    view! {
+        <div class=PHRASE_CHECKER_CLASSES>
+            <label class=PHRASE_CHECKER_LABEL_CLASSES>
+                <span class=DESCRIPTION_LABEL_CLASSES>
+                    {l10n::ui(localizer, UiText::PhraseCheckLabel)}
+                </span>
+                <textarea
+                    rows="2"
+                    class=PHRASE_CHECKER_INPUT_CLASSES
+                    placeholder=move || localizer.read().ui(UiText::PhraseCheckPlaceholder).to_string()
+                    prop:value=move || phrase.get()
+                    on:input=move |event| phrase.set(event_target_value(&event))
+                />
+            </label>
+            <span class=FIELD_HINT_CLASSES>
+                {l10n::ui(localizer, UiText::PhraseCheckDescription)}
+            </span>
+            {move || {
+                let localizer = localizer.read();
+                let phrase = phrase.get();
+                if phrase.trim().is_empty() {
+                    return None;
+                }
+
+                let phrase_check = PhraseCheck::new(&phrase, mood_range);
+                Some(if phrase_check.is_empty() {
+                    Either::Left(view! {
+                        <p class=GUIDANCE_NOTE_CLASSES>
+                            {localizer.ui(UiText::PhraseCheckClear).to_string()}
+                        </p>
+                    })
+                } else {
+                    Either::Right(
+                        phrase_check
+                            .flags()
+                            .iter()
+                            .map(|phrase_flag| view! {
+                                <p class=DESCRIPTION_CLASSES>
+                                    <span class=PHRASE_CHECKER_PHRASE_CLASSES>
+                                        "\""
+                                        {phrase[phrase_flag.range.clone()].to_string()}
+                                        "\""
+                                    </span>
+                                    " · "
+                                    <a class=LINK_CLASSES href=anti_pattern_href(phrase_flag.anti_pattern)>
+                                        {localizer.anti_pattern_name(phrase_flag.anti_pattern).to_string()}
+                                    </a>
+                                    <br />
+                                    {localizer.anti_pattern_reason(phrase_flag.anti_pattern).to_string()}
+                                    <br />
+                                    <span class=DESCRIPTION_LABEL_CLASSES>
+                                        {localizer.ui(UiText::PhraseCheckTry).to_string()}
+                                    </span>
+                                    " "
+                                    {phrase_flag.rewording}
+                                </p>
+                            })
+                            .collect_view(),
+                    )
+                })
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,119 @@
+use leptos::{
+    component,
+    prelude::{ClassAttribute, CollectView, ElementChild, GlobalAttributes, IntoView, Read},
+    view,
+};
+use tears::{Principle, UiText};
+
+use super::{
+    catalog, l10n, site_href, DESCRIPTION_CLASSES, DESCRIPTION_LABEL_CLASSES, FIELD_DESC_CLASSES,
+    FIELD_NAME_CLASSES,
+};
+
+const PRINCIPLES_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-8 \
+";
+
+const PRINCIPLE_CLASSES: &str = "\
+    bg-tears-panel \
+    border-2 \
+    border-tears-outline \
+    rounded-lg \
+    p-8 \
+";
+
+const PRINCIPLE_NAME_CLASSES: &str = "\
+    font-bold \
+    text-3xl \
+";
+
+const PRINCIPLE_LIST_CLASSES: &str = "\
+    list-disc \
+    ps-8 \
+    pb-3 \
+";
+
+/// Returns the link to a principle on the principles page.
+pub fn principle_href(principle: Principle) -> String {
+    format!(
+        "{}#{}",
+        site_href("principles"),
+        principle_anchor(principle)
+    )
+}
+
+/// Returns the element ID of a principle on the principles page.
+fn principle_anchor(principle: Principle) -> String {
+    format!("principle-{}", principle.id())
+}
+
+/// Lists every principle, with examples and the trust and mood levels where
+/// it applies.
+#[component]
+pub fn PrinciplesPage() -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let suggestion_catalog = catalog::use_suggestion_catalog();
+
+    view! {
+        <div class=PRINCIPLES_CLASSES>
+            <div>
+                <h2 class=FIELD_NAME_CLASSES>{l10n::ui(localizer, UiText::Principles)}</h2>
+                <span class=FIELD_DESC_CLASSES>
+                    {l10n::ui(localizer, UiText::PrinciplesDescription)}
+                </span>
+            </div>
+            {
+                Principle::iter()
+                    .map(|principle| {
+                        view! {
+                            <section class=PRINCIPLE_CLASSES id=principle_anchor(principle)>
+                                {move || {
+                                    let localizer = localizer.read();
+                                    let states = suggestion_catalog
+                                        .read()
+                                        .principle_states(principle)
+                                        .into_iter()
+                                        .map(|(trust, mood)| view! {
+                                            <li>
+                                                {localizer.trust_name(trust).to_string()}
+                                                " · "
+                                                {localizer.mood_name(mood).to_string()}
+                                            </li>
+                                        })
+                                        .collect_view();
+
+                                    view! {
+                                        <h3 class=PRINCIPLE_NAME_CLASSES>
+                                            {localizer.principle_name(principle).to_string()}
+                                        </h3>
+                                        <p class=DESCRIPTION_CLASSES>
+                                            {localizer.principle_explanation(principle).to_string()}
+                                        </p>
+                                        <span class=DESCRIPTION_LABEL_CLASSES>
+                                            {localizer.ui(UiText::PrincipleExamples).to_string()}
+                                        </span>
+                                        <ul class=PRINCIPLE_LIST_CLASSES>
+                                            {
+                                                localizer
+                                                    .principle_examples(principle)
+                                                    .into_iter()
+                                                    .map(|example| view! { <li>{example.to_string()}</li> })
+                                                    .collect_view()
+                                            }
+                                        </ul>
+                                        <span class=DESCRIPTION_LABEL_CLASSES>
+                                            {localizer.ui(UiText::PrincipleAppliesIn).to_string()}
+                                        </span>
+                                        <ul class=PRINCIPLE_LIST_CLASSES>{states}</ul>
+                                    }
+                                }}
+                            </section>
+                        }
+                    })
+                    .collect_view()
+            }
+        </div>
+    }
+}
@@ -0,0 +1,126 @@
+use leptos::{
+    component,
+    prelude::{
+        event_target_value, ClassAttribute, CollectView, ElementChild, IntoView, OnAttribute,
+        PropAttribute, RwSignal, Update, With,
+    },
+    view,
+};
+use tears::{PersonProfile, ProfileList, UiText};
+
+use super::{
+    l10n, local_storage, CONTEXT_FIELDS_CLASSES, CONTEXT_SELECT_CLASSES, FIELD_DESC_CLASSES,
+    FIELD_NAME_CLASSES, INPUT_PANEL_CLASSES,
+};
+
+/// Key in `localStorage` that the person profile is stored under.
+const PROFILE_STORAGE_KEY: &str = "tears.profile";
+
+const PROFILE_SUMMARY_CLASSES: &str = "\
+    cursor-pointer \
+    mb-4 \
+";
+
+const PROFILE_LABEL_CLASSES: &str = "\
+    flex \
+    flex-col \
+    gap-1 \
+    text-tears-subtle \
+";
+
+const PROFILE_HINT_CLASSES: &str = "\
+    italic \
+    text-tears-muted \
+";
+
+/// Returns the profile stored from a previous visit, if any.
+///
+/// This must only be called in the browser.
+pub fn profile_load() -> Option<PersonProfile> {
+    local_storage::get(PROFILE_STORAGE_KEY)
+        .and_then(|profile_toml| PersonProfile::parse(&profile_toml).ok())
+}
+
+/// Stores the profile, or removes it if it is empty.
+///
+/// The profile is only stored in this browser, and never sent to the server.
+///
+/// This must only be called in the browser.
+pub fn profile_store(person_profile: &PersonProfile) {
+    let profile_toml = (!person_profile.is_empty())
+        .then(|| person_profile.to_toml().ok())
+        .flatten();
+    local_storage::set(PROFILE_STORAGE_KEY, profile_toml.as_deref());
+}
+
+/// Fields to describe the person being supported, which are filled in to the
+/// suggestions.
+#[component]
+pub fn ProfileInput(person_profile: RwSignal<PersonProfile>) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let name_on_input = move |ev| {
+        person_profile.update(|person_profile| person_profile.name = event_target_value(&ev));
+        person_profile.with(profile_store);
+    };
+
+    view! {
+        <details class=INPUT_PANEL_CLASSES>
+            <summary class=PROFILE_SUMMARY_CLASSES>
+                <span class=FIELD_NAME_CLASSES>
+                    {l10n::ui(localizer, UiText::ProfileField)}
+                </span>
+                <span class=FIELD_DESC_CLASSES>
+                    {l10n::ui(localizer, UiText::ProfileFieldDescription)}
+                </span>
+            </summary>
+            <div class=CONTEXT_FIELDS_CLASSES>
+                <label class=PROFILE_LABEL_CLASSES>
+                    {l10n::ui(localizer, UiText::ProfileNameLabel)}
+                    <input
+                        type="text"
+                        class=CONTEXT_SELECT_CLASSES
+                        prop:value=move || person_profile.with(|person_profile| person_profile.name.clone())
+                        on:input=name_on_input
+                    />
+                </label>
+                {ProfileList::iter()
+                    .map(|profile_list| view! { <ProfileListInput profile_list person_profile /> })
+                    .collect_view()}
+            </div>
+            <p class=PROFILE_HINT_CLASSES>{l10n::ui(localizer, UiText::ProfilePrivacy)}</p>
+        </details>
+    }
+}
+
+/// Text area for one of the lists in the profile, with one value per line.
+#[component]
+fn ProfileListInput(
+    profile_list: ProfileList,
+    person_profile: RwSignal<PersonProfile>,
+) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let text =
+        move || person_profile.with(|person_profile| person_profile.list(profile_list).join("\n"));
+    let on_input = move |ev| {
+        // Empty lines are kept, so that the text area keeps a new line that is
+        // still being typed.
+        let values = event_target_value(&ev)
+            .split('\n')
+            .map(String::from)
+            .collect::<Vec<_>>();
+        person_profile.update(|person_profile| *person_profile.list_mut(profile_list) = values);
+        person_profile.with(profile_store);
+    };
+
+    view! {
+        <label class=PROFILE_LABEL_CLASSES>
+            <span>
+                {l10n::ui(localizer, profile_list.label())}" "
+                <span class=PROFILE_HINT_CLASSES>
+                    "("{l10n::ui(localizer, UiText::ProfileListHint)}")"
+                </span>
+            </span>
+            <textarea rows="3" class=CONTEXT_SELECT_CLASSES prop:value=text on:input=on_input />
+        </label>
+    }
+}
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use leptos::{
+    component,
+    prelude::{
+        event_target_value, expect_context, provide_context, ClassAttribute, CollectView,
+        ElementChild, Get, IntoView, OnAttribute, PropAttribute, RwSignal, Set,
+    },
+    view,
+};
+use tears::{ReadingLevel, UiText};
+
+use super::{l10n, local_storage};
+
+/// Key in `localStorage` that the chosen reading level is stored under.
+const READING_LEVEL_STORAGE_KEY: &str = "tears.reading_level";
+
+const READING_LEVEL_SWITCHER_CLASSES: &str = "\
+    flex \
+    items-center \
+    gap-2 \
+    px-4 \
+    text-base \
+    text-tears-muted \
+";
+
+const READING_LEVEL_SELECT_CLASSES: &str = "\
+    bg-tears-panel \
+    text-tears-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-page \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+/// Provides the reading level as context to the components within the
+/// calling component.
+pub fn provide_reading_level(reading_level: RwSignal<ReadingLevel>) {
+    provide_context(reading_level);
+}
+
+/// Returns the reading level provided by [`provide_reading_level`].
+pub fn use_reading_level() -> RwSignal<ReadingLevel> {
+    expect_context::<RwSignal<ReadingLevel>>()
+}
+
+/// Returns the reading level stored from a previous visit, if any.
+///
+/// This must only be called in the browser.
+pub fn reading_level_load() -> Option<ReadingLevel> {
+    local_storage::get(READING_LEVEL_STORAGE_KEY)
+        .and_then(|reading_level| ReadingLevel::from_str(&reading_level).ok())
+}
+
+/// Stores the chosen reading level.
+///
+/// This must only be called in the browser.
+pub fn reading_level_store(reading_level: ReadingLevel) {
+    local_storage::set(READING_LEVEL_STORAGE_KEY, Some(reading_level.id()));
+}
+
+/// Drop down to choose how much text to show, which is remembered across
+/// visits.
+#[component]
+pub fn ReadingLevelSwitcher(reading_level: RwSignal<ReadingLevel>) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let reading_level_on_change = move |ev| {
+        if let Ok(reading_level_next) = ReadingLevel::from_str(event_target_value(&ev).as_str()) {
+            reading_level.set(reading_level_next);
+            reading_level_store(reading_level_next);
+        }
+    };
+
+    view! {
+        <label class=READING_LEVEL_SWITCHER_CLASSES>
+            {l10n::ui(localizer, UiText::ReadingLevelLabel)}
+            <select
+                class=READING_LEVEL_SELECT_CLASSES
+                on:change=reading_level_on_change
+            >
+                {
+                    ReadingLevel::iter()
+                        .map(|reading_level_variant| view! {
+                            <option
+                                value=reading_level_variant.id()
+                                prop:selected=move || reading_level.get() == reading_level_variant
+                            >
+                                {l10n::ui(localizer, reading_level_variant.ui_text())}
+                            </option>
+                        })
+                        .collect_view()
+                }
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,79 @@
+use leptos::{
+    component,
+    prelude::{AnyView, ClassAttribute, CollectView, ElementChild, IntoAny, IntoView},
+    view,
+};
+use tears::{RichText, RichTextBlock, RichTextSpan};
+
+use super::{DESCRIPTION_CLASSES, LINK_CLASSES};
+
+const LIST_BULLETED_CLASSES: &str = "\
+    list-disc \
+    ps-8 \
+    py-3 \
+";
+
+const LIST_NUMBERED_CLASSES: &str = "\
+    list-decimal \
+    ps-8 \
+    py-3 \
+";
+
+const RICH_TEXT_LINK_CLASSES: &str = "\
+    underline \
+    underline-offset-4 \
+";
+
+/// Shows rich text as paragraphs and lists.
+///
+/// Text is only ever written as text nodes, never as HTML.
+#[component]
+pub fn RichTextView(rich_text: RichText) -> impl IntoView {
+    rich_text
+        .blocks()
+        .iter()
+        .map(|block| match block {
+            RichTextBlock::Paragraph(spans) => view! {
+                <p class=DESCRIPTION_CLASSES>{spans_view(spans)}</p>
+            }
+            .into_any(),
+            RichTextBlock::ListBulleted(items) => view! {
+                <ul class=LIST_BULLETED_CLASSES>
+                    {items.iter().map(|item| view! { <li>{spans_view(item)}</li> }).collect_view()}
+                </ul>
+            }
+            .into_any(),
+            RichTextBlock::ListNumbered(items) => view! {
+                <ol class=LIST_NUMBERED_CLASSES>
+                    {items.iter().map(|item| view! { <li>{spans_view(item)}</li> }).collect_view()}
+                </ol>
+            }
+            .into_any(),
+        })
+        .collect_view()
+}
+
+/// Returns the view of inline spans, which may be nested.
+fn spans_view(spans: &[RichTextSpan]) -> Vec<AnyView> {
+    spans
+        .iter()
+        .map(|span| match span {
+            RichTextSpan::Text(text) => text.clone().into_any(),
+            RichTextSpan::Emphasis(spans) => view! { <em>{spans_view(spans)}</em> }.into_any(),
+            RichTextSpan::Strong(spans) => {
+                view! { <strong>{spans_view(spans)}</strong> }.into_any()
+            }
+            RichTextSpan::Link { spans, url } => view! {
+                <a
+                    class=format!("{LINK_CLASSES} {RICH_TEXT_LINK_CLASSES}")
+                    href=url.clone()
+                    target="_blank"
+                    rel="noopener noreferrer"
+                >
+                    {spans_view(spans)}
+                </a>
+            }
+            .into_any(),
+        })
+        .collect()
+}
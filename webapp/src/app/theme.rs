@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use leptos::{
+    component,
+    prelude::{
+        event_target_value, ClassAttribute, CollectView, ElementChild, Get, IntoView, OnAttribute,
+        PropAttribute, RwSignal, Set,
+    },
+    view,
+};
+use tears::UiText;
+
+use super::{l10n, local_storage};
+
+/// Key in `localStorage` that the chosen theme is stored under.
+const THEME_STORAGE_KEY: &str = "tears.theme";
+
+/// Value of the theme `<select>` option that follows the system preferences.
+const THEME_SYSTEM: &str = "system";
+
+const THEME_SWITCHER_CLASSES: &str = "\
+    flex \
+    items-center \
+    gap-2 \
+    px-4 \
+    text-base \
+    text-tears-muted \
+";
+
+const THEME_SELECT_CLASSES: &str = "\
+    bg-tears-panel \
+    text-tears-fg \
+    border \
+    border-tears-divider \
+    rounded-lg \
+    px-2 \
+    py-1 \
+    \
+    outline-none \
+    ring-offset-4 \
+    ring-offset-tears-page \
+    focus:ring-2 \
+    focus:ring-tears-focus \
+";
+
+/// Colour theme of the page.
+///
+/// When no theme is chosen, the `prefers-color-scheme` and `prefers-contrast`
+/// media queries decide how the page looks.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Theme {
+    /// Dark text on a light background.
+    Light,
+    /// Light text on a dark background.
+    Dark,
+    /// Black, white, and strong accent colours, for low vision.
+    HighContrast,
+    /// Muted colours and no animations, for when the reader is overwhelmed.
+    LowStimulus,
+}
+
+impl Theme {
+    /// Returns an iterator over all variants of this `Theme` enum.
+    pub fn iter() -> impl Iterator<Item = Theme> {
+        [
+            Theme::Light,
+            Theme::Dark,
+            Theme::HighContrast,
+            Theme::LowStimulus,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the value of the `data-theme` attribute on `<html>` for this
+    /// theme.
+    pub fn attr_value(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::HighContrast => "high-contrast",
+            Theme::LowStimulus => "low-stimulus",
+        }
+    }
+
+    /// Returns the label for this theme.
+    pub fn ui_text(self) -> UiText {
+        match self {
+            Theme::Light => UiText::ThemeLight,
+            Theme::Dark => UiText::ThemeDark,
+            Theme::HighContrast => UiText::ThemeHighContrast,
+            Theme::LowStimulus => UiText::ThemeLowStimulus,
+        }
+    }
+
+    /// Returns the theme stored from a previous visit, if any.
+    ///
+    /// This must only be called in the browser.
+    pub fn load() -> Option<Theme> {
+        local_storage::get(THEME_STORAGE_KEY).and_then(|theme| Theme::from_str(&theme).ok())
+    }
+
+    /// Stores the chosen theme, or clears it to follow the system preferences.
+    ///
+    /// This must only be called in the browser.
+    pub fn store(theme: Option<Theme>) {
+        local_storage::set(THEME_STORAGE_KEY, theme.map(Theme::attr_value));
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            "high-contrast" => Ok(Theme::HighContrast),
+            "low-stimulus" => Ok(Theme::LowStimulus),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Drop down to choose the colour theme, which is remembered across visits.
+#[component]
+pub fn ThemeSwitcher(theme: RwSignal<Option<Theme>>) -> impl IntoView {
+    let theme_on_change = move |ev| {
+        let theme_next = Theme::from_str(event_target_value(&ev).as_str()).ok();
+        theme.set(theme_next);
+        Theme::store(theme_next);
+    };
+
+    let localizer = l10n::use_localizer();
+
+    view! {
+        <label class=THEME_SWITCHER_CLASSES>
+            {l10n::ui(localizer, UiText::ThemeLabel)}
+            <select
+                class=THEME_SELECT_CLASSES
+                on:change=theme_on_change
+            >
+                <option
+                    value=THEME_SYSTEM
+                    prop:selected=move || theme.get().is_none()
+                >
+                    {l10n::ui(localizer, UiText::ThemeSystem)}
+                </option>
+                {
+                    Theme::iter()
+                        .map(|theme_variant| view! {
+                            <option
+                                value=theme_variant.attr_value()
+                                prop:selected=move || theme.get() == Some(theme_variant)
+                            >
+                                {l10n::ui(localizer, theme_variant.ui_text())}
+                            </option>
+                        })
+                        .collect_view()
+                }
+            </select>
+        </label>
+    }
+}
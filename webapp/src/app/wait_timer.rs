@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use js_sys::Date;
+use leptos::{
+    component,
+    either::EitherOf3,
+    prelude::{
+        on_cleanup, set_interval_with_handle, ClassAttribute, ElementChild, Get, GetUntracked,
+        GetValue, GlobalAttributes, IntervalHandle, IntoView, OnAttribute, Read, RwSignal, Set,
+        SetValue, StoredValue,
+    },
+    view,
+};
+use tears::UiText;
+
+use super::{l10n, GUIDANCE_NOTE_CLASSES, LINK_CLASSES};
+
+const WAIT_TIMER_CLASSES: &str = "\
+    py-3 \
+    text-tears-muted \
+";
+
+/// Where the wait timer is in its countdown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WaitTimerState {
+    /// Not started, or stopped.
+    Idle,
+    /// Counting down, with the seconds left.
+    Waiting(u64),
+    /// The wait is over.
+    Done,
+}
+
+/// Offers to time a quiet wait after acting on a suggestion, and gently says
+/// when it's fine to speak again or to leave.
+///
+/// The timer is silent, and only changes text on the page.
+#[component]
+pub fn WaitTimer(wait: Duration) -> impl IntoView {
+    let localizer = l10n::use_localizer();
+    let state = RwSignal::new(WaitTimerState::Idle);
+    let interval = StoredValue::new(None::<IntervalHandle>);
+    // When the wait ends, in milliseconds since the epoch.
+    //
+    // Browsers slow down intervals in background tabs, so the time left is
+    // worked out from the clock rather than by counting ticks.
+    let deadline = StoredValue::new(0.0);
+
+    let stop = move || {
+        if let Some(interval_handle) = interval.get_value() {
+            interval_handle.clear();
+            interval.set_value(None);
+        }
+    };
+    on_cleanup(stop);
+
+    let tick = move || {
+        let millis_left = deadline.get_value() - Date::now();
+        let state_next = if millis_left > 0.0 {
+            WaitTimerState::Waiting((millis_left / 1000.0).ceil() as u64)
+        } else {
+            stop();
+            WaitTimerState::Done
+        };
+        // Only changes to the seconds left re-render, so the stop button
+        // keeps focus between ticks.
+        if state.get_untracked() != state_next {
+            state.set(state_next);
+        }
+    };
+    let start = move |_| {
+        stop();
+        let seconds = wait.as_secs().max(1);
+        deadline.set_value(Date::now() + (seconds * 1000) as f64);
+        state.set(WaitTimerState::Waiting(seconds));
+        interval.set_value(set_interval_with_handle(tick, Duration::from_millis(250)).ok());
+    };
+    let cancel = move |_| {
+        stop();
+        state.set(WaitTimerState::Idle);
+    };
+
+    view! {
+        <div class=WAIT_TIMER_CLASSES>
+            {move || {
+                let localizer = localizer.read();
+                match state.get() {
+                    WaitTimerState::Idle => EitherOf3::A(view! {
+                        <button type="button" class=LINK_CLASSES on:click=start>
+                            {
+                                localizer
+                                    .ui(UiText::WaitTimerStart)
+                                    .replace("{duration}", &duration_format(wait.as_secs()))
+                            }
+                        </button>
+                    }),
+                    WaitTimerState::Waiting(seconds) => EitherOf3::B(view! {
+                        <span>
+                            {
+                                localizer
+                                    .ui(UiText::WaitTimerWaiting)
+                                    .replace("{remaining}", &duration_format(seconds))
+                            }
+                        </span>
+                        " "
+                        <button type="button" class=LINK_CLASSES on:click=cancel>
+                            {localizer.ui(UiText::WaitTimerStop).to_string()}
+                        </button>
+                    }),
+                    WaitTimerState::Done => EitherOf3::C(view! {
+                        <p class=GUIDANCE_NOTE_CLASSES role="status">
+                            {localizer.ui(UiText::WaitTimerDone).to_string()}
+                            " "
+                            <button type="button" class=LINK_CLASSES on:click=cancel>
+                                {localizer.ui(UiText::Close).to_string()}
+                            </button>
+                        </p>
+                    }),
+                }
+            }}
+        </div>
+    }
+}
+
+/// Returns a number of seconds as minutes and seconds, e.g. `"2:05"`.
+fn duration_format(seconds: u64) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
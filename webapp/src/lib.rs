@@ -1,3 +1,7 @@
+// The views are deeply nested generic types, which the `ssr` build exceeds
+// the default limit for.
+#![recursion_limit = "256"]
+
 pub mod app;
 
 #[cfg(feature = "hydrate")]
@@ -1,3 +1,7 @@
+// The views are deeply nested generic types, which the `ssr` build exceeds
+// the default limit for.
+#![recursion_limit = "256"]
+
 // Because we don't build the crate as an `rlib` (for `trunk` to work), we
 // declare the module twice.
 //
@@ -8,8 +12,11 @@ pub mod app;
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
-    use crate::app::{shell, App};
-    use axum::Router;
+    use crate::app::{
+        editor_auth, editor_path, shell, App, CATALOG_FILE_ENV, EDITORS_ENV, EDITOR_ALLOW_HTTP_ENV,
+        TRUSTED_PROXIES_ENV,
+    };
+    use axum::{middleware, Router};
     use leptos::{logging::log, prelude::*};
     use leptos_axum::{generate_route_list, LeptosRoutes};
 
@@ -25,15 +32,34 @@ async fn main() {
             move || shell(leptos_options.clone())
         })
         .fallback(leptos_axum::file_and_error_handler(shell))
+        .layer(middleware::from_fn(editor_auth))
         .with_state(leptos_options);
 
     // run our app with hyper
     // `axum::Server` is a re-export of `hyper::Server`
     log!("listening on http://{}", &addr);
+    match std::env::var_os(CATALOG_FILE_ENV) {
+        Some(catalog_file_path) => log!("serving the catalog file {catalog_file_path:?}"),
+        None => log!("serving the built in catalog, set `{CATALOG_FILE_ENV}` to serve and edit a catalog file"),
+    }
+    if std::env::var_os(EDITORS_ENV).is_some() {
+        log!("editor on http://{}{}", &addr, editor_path());
+        if std::env::var_os(EDITOR_ALLOW_HTTP_ENV).is_some() {
+            log!("editor allows HTTP, which sends passwords unencrypted, as `{EDITOR_ALLOW_HTTP_ENV}` is set");
+        } else {
+            log!("editor requires HTTPS, through a proxy in `{TRUSTED_PROXIES_ENV}`");
+        }
+    } else {
+        log!("editor disabled, set `{EDITORS_ENV}` to enable it");
+    }
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app.into_make_service())
-        .await
-        .unwrap();
+    // The client's address is used to limit how often feedback is sent.
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
 
 #[cfg(feature = "csr")]
@@ -1,4 +1,8 @@
 /** @type {import('tailwindcss').Config} */
+
+// Colours are defined as CSS variables in `tears.css`, one set per theme.
+const themeColor = (name) => `rgb(var(--tears-${name}) / <alpha-value>)`;
+
 module.exports = {
   content: [
     // Relative to workspace root / where you run `cargo leptos` from.
@@ -9,7 +13,28 @@ module.exports = {
     extend: {
       backgroundImage: {
         'cross': "url('cross.svg')",
-      }
+      },
+      colors: {
+        'tears': {
+          'page': themeColor('page'),
+          'fg': themeColor('fg'),
+          'subtle': themeColor('subtle'),
+          'muted': themeColor('muted'),
+          'panel': themeColor('panel'),
+          'card': themeColor('card'),
+          'popup': themeColor('popup'),
+          'border': themeColor('border'),
+          'outline': themeColor('outline'),
+          'divider': themeColor('divider'),
+          'control': themeColor('control'),
+          'control-hover': themeColor('control-hover'),
+          'control-checked': themeColor('control-checked'),
+          'control-fg': themeColor('control-fg'),
+          'control-checked-fg': themeColor('control-checked-fg'),
+          'focus': themeColor('focus'),
+          'accent': themeColor('accent'),
+        },
+      },
     },
   },
   plugins: [],